@@ -31,7 +31,29 @@ pub enum Gate {
 #[derive(Clone, Debug)]
 /// Directives do not apply any constraints.
 pub enum Directive {
-    Invert { x: Witness, result: Witness },
+    Invert {
+        x: Witness,
+        result: Witness,
+    },
+    // Splits `a` into `b` and `c`, such that a = b + 2^bit_size * c and b < 2^bit_size
+    Truncate {
+        a: Witness,
+        b: Witness,
+        c: Witness,
+        bit_size: u32,
+    },
+    // Computes the quotient `q` and the remainder `r` of the euclidean division a = q * b + r
+    Quotient {
+        a: Witness,
+        b: Witness,
+        q: Witness,
+        r: Witness,
+    },
+    // Sets `selectors[i]` to 1 if `index` is equal to `i` and to 0 otherwise
+    OneHot {
+        index: Witness,
+        selectors: Vec<Witness>,
+    },
 }
 
 // Note: Some gadgets will not use all of the witness
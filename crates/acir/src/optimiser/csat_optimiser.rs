@@ -31,6 +31,7 @@ impl Optimiser {
         let gate = self.remove_zero_coefficients(gate);
         // Collect like terms in the fan-in and fan-out
         // XXX: Perhaps this optimisation can be done on the fly and we only have one vector
+        let gate = self.simplify_linear_terms(gate);
 
         // Collect like terms in the mul terms array
         let gate = self.simplify_mul_terms(gate);
@@ -75,6 +76,24 @@ impl Optimiser {
         gate
     }
 
+    // Simplifies all linear terms with the same variable
+    fn simplify_linear_terms(&self, mut gate: Arithmetic) -> Arithmetic {
+        let mut hash_map: BTreeMap<Witness, FieldElement> = BTreeMap::new();
+
+        for (scale, witness) in gate.linear_combinations.into_iter() {
+            *hash_map.entry(witness).or_insert(FieldElement::zero()) += scale;
+        }
+
+        // Like terms may cancel out, so we remove the terms which are now zero
+        gate.linear_combinations = hash_map
+            .into_iter()
+            .filter(|(_, scale)| !scale.is_zero())
+            .map(|(witness, scale)| (scale, witness))
+            .collect();
+
+        gate
+    }
+
     // Simplifies all mul terms with the same bi-variate variables
     fn simplify_mul_terms(&self, mut gate: Arithmetic) -> Arithmetic {
         let mut hash_map: BTreeMap<(Witness, Witness), FieldElement> = BTreeMap::new();
@@ -90,8 +109,10 @@ impl Optimiser {
                 .or_insert(FieldElement::zero()) += scale;
         }
 
+        // Like terms may cancel out, so we remove the terms which are now zero
         gate.mul_terms = hash_map
             .into_iter()
+            .filter(|(_, scale)| !scale.is_zero())
             .map(|((w_l, w_r), scale)| (scale, w_l, w_r))
             .collect();
 
@@ -178,25 +199,25 @@ impl Optimiser {
                 (Some(x), Some(y)) => {
                     // This means that we can form a full gate with this Qm term
 
-                    // First fetch the left and right wires which match the mul term
-                    let left_wire_term = gate.linear_combinations[x].clone();
-                    let right_wire_term = gate.linear_combinations[y].clone();
-
                     // Lets create an intermediate gate to store this full gate
                     //
                     let mut intermediate_gate = Arithmetic::default();
                     intermediate_gate.mul_terms.push(pair);
 
-                    // Add the left and right wires
-                    intermediate_gate.linear_combinations.push(left_wire_term);
-                    intermediate_gate.linear_combinations.push(right_wire_term);
-                    // Remove the left and right wires so we do not re-add them
-                    gate.linear_combinations.remove(x);
-                    gate.linear_combinations.remove(y);
+                    // Move the left and right wires which match the mul term into the intermediate gate,
+                    // so we do not re-add them. A square term only has one wire.
+                    // The wire with the largest index is removed first, so that the other index stays valid
+                    let mut wire_indices = vec![x.max(y), x.min(y)];
+                    wire_indices.dedup();
+                    for index in wire_indices.iter() {
+                        let wire_term = gate.linear_combinations.remove(*index);
+                        intermediate_gate.linear_combinations.push(wire_term);
+                    }
 
-                    // Now we have used up 2 spaces in our arithmetic gate. The width now dictates, how many more we can add
-                    let remaining_space = self.width - 2 - 1; // We minus 1 because we need an extra space to contrain the intermediate variable
-                                                              // Keep adding terms until we have no more left, or we reach the width
+                    // Now we have used up 1 or 2 spaces in our arithmetic gate. The width now dictates, how many more we can add
+                    let remaining_space = self.width - wire_indices.len() - 1; // We minus 1 because we need an extra space to contrain the intermediate variable
+
+                    // Keep adding terms until we have no more left, or we reach the width
                     for _ in 0..remaining_space {
                         match gate.linear_combinations.pop() {
                             Some(wire_term) => {
@@ -428,3 +449,90 @@ fn simple_reduction_smoke_test() {
     };
     assert_eq!(&expected_intermediate_gate, got_intermediate_gate);
 }
+
+#[test]
+fn like_terms_are_collected() {
+    let a = Witness(0);
+    let b = Witness(1);
+    let c = Witness(2);
+
+    // a*b + 2a - a*b - a + b - a - b + c = c
+    let gate = Arithmetic {
+        mul_terms: vec![(FieldElement::one(), a, b), (-FieldElement::one(), a, b)],
+        linear_combinations: vec![
+            (FieldElement::from(2), a),
+            (-FieldElement::one(), a),
+            (FieldElement::one(), b),
+            (-FieldElement::one(), a),
+            (-FieldElement::one(), b),
+            (FieldElement::one(), c),
+        ],
+        q_c: FieldElement::zero(),
+    };
+
+    let mut intermediate_variables: BTreeMap<Witness, Arithmetic> = BTreeMap::new();
+    let optimiser = Optimiser::new(3);
+    let got_optimised_gate = optimiser.optimise(gate, &mut intermediate_variables, 3);
+
+    let expected_optimised_gate = Arithmetic {
+        mul_terms: vec![],
+        linear_combinations: vec![(FieldElement::one(), c)],
+        q_c: FieldElement::zero(),
+    };
+    assert_eq!(expected_optimised_gate, got_optimised_gate);
+    assert!(intermediate_variables.is_empty());
+}
+
+#[test]
+fn square_terms_form_full_gates() {
+    let a = Witness(0);
+    let b = Witness(1);
+    let c = Witness(2);
+    let d = Witness(3);
+
+    // a*a + a + b + c + d = 0
+    let gate = Arithmetic {
+        mul_terms: vec![(FieldElement::one(), a, a)],
+        linear_combinations: vec![
+            (FieldElement::one(), a),
+            (FieldElement::one(), b),
+            (FieldElement::one(), c),
+            (FieldElement::one(), d),
+        ],
+        q_c: FieldElement::zero(),
+    };
+
+    let mut intermediate_variables: BTreeMap<Witness, Arithmetic> = BTreeMap::new();
+    let optimiser = Optimiser::new(3);
+    let got_optimised_gate = optimiser.optimise(gate, &mut intermediate_variables, 4);
+
+    // The gates must still be satisfied by a = 2, b = 3, c = 5 and d = -14
+    let mut values: BTreeMap<Witness, FieldElement> = BTreeMap::new();
+    values.insert(a, FieldElement::from(2));
+    values.insert(b, FieldElement::from(3));
+    values.insert(c, FieldElement::from(5));
+    values.insert(d, FieldElement::from(-14));
+    let evaluate = |gate: &Arithmetic, values: &BTreeMap<Witness, FieldElement>| {
+        let mut sum = gate.q_c;
+        for (scale, w_l, w_r) in gate.mul_terms.iter() {
+            sum += *scale * values[w_l] * values[w_r];
+        }
+        for (scale, witness) in gate.linear_combinations.iter() {
+            if values.contains_key(witness) {
+                sum += *scale * values[witness];
+            }
+        }
+        sum
+    };
+
+    // Each intermediate gate constrains `-1 * inter_var` to be equal to the rest of the gate
+    for (inter_var, intermediate_gate) in intermediate_variables.iter() {
+        assert!(
+            intermediate_gate.mul_terms.len() + intermediate_gate.linear_combinations.len() <= 4
+        );
+        let value = evaluate(intermediate_gate, &values);
+        values.insert(*inter_var, value);
+    }
+    assert!(got_optimised_gate.linear_combinations.len() <= 3);
+    assert_eq!(evaluate(&got_optimised_gate, &values), FieldElement::zero());
+}
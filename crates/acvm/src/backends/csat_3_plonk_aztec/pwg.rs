@@ -1,6 +1,6 @@
 use crate::{
     pwg::{arithmetic::ArithmeticSolver, logic::LogicSolver},
-    PartialWitnessGenerator,
+    PartialWitnessGenerator, SolvingError,
 };
 use acir::{circuit::Gate, native_types::Witness};
 use noir_field::FieldElement;
@@ -17,11 +17,12 @@ impl PartialWitnessGenerator for Plonk {
         &self,
         initial_witness: &mut BTreeMap<Witness, FieldElement>,
         gates: Vec<acir::circuit::Gate>,
-    ) -> Result<(), acir::OPCODE> {
+    ) -> Result<(), SolvingError> {
         if gates.len() == 0 {
             return Ok(());
         }
 
+        let num_gates = gates.len();
         let mut unsolved_gates: Vec<Gate> = Vec::new();
 
         for gate in gates.into_iter() {
@@ -47,7 +48,8 @@ impl PartialWitnessGenerator for Plonk {
                     false
                 }
                 Gate::GadgetCall(gc) => {
-                    GadgetCaller::solve_gadget_call(initial_witness, gc)?;
+                    GadgetCaller::solve_gadget_call(initial_witness, gc)
+                        .map_err(SolvingError::UnsupportedOpcode)?;
 
                     false
                 }
@@ -62,6 +64,48 @@ impl PartialWitnessGenerator for Plonk {
                             }
                         }
                     }
+                    acir::circuit::gate::Directive::Truncate { a, b, c, bit_size } => {
+                        match initial_witness.get(a) {
+                            None => true,
+                            Some(val) => {
+                                let remainder = lower_bits(val, *bit_size);
+                                let quotient = (*val - remainder)
+                                    * FieldElement::pow_of_two(*bit_size).inverse();
+                                initial_witness.insert(*b, remainder);
+                                initial_witness.insert(*c, quotient);
+                                false
+                            }
+                        }
+                    }
+                    acir::circuit::gate::Directive::Quotient { a, b, q, r } => {
+                        match (initial_witness.get(a), initial_witness.get(b)) {
+                            (Some(val_a), Some(val_b)) => {
+                                let (quotient, remainder) = euclidean_division(val_a, val_b);
+                                initial_witness.insert(*q, quotient);
+                                initial_witness.insert(*r, remainder);
+                                false
+                            }
+                            _ => true,
+                        }
+                    }
+                    acir::circuit::gate::Directive::OneHot { index, selectors } => {
+                        match initial_witness.get(index) {
+                            None => true,
+                            Some(val) => {
+                                let val = *val;
+                                for (i, selector) in selectors.iter().enumerate() {
+                                    let is_selected = FieldElement::from(i as i128) == val;
+                                    let value = if is_selected {
+                                        FieldElement::one()
+                                    } else {
+                                        FieldElement::zero()
+                                    };
+                                    initial_witness.insert(*selector, value);
+                                }
+                                false
+                            }
+                        }
+                    }
                 },
             };
             if unsolved {
@@ -69,6 +113,74 @@ impl PartialWitnessGenerator for Plonk {
             }
         }
 
+        // Each pass solves the gates which have a single unknown, so
+        // if no gate was solved, the next pass would not solve any either
+        if unsolved_gates.len() == num_gates {
+            return Err(SolvingError::UnsolvableGates { num_gates });
+        }
         self.solve(initial_witness, unsolved_gates)
     }
 }
+
+// Returns the value of the least significant `num_bits` bits
+fn lower_bits(value: &FieldElement, num_bits: u32) -> FieldElement {
+    let bits = value.bits();
+    let start = bits.len().saturating_sub(num_bits as usize);
+    bits[start..].iter().fold(FieldElement::zero(), |acc, bit| {
+        let acc = acc + acc;
+        if *bit {
+            acc + FieldElement::one()
+        } else {
+            acc
+        }
+    })
+}
+
+// Computes the quotient and remainder of a / b, using binary long division
+// If b is zero, then the quotient is zero and the remainder is a
+fn euclidean_division(a: &FieldElement, b: &FieldElement) -> (FieldElement, FieldElement) {
+    if b.is_zero() {
+        return (FieldElement::zero(), *a);
+    }
+
+    let mut quotient = FieldElement::zero();
+    let mut remainder = FieldElement::zero();
+    for bit in a.bits() {
+        quotient = quotient + quotient;
+        remainder = remainder + remainder;
+        if bit {
+            remainder = remainder + FieldElement::one();
+        }
+        if remainder >= *b {
+            remainder = remainder - *b;
+            quotient = quotient + FieldElement::one();
+        }
+    }
+    (quotient, remainder)
+}
+
+#[test]
+fn unsolvable_gates_are_reported() {
+    use acir::native_types::Arithmetic;
+
+    let a = Witness(1);
+    let b = Witness(2);
+    let c = Witness(3);
+
+    // a * b = c, where a is zero, so the gate does not determine b
+    let gate = Arithmetic {
+        mul_terms: vec![(FieldElement::one(), a, b)],
+        linear_combinations: vec![(-FieldElement::one(), c)],
+        q_c: FieldElement::zero(),
+    };
+
+    let mut values: BTreeMap<Witness, FieldElement> = BTreeMap::new();
+    values.insert(a, FieldElement::zero());
+    values.insert(c, FieldElement::zero());
+
+    let result = Plonk.solve(&mut values, vec![Gate::Arithmetic(gate)]);
+    assert!(matches!(
+        result,
+        Err(SolvingError::UnsolvableGates { num_gates: 1 })
+    ));
+}
@@ -67,13 +67,39 @@ pub trait Backend: SmartContract + ProofSystemCompiler + PartialWitnessGenerator
 
 /// This component will generate the backend specific output for
 /// each OPCODE.
-/// Returns an Error if the backend does not support that OPCODE
+/// Returns an Error if the backend does not support that OPCODE,
+/// or if the gates do not determine every witness
 pub trait PartialWitnessGenerator {
     fn solve(
         &self,
         initial_witness: &mut BTreeMap<Witness, FieldElement>,
         gates: Vec<Gate>,
-    ) -> Result<(), OPCODE>;
+    ) -> Result<(), SolvingError>;
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum SolvingError {
+    UnsupportedOpcode(OPCODE),
+    // None of the remaining gates could be solved, as each of them has more
+    // than one unknown witness, or an unknown which is only multiplied by zero
+    UnsolvableGates { num_gates: usize },
+}
+
+impl std::fmt::Display for SolvingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolvingError::UnsupportedOpcode(opcode) => write!(
+                f,
+                "backend does not currently support the {} opcode. ACVM does not currently fall back to arithmetic gates.",
+                opcode
+            ),
+            SolvingError::UnsolvableGates { num_gates } => write!(
+                f,
+                "could not solve the witness, {} gates do not determine the value of their unknown witnesses",
+                num_gates
+            ),
+        }
+    }
 }
 pub trait SmartContract {
     // Takes a verification  key and produces a smart contract
@@ -20,6 +20,8 @@ enum MulTerm {
 
 impl ArithmeticSolver {
     /// Derives the rest of the witness based on the initial low level variables
+    ///
+    /// Returns the gate if it could not be solved yet
     pub fn solve<'a>(
         initial_witness: &mut BTreeMap<Witness, FieldElement>,
         gate: &'a Arithmetic,
@@ -33,6 +35,13 @@ impl ArithmeticSolver {
             (MulTerm::TooManyUnknowns, _) => return Some(gate),
             (_, GateStatus::GateUnsolvable) => return Some(gate),
             (MulTerm::OneUnknown(_, _), GateStatus::GateSolvable(_, _)) => return Some(gate),
+            // The unknown is multiplied by zero, so this gate does not determine its value.
+            // It is left for a later pass, once the unknown has been solved by another gate.
+            (MulTerm::OneUnknown(partial_prod, _), GateStatus::GateSatisfied(_))
+                if partial_prod.is_zero() =>
+            {
+                return Some(gate)
+            }
             (MulTerm::OneUnknown(partial_prod, unknown_var), GateStatus::GateSatisfied(sum)) => {
                 // We have one unknown in the mul term and the fan-in terms are solved.
                 // Hence the equation is solvable, since there is a single unknown
@@ -182,3 +191,45 @@ fn arithmetic_smoke_test() {
 
     assert_eq!(values.get(&a).unwrap(), &FieldElement::from(4));
 }
+
+#[test]
+fn unknown_in_mul_term_is_solved() {
+    let a = Witness(0);
+    let b = Witness(1);
+    let c = Witness(2);
+
+    // a * b = c
+    let gate = Arithmetic {
+        mul_terms: vec![(FieldElement::one(), a, b)],
+        linear_combinations: vec![(-FieldElement::one(), c)],
+        q_c: FieldElement::zero(),
+    };
+
+    let mut values: BTreeMap<Witness, FieldElement> = BTreeMap::new();
+    values.insert(a, FieldElement::from(2));
+    values.insert(c, FieldElement::from(6));
+
+    assert!(ArithmeticSolver::solve(&mut values, &gate).is_none());
+    assert_eq!(values.get(&b).unwrap(), &FieldElement::from(3));
+}
+
+#[test]
+fn unknown_multiplied_by_zero_is_not_solved() {
+    let a = Witness(0);
+    let b = Witness(1);
+    let c = Witness(2);
+
+    // a * b = c, where a is zero, so any value of b satisfies the gate
+    let gate = Arithmetic {
+        mul_terms: vec![(FieldElement::one(), a, b)],
+        linear_combinations: vec![(-FieldElement::one(), c)],
+        q_c: FieldElement::zero(),
+    };
+
+    let mut values: BTreeMap<Witness, FieldElement> = BTreeMap::new();
+    values.insert(a, FieldElement::zero());
+    values.insert(c, FieldElement::zero());
+
+    assert!(ArithmeticSolver::solve(&mut values, &gate).is_some());
+    assert!(values.get(&b).is_none());
+}
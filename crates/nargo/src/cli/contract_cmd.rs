@@ -1,4 +1,4 @@
-use super::{create_dir, write_to_file, CONTRACT_DIR};
+use super::{create_dir, max_loop_iterations, write_to_file, CONTRACT_DIR};
 use crate::resolver::Resolver;
 use clap::ArgMatches;
 use std::path::PathBuf;
@@ -11,6 +11,9 @@ pub(crate) fn run(args: ArgMatches) {
         None => std::env::current_dir().unwrap(),
     };
     let (mut driver, backend_ptr) = Resolver::resolve_root_config(&package_dir);
+    if let Some(max_loop_iterations) = max_loop_iterations(cmd) {
+        driver.set_max_loop_iterations(max_loop_iterations);
+    }
     let compiled_program = driver.into_compiled_program(backend_ptr);
 
     let smart_contract_string = backend_ptr
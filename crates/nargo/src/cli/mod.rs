@@ -1,5 +1,6 @@
+use crate::write_stderr;
 pub use build_cmd::build_from_path;
-use clap::{App, Arg};
+use clap::{App, Arg, ArgMatches};
 use std::{
     fs::File,
     io::Write,
@@ -21,12 +22,21 @@ const PKG_FILE: &str = "Nargo.toml";
 const PROOF_EXT: &str = "proof";
 
 pub fn start_cli() {
+    let max_loop_iterations = Arg::with_name("max_loop_iterations")
+        .long("max-loop-iterations")
+        .takes_value(true)
+        .help("The number of iterations after which unrolling a while loop fails");
+
     let matches = App::new("nargo")
         .about("Noir's package manager")
         .version("0.1")
         .author("Kevaundray Wedderburn <kevtheappdev@gmail.com>")
         .subcommand(App::new("build").about("Builds the constraint system"))
-        .subcommand(App::new("contract").about("Creates the smart contract code for circuit"))
+        .subcommand(
+            App::new("contract")
+                .about("Creates the smart contract code for circuit")
+                .arg(max_loop_iterations.clone()),
+        )
         .subcommand(
             App::new("new")
                 .about("Create a new binary project")
@@ -48,7 +58,8 @@ pub fn start_cli() {
                     Arg::with_name("proof")
                         .help("The proof to verify")
                         .required(true),
-                ),
+                )
+                .arg(max_loop_iterations.clone()),
         )
         .subcommand(
             App::new("prove")
@@ -57,7 +68,8 @@ pub fn start_cli() {
                     Arg::with_name("proof_name")
                         .help("The name of the proof")
                         .required(true),
-                ),
+                )
+                .arg(max_loop_iterations),
         )
         .get_matches();
 
@@ -72,6 +84,18 @@ pub fn start_cli() {
     }
 }
 
+// Returns the value of the `--max-loop-iterations` flag of a subcommand, if it was given
+fn max_loop_iterations(args: &ArgMatches) -> Option<u64> {
+    args.value_of("max_loop_iterations")
+        .map(|value| match value.parse() {
+            Ok(max_loop_iterations) => max_loop_iterations,
+            Err(_) => write_stderr(&format!(
+                "expected the maximum number of loop iterations to be an integer, found {}",
+                value
+            )),
+        })
+}
+
 fn create_dir<P: AsRef<Path>>(dir_path: P) -> Result<PathBuf, std::io::Error> {
     let mut dir = std::path::PathBuf::new();
     dir.push(dir_path);
@@ -4,37 +4,42 @@ use crate::write_stderr;
 use acvm::acir::native_types::Witness;
 use clap::ArgMatches;
 use noir_field::FieldElement;
-use noirc_abi::{input_parser::InputValue, Abi};
+use noirc_abi::{input_parser::InputValue, Abi, AbiType};
 
 use crate::resolver::Resolver;
 
-use super::{create_dir, write_to_file, PROOFS_DIR, PROOF_EXT, PROVER_INPUT_FILE};
+use super::{
+    create_dir, max_loop_iterations, write_to_file, PROOFS_DIR, PROOF_EXT, PROVER_INPUT_FILE,
+};
 
 pub(crate) fn run(args: ArgMatches) {
-    let proof_name = args
-        .subcommand_matches("prove")
-        .unwrap()
-        .value_of("proof_name")
-        .unwrap();
+    let cmd = args.subcommand_matches("prove").unwrap();
+    let proof_name = cmd.value_of("proof_name").unwrap();
 
-    prove(proof_name);
+    prove(proof_name, max_loop_iterations(cmd));
 }
 
 /// In Barretenberg, the proof system adds a zero witness in the first index,
 /// So when we add witness values, their index start from 1.
 const WITNESS_OFFSET: u32 = 1;
 
-fn prove(proof_name: &str) {
+fn prove(proof_name: &str, max_loop_iterations: Option<u64>) {
     let curr_dir = std::env::current_dir().unwrap();
     let (mut driver, backend_ptr) = Resolver::resolve_root_config(&curr_dir);
+    if let Some(max_loop_iterations) = max_loop_iterations {
+        driver.set_max_loop_iterations(max_loop_iterations);
+    }
     let compiled_program = driver.into_compiled_program(backend_ptr);
 
     // Parse the initial witness values
     let curr_dir = std::env::current_dir().unwrap();
-    let witness_map = noirc_abi::input_parser::Format::Toml.parse(curr_dir, PROVER_INPUT_FILE);
+    let abi = compiled_program.abi.as_ref().unwrap();
+    let witness_map = noirc_abi::input_parser::Format::Toml
+        .parse(curr_dir, PROVER_INPUT_FILE, abi)
+        .unwrap_or_else(|err| write_stderr(&err.to_string()));
 
     // Check that enough witness values were supplied
-    let num_params = compiled_program.abi.as_ref().unwrap().num_parameters();
+    let num_params = abi.num_parameters();
     if num_params != witness_map.len() {
         panic!(
             "Expected {} number of values, but got {} number of values",
@@ -51,10 +56,7 @@ fn prove(proof_name: &str) {
         .solve(&mut solved_witness, compiled_program.circuit.gates.clone());
     match solver_res {
         Ok(_) => {}
-        Err(opcode) => write_stderr(&format!(
-            "backend does not currently support the {} opcode. ACVM does not currently fall back to arithmetic gates.",
-            opcode
-        )),
+        Err(err) => write_stderr(&err.to_string()),
     }
 
     let proof = backend_ptr
@@ -94,26 +96,50 @@ fn process_abi_with_input(
             ))
             .clone();
 
-        if !value.matches_abi(param_type) {
+        if !value.matches_abi(param_type.clone()) {
             write_stderr(&format!("The parameters in the main do not match the parameters in the {}.toml file. \n Please check `{}` parameter ", PROVER_INPUT_FILE,param_name))
         }
 
-        match value {
-            InputValue::Field(element) => {
-                let old_value =
-                    solved_witness.insert(Witness::new(index + WITNESS_OFFSET), element);
-                assert!(old_value.is_none());
-                index += 1;
-            }
-            InputValue::Vec(arr) => {
-                for element in arr {
-                    let old_value =
-                        solved_witness.insert(Witness::new(index + WITNESS_OFFSET), element);
-                    assert!(old_value.is_none());
-                    index += 1;
-                }
-            }
+        let value = value.into_twos_complement(&param_type);
+
+        let mut elements = Vec::new();
+        flatten_input_value(value, &param_type, &mut elements);
+
+        for element in elements {
+            let old_value = solved_witness.insert(Witness::new(index + WITNESS_OFFSET), element);
+            assert!(old_value.is_none());
+            index += 1;
         }
     }
     solved_witness
 }
+
+/// Flattens an input value into its field elements.
+/// Struct fields are laid out in the order that they are declared in the ABI,
+/// which is the order that the evaluator allocates their witnesses in.
+/// Tuples are already given as the flattened list of their elements.
+fn flatten_input_value(value: InputValue, abi_type: &AbiType, elements: &mut Vec<FieldElement>) {
+    match (value, abi_type) {
+        (InputValue::Field(element), _) => elements.push(element),
+        (InputValue::Vec(arr), _) => elements.extend(arr),
+        (InputValue::String(string), _) => {
+            elements.extend(string.bytes().map(|byte| FieldElement::from(byte as i128)))
+        }
+        (
+            InputValue::Struct(mut fields),
+            AbiType::Struct {
+                fields: field_types,
+            },
+        ) => {
+            for (field_name, field_type) in field_types {
+                let field_value = fields
+                    .remove(field_name)
+                    .expect("ice: the input value was checked against the abi");
+                flatten_input_value(field_value, field_type, elements);
+            }
+        }
+        (InputValue::Struct(_), _) => {
+            unreachable!("ice: the input value was checked against the abi")
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::{PROOFS_DIR, PROOF_EXT, VERIFIER_INPUT_FILE};
+use super::{max_loop_iterations, PROOFS_DIR, PROOF_EXT, VERIFIER_INPUT_FILE};
 use crate::resolver::Resolver;
 use crate::write_stderr;
 use clap::ArgMatches;
@@ -13,24 +13,30 @@ use std::{collections::BTreeMap, path::Path};
 pub const RESERVED_PUBLIC_ARR: &str = "setpub";
 
 pub(crate) fn run(args: ArgMatches) {
-    let proof_name = args
-        .subcommand_matches("verify")
-        .unwrap()
-        .value_of("proof")
-        .unwrap();
+    let cmd = args.subcommand_matches("verify").unwrap();
+    let proof_name = cmd.value_of("proof").unwrap();
     let mut proof_path = std::path::PathBuf::new();
     proof_path.push(Path::new(PROOFS_DIR));
 
     proof_path.push(Path::new(proof_name));
     proof_path.set_extension(PROOF_EXT);
 
-    let result = verify(proof_name);
+    let result = match verify(proof_name, max_loop_iterations(cmd)) {
+        Ok(result) => result,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1)
+        }
+    };
     println!("Proof verified : {}\n", result);
 }
 
-fn verify(proof_name: &str) -> bool {
+fn verify(proof_name: &str, max_loop_iterations: Option<u64>) -> Result<bool, String> {
     let curr_dir = std::env::current_dir().unwrap();
     let (mut driver, backend_ptr) = Resolver::resolve_root_config(&curr_dir);
+    if let Some(max_loop_iterations) = max_loop_iterations {
+        driver.set_max_loop_iterations(max_loop_iterations);
+    }
     let compiled_program = driver.into_compiled_program(backend_ptr);
 
     let mut proof_path = curr_dir;
@@ -45,7 +51,9 @@ fn verify(proof_name: &str) -> bool {
     let mut public_inputs = BTreeMap::new();
     if num_params != 0 {
         let curr_dir = std::env::current_dir().unwrap();
-        public_inputs = noirc_abi::input_parser::Format::Toml.parse(curr_dir, VERIFIER_INPUT_FILE);
+        public_inputs = noirc_abi::input_parser::Format::Toml
+            .parse(curr_dir, VERIFIER_INPUT_FILE, &public_abi)
+            .unwrap_or_else(|err| write_stderr(&err.to_string()));
     }
 
     if num_params != public_inputs.len() {
@@ -56,22 +64,22 @@ fn verify(proof_name: &str) -> bool {
         )
     }
 
-    let public_inputs = process_abi_with_verifier_input(public_abi, public_inputs);
+    let public_inputs = process_abi_with_verifier_input(public_abi, public_inputs)?;
 
     // XXX: Instead of unwrap, return a PathNotValidError
     let proof_hex: Vec<_> = std::fs::read(proof_path).unwrap();
     // XXX: Instead of unwrap, return a ProofNotValidError
     let proof = hex::decode(proof_hex).unwrap();
 
-    backend_ptr
+    Ok(backend_ptr
         .backend()
-        .verify_from_cs(&proof, public_inputs, compiled_program.circuit)
+        .verify_from_cs(&proof, public_inputs, compiled_program.circuit))
 }
 
 fn process_abi_with_verifier_input(
     abi: Abi,
     pi_map: BTreeMap<String, InputValue>,
-) -> Vec<FieldElement> {
+) -> Result<Vec<FieldElement>, String> {
     let mut public_inputs = Vec::with_capacity(pi_map.len());
 
     for (param_name, param_type) in abi.parameters.into_iter() {
@@ -83,17 +91,26 @@ fn process_abi_with_verifier_input(
             ))
             .clone();
 
-        if !value.matches_abi(param_type) && param_name != RESERVED_PUBLIC_ARR {
+        if !value.matches_abi(param_type.clone()) && param_name != RESERVED_PUBLIC_ARR {
             write_stderr(&format!("The parameters in the main do not match the parameters in the {}.toml file. \n Please check `{}` parameter. ", VERIFIER_INPUT_FILE,param_name))
         }
 
-        match value {
+        match value.into_twos_complement(&param_type) {
             InputValue::Field(elem) => public_inputs.push(elem),
             InputValue::Vec(vec_elem) => public_inputs.extend(vec_elem),
+            InputValue::String(string) => {
+                public_inputs.extend(string.bytes().map(|byte| FieldElement::from(byte as i128)))
+            }
+            InputValue::Struct(_) => {
+                return Err(format!(
+                    "structs cannot be public inputs. Please check `{}` parameter. ",
+                    param_name
+                ))
+            }
         }
     }
 
-    public_inputs
+    Ok(public_inputs)
 }
 
 use noirc_abi::{AbiFEType, AbiType};
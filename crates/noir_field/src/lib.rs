@@ -237,6 +237,25 @@ impl FieldElement {
     pub fn xor(&self, rhs: &FieldElement, num_bits: u32) -> FieldElement {
         self.and_xor(rhs, num_bits, true)
     }
+
+    pub fn pow_of_two(exponent: u32) -> FieldElement {
+        let mut result = FieldElement::one();
+        for _ in 0..exponent {
+            result = result + result;
+        }
+        result
+    }
+    /// Returns the two's complement representation of this element, using `num_bits` bits.
+    /// A negative value `-x` is represented by the field element `p - x`,
+    /// which is converted to `2^num_bits - x`
+    pub fn twos_complement(&self, num_bits: u32) -> FieldElement {
+        let negated = -*self;
+        if negated.num_bits() < self.num_bits() {
+            FieldElement::pow_of_two(num_bits) - negated
+        } else {
+            *self
+        }
+    }
 }
 
 // Taken from matter-labs: https://github.com/matter-labs/zksync/blob/6bfe1c06f5c00519ce14adf9827086119a50fae2/core/models/src/primitives.rs#L243
@@ -3,8 +3,9 @@ mod toml;
 use std::{collections::BTreeMap, path::Path};
 
 use noir_field::FieldElement;
+use thiserror::Error;
 
-use crate::AbiType;
+use crate::{Abi, AbiType, Sign};
 
 /// This is what all formats eventually transform into
 /// For example, a toml file will parse into TomlTypes
@@ -12,7 +13,25 @@ use crate::AbiType;
 #[derive(Debug, Clone)]
 pub enum InputValue {
     Field(FieldElement),
+    // The elements of an array or tuple, nested arrays are flattened in row-major order
     Vec(Vec<FieldElement>),
+    // Strings are passed to the circuit as their bytes
+    String(String),
+    Struct(BTreeMap<String, InputValue>),
+}
+
+#[derive(Error, Debug)]
+pub enum InputParserError {
+    #[error("arrays of structs are not supported in the input file, found one for {0}")]
+    ArrayOfStructs(String),
+    // An array of a multi-dimensional array parameter does not have
+    // the length of the dimension that it is in
+    #[error("expected the arrays of {parameter} to have {expected} elements, found one with {found} elements")]
+    ArrayLength {
+        parameter: String,
+        expected: usize,
+        found: usize,
+    },
 }
 
 impl InputValue {
@@ -22,14 +41,121 @@ impl InputValue {
         match (self, abi_param) {
             (InputValue::Field(_), AbiType::Field(_)) => true,
             (InputValue::Field(_), AbiType::Array { .. }) => false,
-            (InputValue::Field(_), AbiType::Integer { .. }) => true,
+            (InputValue::Field(value), abi_param @ AbiType::Integer { .. }) => {
+                is_in_range(value, &abi_param)
+            }
+            (InputValue::Field(_), AbiType::Boolean) => true,
+            (InputValue::Field(_), AbiType::Enum { .. }) => true,
             (InputValue::Vec(_), AbiType::Field(_)) => false,
-            (InputValue::Vec(x), AbiType::Array { length, .. }) => x.len() == length as usize,
+            // Nested arrays are passed in as the list of their flattened elements
+            (InputValue::Vec(x), abi_param @ AbiType::Array { .. }) => {
+                x.len() == abi_param.num_elements() && all_in_range(x, &abi_param)
+            }
             (InputValue::Vec(_), AbiType::Integer { .. }) => false,
+            (InputValue::Vec(_), AbiType::Boolean) => false,
+            (InputValue::Vec(_), AbiType::Enum { .. }) => false,
+            (InputValue::Vec(_), AbiType::Struct { .. }) => false,
+            // Tuples are passed in as the list of their flattened elements
+            (InputValue::Vec(x), abi_param @ AbiType::Tuple { .. }) => {
+                x.len() == abi_param.num_elements() && all_in_range(x, &abi_param)
+            }
+            (InputValue::Field(_), AbiType::Tuple { .. }) => false,
+            (InputValue::Field(_), AbiType::Struct { .. }) => false,
+            (InputValue::Struct(map), AbiType::Struct { fields }) => {
+                map.len() == fields.len()
+                    && fields.into_iter().all(|(field_name, field_type)| {
+                        map.get(&field_name)
+                            .map_or(false, |value| value.matches_abi(field_type))
+                    })
+            }
+            (InputValue::Struct(_), _) => false,
+            // A `str<N>` parameter is an array of `N` bytes
+            (InputValue::String(string), AbiType::Array { length, typ, .. }) => {
+                let is_byte = match *typ {
+                    AbiType::Integer {
+                        sign: Sign::Unsigned,
+                        width: 8,
+                        ..
+                    } => true,
+                    _ => false,
+                };
+                is_byte && string.len() == length as usize
+            }
+            (InputValue::String(_), _) => false,
+        }
+    }
+
+    /// Signed integers are passed to the circuit in two's complement.
+    ///
+    /// A negative value `-x` is parsed as the field element `p - x`,
+    /// this converts it to `2^width - x` for each signed integer in the ABI type.
+    /// The value is expected to have been checked with `matches_abi`, which rejects
+    /// signed integers that do not fit in their width.
+    pub fn into_twos_complement(self, abi_param: &AbiType) -> InputValue {
+        match (self, abi_param) {
+            (
+                InputValue::Field(value),
+                AbiType::Integer {
+                    sign: Sign::Signed,
+                    width,
+                    ..
+                },
+            ) => InputValue::Field(value.twos_complement(*width)),
+            (InputValue::Vec(values), AbiType::Array { .. })
+            | (InputValue::Vec(values), AbiType::Tuple { .. }) => InputValue::Vec(
+                values
+                    .into_iter()
+                    .zip(abi_param.flattened_types())
+                    .map(|(value, typ)| match typ {
+                        AbiType::Integer {
+                            sign: Sign::Signed,
+                            width,
+                            ..
+                        } => value.twos_complement(*width),
+                        _ => value,
+                    })
+                    .collect(),
+            ),
+            (InputValue::Struct(mut values), AbiType::Struct { fields }) => {
+                for (field_name, field_type) in fields {
+                    if let Some(value) = values.remove(field_name) {
+                        values.insert(field_name.clone(), value.into_twos_complement(field_type));
+                    }
+                }
+                InputValue::Struct(values)
+            }
+            (value, _) => value,
         }
     }
 }
 
+// A signed integer of `width` bits lies in [-2^(width-1), 2^(width-1)),
+// the values of the other types are range constrained by the circuit
+fn is_in_range(value: &FieldElement, abi_param: &AbiType) -> bool {
+    match abi_param {
+        AbiType::Integer {
+            sign: Sign::Signed,
+            width,
+            ..
+        } => {
+            let negated = -*value;
+            if negated.num_bits() < value.num_bits() {
+                negated.num_bits() < *width || negated == FieldElement::pow_of_two(width - 1)
+            } else {
+                value.num_bits() < *width
+            }
+        }
+        _ => true,
+    }
+}
+
+fn all_in_range(values: &[FieldElement], abi_param: &AbiType) -> bool {
+    values
+        .iter()
+        .zip(abi_param.flattened_types())
+        .all(|(value, typ)| is_in_range(value, typ))
+}
+
 /// Parses the initial Witness Values that are needed to seed the
 /// Partial Witness generator
 pub trait InitialWitnessParser {
@@ -51,14 +177,81 @@ impl Format {
 }
 
 impl Format {
-    pub fn parse<P: AsRef<Path>>(&self, path: P, file_name: &str) -> BTreeMap<String, InputValue> {
+    /// Parses the values of the parameters of `abi` from the file `file_name` in `path`
+    pub fn parse<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_name: &str,
+        abi: &Abi,
+    ) -> Result<BTreeMap<String, InputValue>, InputParserError> {
         match self {
             Format::Toml => {
                 let mut dir_path = path.as_ref().to_path_buf();
                 dir_path.push(file_name);
                 dir_path.set_extension(self.ext());
-                toml::parse(dir_path)
+                toml::parse(dir_path, abi)
             }
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::InputValue;
+    use crate::{AbiFEType, AbiType, Sign};
+    use noir_field::FieldElement;
+
+    fn i8_type() -> AbiType {
+        AbiType::Integer {
+            visibility: AbiFEType::Private,
+            sign: Sign::Signed,
+            width: 8,
+        }
+    }
+
+    fn field(value: i128) -> InputValue {
+        InputValue::Field(FieldElement::from(value))
+    }
+
+    #[test]
+    fn signed_integers_in_range() {
+        for value in &[-128, -1, 0, 1, 127] {
+            assert!(field(*value).matches_abi(i8_type()), "{} is an i8", value);
+        }
+    }
+
+    #[test]
+    fn signed_integers_out_of_range() {
+        for value in &[-129, 128, 255, -1000] {
+            assert!(
+                !field(*value).matches_abi(i8_type()),
+                "{} is not an i8",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn signed_array_elements_out_of_range() {
+        let array_type = AbiType::Array {
+            visibility: AbiFEType::Private,
+            length: 2,
+            typ: Box::new(i8_type()),
+        };
+        let array = |a, b| InputValue::Vec(vec![FieldElement::from(a), FieldElement::from(b)]);
+        assert!(array(-128, 127).matches_abi(array_type.clone()));
+        assert!(!array(-128, 128).matches_abi(array_type));
+    }
+
+    #[test]
+    fn twos_complement() {
+        let convert = |value| match field(value).into_twos_complement(&i8_type()) {
+            InputValue::Field(value) => value,
+            _ => unreachable!(),
+        };
+        assert_eq!(convert(-1), FieldElement::from(255));
+        assert_eq!(convert(-128), FieldElement::from(128));
+        assert_eq!(convert(127), FieldElement::from(127));
+        assert_eq!(convert(0), FieldElement::zero());
+    }
+}
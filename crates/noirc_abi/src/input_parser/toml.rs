@@ -2,9 +2,13 @@ use noir_field::FieldElement;
 use serde_derive::Deserialize;
 use std::{collections::BTreeMap, path::Path};
 
-use super::InputValue;
+use super::{InputParserError, InputValue};
+use crate::{Abi, AbiType};
 
-pub(crate) fn parse<P: AsRef<Path>>(path_to_toml: P) -> BTreeMap<String, InputValue> {
+pub(crate) fn parse<P: AsRef<Path>>(
+    path_to_toml: P,
+    abi: &Abi,
+) -> Result<BTreeMap<String, InputValue>, InputParserError> {
     let path_to_toml = path_to_toml.as_ref();
     assert!(
         path_to_toml.exists(),
@@ -15,23 +19,46 @@ pub(crate) fn parse<P: AsRef<Path>>(path_to_toml: P) -> BTreeMap<String, InputVa
     // Get input.toml file as a string
     let input_as_string = std::fs::read_to_string(path_to_toml).unwrap();
 
+    parse_toml_str(&input_as_string, abi)
+}
+
+fn parse_toml_str(
+    input_as_string: &str,
+    abi: &Abi,
+) -> Result<BTreeMap<String, InputValue>, InputParserError> {
     // Parse input.toml into a BTreeMap, converting the argument to field elements
     let data: BTreeMap<String, TomlTypes> =
-        toml::from_str(&input_as_string).expect("input.toml file is badly formed, could not parse");
+        toml::from_str(input_as_string).expect("input.toml file is badly formed, could not parse");
 
-    toml_map_to_field(data)
+    toml_map_to_field(data, &abi.parameters)
 }
 
 /// Converts the Toml mapping to the native representation that the compiler
 /// understands for Inputs
-fn toml_map_to_field(toml_map: BTreeMap<String, TomlTypes>) -> BTreeMap<String, InputValue> {
+///
+/// The types of the parameters are used to tell strings apart from numbers,
+/// since both are written as TOML strings.
+fn toml_map_to_field(
+    toml_map: BTreeMap<String, TomlTypes>,
+    parameters: &[(String, AbiType)],
+) -> Result<BTreeMap<String, InputValue>, InputParserError> {
     let mut field_map = BTreeMap::new();
 
     for (parameter, value) in toml_map {
+        let abi_type = parameters
+            .iter()
+            .find(|(name, _)| name == &parameter)
+            .map(|(_, typ)| typ);
+
         match value {
             TomlTypes::String(string) => {
-                let old_value =
-                    field_map.insert(parameter.clone(), InputValue::Field(parse_str(&string)));
+                // A string parameter is an array of bytes, so a string
+                // such as "12" is only parsed as a number for the other types
+                let value = match (abi_type, try_parse_str(&string)) {
+                    (Some(AbiType::Array { .. }), _) | (_, None) => InputValue::String(string),
+                    (_, Some(value)) => InputValue::Field(value),
+                };
+                let old_value = field_map.insert(parameter.clone(), value);
                 assert!(old_value.is_none(), "duplicate variable name {}", parameter);
             }
             TomlTypes::Integer(integer) => {
@@ -41,6 +68,11 @@ fn toml_map_to_field(toml_map: BTreeMap<String, TomlTypes>) -> BTreeMap<String,
                 );
                 assert!(old_value.is_none(), "duplicate variable name {}", parameter);
             }
+            TomlTypes::Bool(boolean) => {
+                let old_value =
+                    field_map.insert(parameter.clone(), InputValue::Field(parse_bool(boolean)));
+                assert!(old_value.is_none(), "duplicate variable name {}", parameter);
+            }
             TomlTypes::ArrayNum(arr_num) => {
                 let array_elements: Vec<_> = arr_num
                     .into_iter()
@@ -61,24 +93,133 @@ fn toml_map_to_field(toml_map: BTreeMap<String, TomlTypes>) -> BTreeMap<String,
                     field_map.insert(parameter.clone(), InputValue::Vec(array_elements));
                 assert!(old_value.is_none(), "duplicate variable name {}", parameter);
             }
+            TomlTypes::ArrayBool(arr_bool) => {
+                let array_elements: Vec<_> = arr_bool.into_iter().map(parse_bool).collect();
+
+                let old_value =
+                    field_map.insert(parameter.clone(), InputValue::Vec(array_elements));
+                assert!(old_value.is_none(), "duplicate variable name {}", parameter);
+            }
+            array @ TomlTypes::ArrayNested(_) => {
+                let mut array_elements = Vec::new();
+                flatten_toml_array(&parameter, array, abi_type, &mut array_elements)?;
+
+                let old_value =
+                    field_map.insert(parameter.clone(), InputValue::Vec(array_elements));
+                assert!(old_value.is_none(), "duplicate variable name {}", parameter);
+            }
+            TomlTypes::Table(table) => {
+                let fields = match abi_type {
+                    Some(AbiType::Struct { fields }) => fields.as_slice(),
+                    _ => &[],
+                };
+                let struct_fields = toml_map_to_field(table, fields)?;
+
+                let old_value =
+                    field_map.insert(parameter.clone(), InputValue::Struct(struct_fields));
+                assert!(old_value.is_none(), "duplicate variable name {}", parameter);
+            }
         }
     }
 
-    field_map
+    Ok(field_map)
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 enum TomlTypes {
-    // This is most likely going to be a hex string
-    // But it is possible to support utf8
+    // This is most likely going to be a hex string,
+    // unless it is the value of a string parameter
     String(String),
-    // Just a regular integer, that can fit in 128 bits
-    Integer(u64),
+    // Just a regular integer, which may be negative for signed integer parameters
+    Integer(i64),
+    // Either `true` or `false`, which is passed to the circuit as 1 or 0
+    Bool(bool),
     // Array of regular integers
-    ArrayNum(Vec<u64>),
+    ArrayNum(Vec<i64>),
     // Array of hexadecimal integers
     ArrayString(Vec<String>),
+    // Array of booleans
+    ArrayBool(Vec<bool>),
+    // Array of arrays, for multi-dimensional array parameters
+    ArrayNested(Vec<TomlTypes>),
+    // Struct of values, the keys are the field names
+    Table(BTreeMap<String, TomlTypes>),
+}
+
+// Flattens an array of `parameter` into its elements.
+// The rows of a nested array are flattened in order, which is the row-major order of the
+// witnesses in the ABI. So each array must have the length of its dimension in `abi_type`
+fn flatten_toml_array(
+    parameter: &str,
+    array: TomlTypes,
+    abi_type: Option<&AbiType>,
+    elements: &mut Vec<FieldElement>,
+) -> Result<(), InputParserError> {
+    let (expected_length, element_type) = match abi_type {
+        Some(AbiType::Array { length, typ, .. }) => (Some(*length as usize), Some(typ.as_ref())),
+        _ => (None, None),
+    };
+
+    let length = match array {
+        TomlTypes::String(string) => {
+            elements.push(parse_str(&string));
+            return Ok(());
+        }
+        TomlTypes::Integer(integer) => {
+            elements.push(parse_str(&integer.to_string()));
+            return Ok(());
+        }
+        TomlTypes::Bool(boolean) => {
+            elements.push(parse_bool(boolean));
+            return Ok(());
+        }
+        TomlTypes::ArrayNum(arr_num) => {
+            let length = arr_num.len();
+            elements.extend(
+                arr_num
+                    .into_iter()
+                    .map(|elem_num| parse_str(&elem_num.to_string())),
+            );
+            length
+        }
+        TomlTypes::ArrayString(arr_str) => {
+            let length = arr_str.len();
+            elements.extend(arr_str.into_iter().map(|elem_str| parse_str(&elem_str)));
+            length
+        }
+        TomlTypes::ArrayBool(arr_bool) => {
+            let length = arr_bool.len();
+            elements.extend(arr_bool.into_iter().map(parse_bool));
+            length
+        }
+        TomlTypes::ArrayNested(rows) => {
+            let length = rows.len();
+            for row in rows {
+                flatten_toml_array(parameter, row, element_type, elements)?;
+            }
+            length
+        }
+        TomlTypes::Table(_) => return Err(InputParserError::ArrayOfStructs(parameter.to_owned())),
+    };
+
+    match expected_length {
+        Some(expected) if expected != length => Err(InputParserError::ArrayLength {
+            parameter: parameter.to_owned(),
+            expected,
+            found: length,
+        }),
+        _ => Ok(()),
+    }
+}
+
+// Parses a string which is either a hex or a decimal number
+fn try_parse_str(value: &str) -> Option<FieldElement> {
+    if value.starts_with("0x") {
+        FieldElement::from_hex(value)
+    } else {
+        value.parse::<i128>().ok().map(FieldElement::from)
+    }
 }
 
 fn parse_str(value: &str) -> FieldElement {
@@ -91,3 +232,154 @@ fn parse_str(value: &str) -> FieldElement {
         FieldElement::from(val)
     }
 }
+
+fn parse_bool(value: bool) -> FieldElement {
+    if value {
+        FieldElement::one()
+    } else {
+        FieldElement::zero()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_toml_str;
+    use crate::input_parser::{InputParserError, InputValue};
+    use crate::{Abi, AbiFEType, AbiType, Sign};
+    use noir_field::FieldElement;
+
+    fn string_type(length: u128) -> AbiType {
+        AbiType::Array {
+            visibility: AbiFEType::Private,
+            length,
+            typ: Box::new(AbiType::Integer {
+                visibility: AbiFEType::Private,
+                sign: Sign::Unsigned,
+                width: 8,
+            }),
+        }
+    }
+
+    #[test]
+    fn numeric_strings_are_parsed_according_to_the_abi() {
+        let abi = Abi {
+            parameters: vec![
+                ("message".to_owned(), string_type(2)),
+                ("x".to_owned(), AbiType::Field(AbiFEType::Private)),
+                ("y".to_owned(), AbiType::Field(AbiFEType::Private)),
+            ],
+        };
+        let inputs = parse_toml_str("message = \"12\"\nx = \"12\"\ny = \"0x0c\"", &abi).unwrap();
+
+        match &inputs["message"] {
+            InputValue::String(string) => assert_eq!(string, "12"),
+            value => panic!("expected a string, found {:?}", value),
+        }
+        for name in &["x", "y"] {
+            match &inputs[*name] {
+                InputValue::Field(value) => assert_eq!(*value, FieldElement::from(12)),
+                value => panic!("expected a field element, found {:?}", value),
+            }
+        }
+    }
+
+    #[test]
+    fn numeric_strings_in_structs_are_parsed_according_to_the_abi() {
+        let abi = Abi {
+            parameters: vec![(
+                "point".to_owned(),
+                AbiType::Struct {
+                    fields: vec![
+                        ("label".to_owned(), string_type(1)),
+                        ("x".to_owned(), AbiType::Field(AbiFEType::Private)),
+                    ],
+                },
+            )],
+        };
+        let inputs = parse_toml_str("[point]\nlabel = \"7\"\nx = \"7\"", &abi).unwrap();
+
+        let fields = match &inputs["point"] {
+            InputValue::Struct(fields) => fields,
+            value => panic!("expected a struct, found {:?}", value),
+        };
+        assert!(fields["label"].matches_abi(string_type(1)));
+        assert!(fields["x"].matches_abi(AbiType::Field(AbiFEType::Private)));
+    }
+
+    // A `[rows][columns]Field` parameter named `matrix`
+    fn matrix_abi(rows: u128, columns: u128) -> Abi {
+        let row_type = AbiType::Array {
+            visibility: AbiFEType::Private,
+            length: columns,
+            typ: Box::new(AbiType::Field(AbiFEType::Private)),
+        };
+        let matrix_type = AbiType::Array {
+            visibility: AbiFEType::Private,
+            length: rows,
+            typ: Box::new(row_type),
+        };
+        Abi {
+            parameters: vec![("matrix".to_owned(), matrix_type)],
+        }
+    }
+
+    #[test]
+    fn nested_arrays_are_flattened_in_row_major_order() {
+        let abi = matrix_abi(2, 3);
+        let inputs =
+            parse_toml_str("matrix = [[1, 2, 3], [\"0x04\", \"5\", \"6\"]]", &abi).unwrap();
+
+        match &inputs["matrix"] {
+            InputValue::Vec(elements) => {
+                let expected: Vec<_> = (1..=6).map(FieldElement::from).collect();
+                assert_eq!(elements, &expected);
+            }
+            value => panic!("expected an array, found {:?}", value),
+        }
+    }
+
+    #[test]
+    fn rows_must_have_the_length_of_their_dimension() {
+        let abi = matrix_abi(2, 3);
+
+        // There are 6 elements, but the rows do not have 3 elements each
+        match parse_toml_str("matrix = [[1, 2], [3, 4, 5, 6]]", &abi) {
+            Err(InputParserError::ArrayLength {
+                expected, found, ..
+            }) => assert_eq!((expected, found), (3, 2)),
+            result => panic!("expected an array length error, found {:?}", result),
+        }
+        // The number of rows is also checked
+        match parse_toml_str("matrix = [[1, 2, 3]]", &abi) {
+            Err(InputParserError::ArrayLength {
+                expected, found, ..
+            }) => assert_eq!((expected, found), (2, 1)),
+            result => panic!("expected an array length error, found {:?}", result),
+        }
+    }
+
+    #[test]
+    fn nested_arrays_in_structs_are_checked() {
+        let abi = Abi {
+            parameters: vec![(
+                "shape".to_owned(),
+                AbiType::Struct {
+                    fields: matrix_abi(2, 2).parameters,
+                },
+            )],
+        };
+
+        assert!(parse_toml_str("[shape]\nmatrix = [[1, 2], [3, 4]]", &abi).is_ok());
+        assert!(parse_toml_str("[shape]\nmatrix = [[1, 2, 3], [4]]", &abi).is_err());
+    }
+
+    #[test]
+    fn arrays_of_structs_are_an_error() {
+        let abi = matrix_abi(2, 1);
+
+        match parse_toml_str("matrix = [{ x = 1 }, { x = 2 }]", &abi) {
+            Err(InputParserError::ArrayOfStructs(parameter)) => assert_eq!(parameter, "matrix"),
+            result => panic!("expected an array of structs error, found {:?}", result),
+        }
+    }
+}
@@ -20,6 +20,8 @@ pub mod input_parser;
 /// support.
 pub enum AbiType {
     Field(AbiFEType),
+    // Multi-dimensional arrays are arrays of arrays. Their elements are
+    // laid out in row-major order, so `a[i][j]` comes before `a[i][j + 1]`
     Array {
         visibility: AbiFEType,
         length: u128,
@@ -30,6 +32,25 @@ pub enum AbiType {
         sign: Sign,
         width: u32,
     },
+    // Booleans are range constrained to be either 0 or 1.
+    // They are always private, since the parser does not accept `pub bool`
+    Boolean,
+    // Enums are passed in as the discriminant of the variant, which is its
+    // position in `variants`. The discriminant is constrained to be less than
+    // the number of variants.
+    Enum {
+        variants: Vec<String>,
+    },
+    // Struct fields are stored in declaration order, which is also
+    // the order in which their witnesses are allocated.
+    Struct {
+        fields: Vec<(String, AbiType)>,
+    },
+    // Tuples are flattened into a single list of elements,
+    // which are laid out in the same order as the tuple.
+    Tuple {
+        fields: Vec<AbiType>,
+    },
 }
 /// This is the same as the FieldElementType in AST, without constants.
 /// We don't want the ABI to depend on Noir, so types are not shared between the two
@@ -54,12 +75,37 @@ pub enum Sign {
 impl AbiType {
     pub fn num_elements(&self) -> usize {
         match self {
-            AbiType::Field(_) | AbiType::Integer { .. } => 1,
-            AbiType::Array {
-                visibility: _,
-                length,
-                typ: _,
-            } => *length as usize,
+            AbiType::Field(_)
+            | AbiType::Integer { .. }
+            | AbiType::Boolean
+            | AbiType::Enum { .. } => 1,
+            // The elements of nested arrays are counted individually
+            AbiType::Array { length, typ, .. } => *length as usize * typ.num_elements(),
+            AbiType::Struct { fields } => fields.iter().map(|(_, typ)| typ.num_elements()).sum(),
+            AbiType::Tuple { fields } => fields.iter().map(AbiType::num_elements).sum(),
+        }
+    }
+
+    /// Returns the type of each field element in the flattened representation of this type
+    pub fn flattened_types(&self) -> Vec<&AbiType> {
+        match self {
+            AbiType::Field(_)
+            | AbiType::Integer { .. }
+            | AbiType::Boolean
+            | AbiType::Enum { .. } => vec![self],
+            AbiType::Array { length, typ, .. } => {
+                let element_types = typ.flattened_types();
+                let mut types = Vec::with_capacity(*length as usize * element_types.len());
+                for _ in 0..*length {
+                    types.extend(element_types.iter().copied());
+                }
+                types
+            }
+            AbiType::Struct { fields } => fields
+                .iter()
+                .flat_map(|(_, typ)| typ.flattened_types())
+                .collect(),
+            AbiType::Tuple { fields } => fields.iter().flat_map(AbiType::flattened_types).collect(),
         }
     }
 
@@ -76,6 +122,11 @@ impl AbiType {
                 sign: _,
                 width: _,
             } => visibility == &AbiFEType::Public,
+            // Booleans, structs, tuples and enums cannot be declared as public inputs
+            AbiType::Boolean
+            | AbiType::Struct { .. }
+            | AbiType::Tuple { .. }
+            | AbiType::Enum { .. } => false,
         }
     }
 }
@@ -114,8 +165,18 @@ impl Serialize for Abi {
         for (param_name, param_type) in &self.parameters {
             match param_type {
                 AbiType::Field(_) => map.serialize_entry(&param_name, "")?,
-                AbiType::Array { .. } => map.serialize_entry(&param_name, &vec)?,
+                AbiType::Array { .. } | AbiType::Tuple { .. } => {
+                    map.serialize_entry(&param_name, &vec)?
+                }
                 AbiType::Integer { .. } => map.serialize_entry(&param_name, "")?,
+                AbiType::Boolean => map.serialize_entry(&param_name, "")?,
+                AbiType::Enum { .. } => map.serialize_entry(&param_name, "")?,
+                AbiType::Struct { fields } => {
+                    let struct_abi = Abi {
+                        parameters: fields.clone(),
+                    };
+                    map.serialize_entry(&param_name, &struct_abi)?
+                }
             };
         }
         map.end()
@@ -3,8 +3,8 @@ use acvm::BackendPointer;
 use fm::FileType;
 use noirc_abi::Abi;
 use noirc_errors::DiagnosableError;
-use noirc_errors::Reporter;
-use noirc_evaluator::Evaluator;
+use noirc_errors::{CollectedErrors, Reporter};
+use noirc_evaluator::{Evaluator, RuntimeErrorKind};
 use noirc_frontend::graph::{CrateId, CrateName, CrateType, LOCAL_CRATE};
 use noirc_frontend::hir::def_map::CrateDefMap;
 use noirc_frontend::hir::Context;
@@ -13,6 +13,9 @@ use std::path::{Path, PathBuf};
 #[derive(Debug)]
 pub struct Driver {
     context: Context,
+    // The number of iterations after which unrolling a while loop fails.
+    // The evaluator's default is used if this is not set
+    max_loop_iterations: Option<u64>,
 }
 pub struct CompiledProgram {
     pub circuit: Circuit,
@@ -23,9 +26,15 @@ impl Driver {
     pub fn new() -> Self {
         Driver {
             context: Context::default(),
+            max_loop_iterations: None,
         }
     }
 
+    /// Sets the number of iterations after which unrolling a while loop fails
+    pub fn set_max_loop_iterations(&mut self, max_loop_iterations: u64) {
+        self.max_loop_iterations = Some(max_loop_iterations);
+    }
+
     // This is here for backwards compatibility
     // with the restricted version which only uses one file
     pub fn compile_file(root_file: PathBuf, backend: BackendPointer) -> CompiledProgram {
@@ -41,7 +50,7 @@ impl Driver {
         let mut driver = Driver::new();
         driver.create_local_crate(root_file, CrateType::Binary);
         driver.add_std_lib();
-        if let Err(errs) = CrateDefMap::collect_defs(LOCAL_CRATE, &mut driver.context) {
+        if let Err(errs) = driver.check_crate() {
             for errors in errs {
                 dbg!(errors);
             }
@@ -130,7 +139,7 @@ impl Driver {
     }
 
     fn analyse_crate(&mut self) {
-        if let Err(errs) = CrateDefMap::collect_defs(LOCAL_CRATE, &mut self.context) {
+        if let Err(errs) = self.check_crate() {
             for errors in errs {
                 Reporter::with_diagnostics(
                     errors.file_id.as_usize(),
@@ -143,6 +152,11 @@ impl Driver {
         }
     }
 
+    /// Statically analyses the local crate, returning the errors instead of reporting them
+    pub fn check_crate(&mut self) -> Result<(), Vec<CollectedErrors>> {
+        CrateDefMap::collect_defs(LOCAL_CRATE, &mut self.context)
+    }
+
     pub fn compute_abi(&self) -> Option<Abi> {
         let local_crate = self.context.def_map(LOCAL_CRATE).unwrap();
 
@@ -169,6 +183,30 @@ impl Driver {
             std::process::exit(1);
         };
 
+        match self.compile_main(backend) {
+            Ok(compiled_program) => compiled_program,
+            Err(err) => {
+                // The FileId here will be the file id of the file with the main file
+                // Errors will be shown at the callsite without a stacktrace
+                Reporter::with_diagnostics(
+                    file_id,
+                    &self.context.file_manager,
+                    &vec![err.to_diagnostic()],
+                );
+                std::process::exit(1);
+            }
+        }
+    }
+
+    /// Evaluates the main function of the local crate into a circuit.
+    ///
+    /// The local crate must have been analysed beforehand.
+    pub fn compile_main(
+        &self,
+        backend: BackendPointer,
+    ) -> Result<CompiledProgram, RuntimeErrorKind> {
+        let local_crate = self.context.def_map(LOCAL_CRATE).unwrap();
+
         // All Binaries should have a main function
         let main_function = local_crate
             .main_function()
@@ -178,27 +216,18 @@ impl Driver {
         let func_meta = self.context.def_interner.function_meta(&main_function);
         let abi = func_meta.parameters.to_abi(&self.context.def_interner);
 
-        let evaluator = Evaluator::new(main_function, &self.context);
+        let mut evaluator = Evaluator::new(main_function, &self.context);
+        if let Some(max_loop_iterations) = self.max_loop_iterations {
+            evaluator = evaluator.with_max_loop_iterations(max_loop_iterations);
+        }
 
         // Compile Program
-        let circuit = match evaluator.compile(backend) {
-            Ok(circuit) => circuit,
-            Err(err) => {
-                // The FileId here will be the file id of the file with the main file
-                // Errors will be shown at the callsite without a stacktrace
-                Reporter::with_diagnostics(
-                    file_id,
-                    &self.context.file_manager,
-                    &vec![err.to_diagnostic()],
-                );
-                std::process::exit(1);
-            }
-        };
+        let circuit = evaluator.compile(backend)?;
 
-        CompiledProgram {
+        Ok(CompiledProgram {
             circuit,
             abi: Some(abi),
-        }
+        })
     }
 
     /// XXX: It is sub-optimal to add the std as a regular crate right now because
@@ -0,0 +1,58 @@
+mod common;
+
+use acvm::acir::circuit::gate::{Directive, Gate};
+use common::{compile, field, solve};
+
+#[test]
+fn bitwise_and_shift_operators() {
+    let program = compile("bitwise").unwrap();
+
+    let inputs = |x, y, or, not, left, right| {
+        vec![
+            ("x", field(x)),
+            ("y", field(y)),
+            ("o", field(or)),
+            ("n", field(not)),
+            ("l", field(left)),
+            ("r", field(right)),
+        ]
+    };
+    assert!(solve(&program, inputs(202, 21, 223, 53, 80, 50)).is_ok());
+    assert!(solve(&program, inputs(255, 0, 255, 0, 248, 63)).is_ok());
+    // The bits which are shifted out of the integer are dropped
+    assert!(solve(&program, inputs(202, 21, 223, 53, 1616, 50)).is_err());
+    assert!(solve(&program, inputs(202, 21, 202, 53, 80, 50)).is_err());
+    assert!(solve(&program, inputs(202, 21, 223, 52, 80, 50)).is_err());
+    assert!(solve(&program, inputs(202, 21, 223, 53, 80, 51)).is_err());
+}
+
+#[test]
+fn shifts_use_the_truncate_directive() {
+    let program = compile("bitwise").unwrap();
+
+    let has_truncate = program.circuit.gates.iter().any(|gate| match gate {
+        Gate::Directive(Directive::Truncate { .. }) => true,
+        _ => false,
+    });
+    assert!(has_truncate);
+}
+
+#[test]
+fn bitwise_operators_with_constant_masks() {
+    let program = compile("bitwise_constant_masks").unwrap();
+
+    let inputs = |x, high, low, flipped| {
+        vec![
+            ("x", field(x)),
+            ("high", field(high)),
+            ("low", field(low)),
+            ("flipped", field(flipped)),
+        ]
+    };
+    assert!(solve(&program, inputs(0x25, 0xa5, 0x05, 0xda)).is_ok());
+    assert!(solve(&program, inputs(0xf3, 0xf3, 0x03, 0x0c)).is_ok());
+    assert!(solve(&program, inputs(0, 0x80, 0, 0xff)).is_ok());
+    assert!(solve(&program, inputs(0x25, 0x25, 0x05, 0xda)).is_err());
+    assert!(solve(&program, inputs(0x25, 0xa5, 0x25, 0xda)).is_err());
+    assert!(solve(&program, inputs(0x25, 0xa5, 0x05, 0x25)).is_err());
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use common::{compile, field, solve};
+
+#[test]
+fn booleans_are_field_elements_in_arithmetic() {
+    let program = compile("bool_arithmetic").unwrap();
+
+    let inputs = |a, b, x, count| {
+        vec![
+            ("a", field(a)),
+            ("b", field(b)),
+            ("x", field(x)),
+            ("count", field(count)),
+        ]
+    };
+    assert!(solve(&program, inputs(0, 0, 5, 0)).is_ok());
+    assert!(solve(&program, inputs(1, 0, 5, 1)).is_ok());
+    assert!(solve(&program, inputs(1, 1, 5, 2)).is_ok());
+    assert!(solve(&program, inputs(1, 1, 5, 1)).is_err());
+}
+
+#[test]
+fn boolean_parameters_are_either_0_or_1() {
+    let program = compile("bool_arithmetic").unwrap();
+
+    let inputs = vec![
+        ("a", field(2)),
+        ("b", field(0)),
+        ("x", field(5)),
+        ("count", field(2)),
+    ];
+    assert!(solve(&program, inputs).is_err());
+}
@@ -0,0 +1,188 @@
+// Helpers for the tests which compile the programs in tests/evaluator/ into circuits,
+// then solve the witness for some inputs and check that every gate is satisfied.
+//
+// Not every test file uses every helper
+#![allow(dead_code)]
+
+use acvm::acir::circuit::Gate;
+use acvm::acir::native_types::Witness;
+use noir_field::FieldElement;
+use noirc_abi::input_parser::InputValue;
+use noirc_abi::AbiType;
+use noirc_driver::{CompiledProgram, Driver};
+use noirc_evaluator::RuntimeErrorKind;
+use noirc_frontend::graph::CrateType;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Compiles the program `tests/evaluator/<name>.nr`
+///
+/// Panics if the program does not pass the static analysis
+pub fn compile(name: &str) -> Result<CompiledProgram, RuntimeErrorKind> {
+    driver(name).compile_main(acvm::BackendPointer::default())
+}
+
+/// Returns a driver for the program `tests/evaluator/<name>.nr`, which has been analysed
+pub fn driver(name: &str) -> Driver {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("tests/evaluator");
+    path.push(name);
+    path.set_extension("nr");
+
+    let mut driver = Driver::new();
+    driver.create_local_crate(&path, CrateType::Binary);
+    driver.add_std_lib();
+    if let Err(errs) = driver.check_crate() {
+        panic!("{} does not compile: {:?}", path.display(), errs);
+    }
+    driver
+}
+
+/// Solves the witness of a program for the given values of the parameters of main.
+///
+/// Returns an error describing the first gate which is not satisfied.
+pub fn solve(
+    program: &CompiledProgram,
+    inputs: Vec<(&str, InputValue)>,
+) -> Result<BTreeMap<Witness, FieldElement>, String> {
+    let mut inputs: BTreeMap<_, _> = inputs.into_iter().collect();
+
+    // The parameters are assigned to the witnesses in the order of the ABI, starting from 1
+    let mut values = Vec::new();
+    let abi = program.abi.clone().expect("programs have an abi");
+    for (name, typ) in abi.parameters.iter() {
+        let value = inputs
+            .remove(name.as_str())
+            .unwrap_or_else(|| panic!("missing input for the parameter {}", name));
+        assert!(
+            value.matches_abi(typ.clone()),
+            "{} does not match the abi",
+            name
+        );
+        flatten_input(value.into_twos_complement(typ), typ, &mut values);
+    }
+    let mut witness: BTreeMap<_, _> = values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| (Witness(index as u32 + 1), value))
+        .collect();
+
+    let backend = acvm::BackendPointer::default().backend();
+    backend
+        .solve(&mut witness, program.circuit.gates.clone())
+        .map_err(|err| err.to_string())?;
+
+    for gate in program.circuit.gates.iter() {
+        check_gate(gate, &witness)?;
+    }
+    Ok(witness)
+}
+
+pub fn field(value: i128) -> InputValue {
+    InputValue::Field(FieldElement::from(value))
+}
+
+pub fn array(values: &[i128]) -> InputValue {
+    InputValue::Vec(
+        values
+            .iter()
+            .map(|value| FieldElement::from(*value))
+            .collect(),
+    )
+}
+
+pub fn structure(fields: Vec<(&str, InputValue)>) -> InputValue {
+    InputValue::Struct(
+        fields
+            .into_iter()
+            .map(|(name, value)| (name.to_owned(), value))
+            .collect(),
+    )
+}
+
+// Struct fields are laid out in the order that they are declared in the ABI
+fn flatten_input(value: InputValue, typ: &AbiType, values: &mut Vec<FieldElement>) {
+    match (value, typ) {
+        (InputValue::Field(value), _) => values.push(value),
+        (InputValue::Vec(elements), _) => values.extend(elements),
+        (InputValue::String(string), _) => {
+            values.extend(string.bytes().map(|byte| FieldElement::from(byte as i128)))
+        }
+        (
+            InputValue::Struct(mut fields),
+            AbiType::Struct {
+                fields: field_types,
+            },
+        ) => {
+            for (name, field_type) in field_types {
+                let field = fields.remove(name).expect("struct inputs have every field");
+                flatten_input(field, field_type, values);
+            }
+        }
+        (InputValue::Struct(_), _) => panic!("only struct parameters can be given a struct"),
+    }
+}
+
+fn check_gate(gate: &Gate, witness: &BTreeMap<Witness, FieldElement>) -> Result<(), String> {
+    let value = |w: &Witness| {
+        witness
+            .get(w)
+            .copied()
+            .ok_or_else(|| format!("{:?} was not solved", w))
+    };
+
+    match gate {
+        Gate::Arithmetic(arith) => {
+            let mut sum = arith.q_c;
+            for (coefficient, lhs, rhs) in arith.mul_terms.iter() {
+                sum = sum + *coefficient * value(lhs)? * value(rhs)?;
+            }
+            for (coefficient, term) in arith.linear_combinations.iter() {
+                sum = sum + *coefficient * value(term)?;
+            }
+            if !sum.is_zero() {
+                return Err(format!("unsatisfied gate {:?}", arith));
+            }
+        }
+        Gate::Range(w, num_bits) => {
+            if value(w)?.num_bits() > *num_bits {
+                return Err(format!("{:?} does not fit in {} bits", w, num_bits));
+            }
+        }
+        Gate::And(and_gate) => {
+            let (a, b) = (value(&and_gate.a)?, value(&and_gate.b)?);
+            check_logic_gate(a, b, value(&and_gate.result)?, and_gate.num_bits, |a, b| {
+                a & b
+            })?;
+        }
+        Gate::Xor(xor_gate) => {
+            let (a, b) = (value(&xor_gate.a)?, value(&xor_gate.b)?);
+            check_logic_gate(a, b, value(&xor_gate.result)?, xor_gate.num_bits, |a, b| {
+                a ^ b
+            })?;
+        }
+        // Directives only compute witnesses, and gadgets are
+        // constrained by the proof system
+        Gate::Directive(_) | Gate::GadgetCall(_) => {}
+    }
+    Ok(())
+}
+
+fn check_logic_gate(
+    a: FieldElement,
+    b: FieldElement,
+    result: FieldElement,
+    num_bits: u32,
+    op: fn(u128, u128) -> u128,
+) -> Result<(), String> {
+    if a.num_bits() > num_bits || b.num_bits() > num_bits {
+        return Err(format!(
+            "the operands of a logic gate do not fit in {} bits",
+            num_bits
+        ));
+    }
+    if FieldElement::from(op(a.to_u128(), b.to_u128()) as i128) != result {
+        return Err("unsatisfied logic gate".to_owned());
+    }
+    Ok(())
+}
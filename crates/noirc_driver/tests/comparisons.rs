@@ -0,0 +1,88 @@
+mod common;
+
+use common::{compile, field, solve};
+use noirc_evaluator::RuntimeErrorKind;
+
+#[test]
+fn comparisons_are_values() {
+    let program = compile("comparison_values").unwrap();
+
+    let inputs = |x, y, lt, le, eq, ne| {
+        vec![
+            ("x", field(x)),
+            ("y", field(y)),
+            ("lt", field(lt)),
+            ("le", field(le)),
+            ("eq", field(eq)),
+            ("ne", field(ne)),
+        ]
+    };
+    assert!(solve(&program, inputs(3, 5, 1, 1, 0, 1)).is_ok());
+    assert!(solve(&program, inputs(5, 3, 0, 0, 0, 1)).is_ok());
+    assert!(solve(&program, inputs(4, 4, 0, 1, 1, 0)).is_ok());
+    assert!(solve(&program, inputs(0, 255, 1, 1, 0, 1)).is_ok());
+    assert!(solve(&program, inputs(255, 0, 0, 0, 0, 1)).is_ok());
+    assert!(solve(&program, inputs(0, 0, 0, 1, 1, 0)).is_ok());
+    assert!(solve(&program, inputs(255, 255, 0, 1, 1, 0)).is_ok());
+
+    // Each claimed result is checked
+    assert!(solve(&program, inputs(3, 5, 0, 1, 0, 1)).is_err());
+    assert!(solve(&program, inputs(5, 3, 0, 1, 0, 1)).is_err());
+    assert!(solve(&program, inputs(4, 4, 0, 1, 0, 0)).is_err());
+    assert!(solve(&program, inputs(4, 4, 0, 1, 1, 1)).is_err());
+    assert!(solve(&program, inputs(255, 255, 1, 1, 1, 0)).is_err());
+}
+
+#[test]
+fn signed_comparisons_are_values() {
+    let program = compile("signed_comparison_values").unwrap();
+
+    let inputs = |x, y, lt, le, eq, ne, negative| {
+        vec![
+            ("x", field(x)),
+            ("y", field(y)),
+            ("lt", field(lt)),
+            ("le", field(le)),
+            ("eq", field(eq)),
+            ("ne", field(ne)),
+            ("negative", field(negative)),
+        ]
+    };
+    assert!(solve(&program, inputs(-1, 1, 1, 1, 0, 1, 1)).is_ok());
+    assert!(solve(&program, inputs(1, -1, 0, 0, 0, 1, 0)).is_ok());
+    assert!(solve(&program, inputs(-128, 127, 1, 1, 0, 1, 1)).is_ok());
+    assert!(solve(&program, inputs(127, -128, 0, 0, 0, 1, 0)).is_ok());
+    assert!(solve(&program, inputs(-128, -128, 0, 1, 1, 0, 1)).is_ok());
+    assert!(solve(&program, inputs(0, 0, 0, 1, 1, 0, 0)).is_ok());
+
+    // Without the sign, -1 would be greater than 1
+    assert!(solve(&program, inputs(-1, 1, 0, 0, 0, 1, 1)).is_err());
+    assert!(solve(&program, inputs(0, 0, 0, 1, 1, 0, 1)).is_err());
+    assert!(solve(&program, inputs(-1, -1, 0, 1, 1, 0, 0)).is_err());
+}
+
+#[test]
+fn integer_comparison_is_only_enforced_in_taken_branch() {
+    let program = compile("if_integer_comparison").unwrap();
+
+    let inputs = |c, x, y| vec![("c", field(c)), ("x", field(x)), ("y", field(y))];
+    assert!(solve(&program, inputs(1, 3, 5)).is_ok());
+    assert!(solve(&program, inputs(1, 0, 255)).is_ok());
+    assert!(solve(&program, inputs(0, 5, 3)).is_ok());
+    assert!(solve(&program, inputs(0, 255, 255)).is_ok());
+    assert!(solve(&program, inputs(1, 5, 3)).is_err());
+    assert!(solve(&program, inputs(1, 4, 4)).is_err());
+}
+
+#[test]
+fn field_comparison_under_witness_condition_is_rejected() {
+    match compile("if_field_comparison") {
+        Err(RuntimeErrorKind::UnstructuredError { message, .. }) => {
+            assert_eq!(
+                message,
+                "field elements cannot be compared with Less in a constrain statement which depends on a witness condition, found types witness and constant. Cast them to an integer type first"
+            )
+        }
+        _ => panic!("expected an error for a field comparison under a witness condition"),
+    }
+}
@@ -0,0 +1,48 @@
+// Programs in tests/dependency/ which depend on the library tests/dependency/shapes/.
+// Each crate is in its own directory, since crates are identified by the directory of their root
+use noirc_driver::Driver;
+use noirc_errors::CollectedErrors;
+use noirc_frontend::graph::{CrateType, LOCAL_CRATE};
+use std::path::PathBuf;
+
+/// Runs the static analysis on the program `tests/dependency/<name>/main.nr`
+fn check(name: &str) -> Result<(), Vec<CollectedErrors>> {
+    let mut dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    dir.push("tests/dependency");
+
+    let mut driver = Driver::new();
+    driver.create_local_crate(dir.join(name).join("main.nr"), CrateType::Binary);
+    let shapes = driver.create_non_local_crate(dir.join("shapes/lib.nr"), CrateType::Library);
+    driver.add_dep(LOCAL_CRATE, shapes, "shapes");
+    driver.add_std_lib();
+    driver.check_crate()
+}
+
+#[test]
+fn traits_add_methods_to_structs_from_another_crate() {
+    assert!(check("trait_impl").is_ok());
+}
+
+#[test]
+fn inherent_impls_on_structs_from_another_crate_are_rejected() {
+    assert!(check("inherent_impl").is_err());
+}
+
+#[test]
+fn methods_clashing_with_another_crate_are_rejected() {
+    assert!(check("duplicate_method").is_err());
+}
+
+#[test]
+fn private_items_of_another_crate_suggest_pub() {
+    let errors = check("private_import").unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].errors.len(), 1);
+
+    let diagnostic = &errors[0].errors[0];
+    assert_eq!(diagnostic.message(), "`double` is private");
+    assert_eq!(
+        diagnostic.notes(),
+        ["items are private by default, so the crate which declares `double` must declare it with `pub` for it to be used from another crate"]
+    );
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use common::{array, compile, field, solve};
+
+#[test]
+fn dynamic_index_selects_the_element() {
+    let program = compile("dynamic_index").unwrap();
+
+    let inputs = |index, flag, small, expected| {
+        vec![
+            ("table", array(&[10, 20, 30])),
+            ("index", field(index)),
+            ("flag", field(flag)),
+            ("small", field(small)),
+            ("expected", field(expected)),
+        ]
+    };
+    assert!(solve(&program, inputs(0, 0, 2, 10)).is_ok());
+    assert!(solve(&program, inputs(2, 1, 1, 30)).is_ok());
+    assert!(solve(&program, inputs(1, 1, 0, 30)).is_err());
+}
+
+#[test]
+fn out_of_bounds_index_is_unsatisfiable() {
+    let program = compile("dynamic_index").unwrap();
+
+    let inputs = |index, small| {
+        vec![
+            ("table", array(&[10, 20, 30])),
+            ("index", field(index)),
+            ("flag", field(0)),
+            ("small", field(small)),
+            ("expected", field(0)),
+        ]
+    };
+    assert!(solve(&program, inputs(3, 0)).is_err());
+    assert!(solve(&program, inputs(0, 3)).is_err());
+}
+
+#[test]
+fn bound_check_is_only_enforced_in_taken_branch() {
+    let program = compile("dynamic_index_untaken").unwrap();
+
+    let inputs = |c, index| {
+        vec![
+            ("table", array(&[10, 20, 30])),
+            ("c", field(c)),
+            ("index", field(index)),
+        ]
+    };
+    assert!(solve(&program, inputs(1, 0)).is_ok());
+    assert!(solve(&program, inputs(0, 100)).is_ok());
+    assert!(solve(&program, inputs(1, 100)).is_err());
+}
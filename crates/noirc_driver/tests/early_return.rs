@@ -0,0 +1,49 @@
+mod common;
+
+use common::{compile, field, solve};
+
+#[test]
+fn division_is_only_enforced_on_paths_which_have_not_returned() {
+    let program = compile("return_before_division").unwrap();
+
+    assert!(solve(&program, vec![("x", field(0)), ("y", field(0))]).is_ok());
+    assert!(solve(&program, vec![("x", field(5)), ("y", field(1))]).is_ok());
+    assert!(solve(&program, vec![("x", field(5)), ("y", field(0))]).is_err());
+}
+
+#[test]
+fn range_constraints_are_only_enforced_on_paths_which_have_not_returned() {
+    let program = compile("return_before_range").unwrap();
+
+    let inputs = |c, x, y| vec![("c", field(c)), ("x", field(x)), ("y", field(y))];
+    assert!(solve(&program, inputs(0, 7, 7)).is_ok());
+    assert!(solve(&program, inputs(1, 1000, 8)).is_ok());
+    assert!(solve(&program, inputs(0, 1000, 232)).is_err());
+}
+
+#[test]
+fn return_without_a_value() {
+    let program = compile("return_unit").unwrap();
+
+    assert!(solve(&program, vec![("x", field(0)), ("y", field(7))]).is_ok());
+    assert!(solve(&program, vec![("x", field(1)), ("y", field(1))]).is_ok());
+    assert!(solve(&program, vec![("x", field(2)), ("y", field(2))]).is_err());
+}
+
+#[test]
+fn last_value_is_selected_after_the_gates_are_split() {
+    let program = compile("return_wide_expression").unwrap();
+
+    let inputs = |x, expected| {
+        vec![
+            ("x", field(x)),
+            ("y", field(3)),
+            ("z", field(4)),
+            ("expected", field(expected)),
+        ]
+    };
+    assert!(solve(&program, inputs(0, 3)).is_ok());
+    assert!(solve(&program, inputs(2, 35)).is_ok());
+    assert!(solve(&program, inputs(0, 0)).is_err());
+    assert!(solve(&program, inputs(2, 3)).is_err());
+}
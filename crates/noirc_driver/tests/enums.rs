@@ -0,0 +1,77 @@
+mod common;
+
+use acvm::acir::circuit::gate::{Directive, Gate};
+use common::{compile, field, solve};
+
+fn count_inverses(gates: &[Gate]) -> usize {
+    gates
+        .iter()
+        .filter(|gate| match gate {
+            Gate::Directive(Directive::Invert { .. }) => true,
+            _ => false,
+        })
+        .count()
+}
+
+#[test]
+fn witness_discriminant_selects_arm() {
+    let program = compile("match_witness").unwrap();
+
+    let inputs = |op, expected| {
+        vec![
+            ("op", field(op)),
+            ("balance", field(10)),
+            ("amount", field(3)),
+            ("expected", field(expected)),
+        ]
+    };
+    assert!(solve(&program, inputs(0, 12)).is_ok());
+    assert!(solve(&program, inputs(1, 6)).is_ok());
+    assert!(solve(&program, inputs(2, 8)).is_ok());
+    assert!(solve(&program, inputs(0, 6)).is_err());
+    assert!(solve(&program, inputs(2, 12)).is_err());
+}
+
+#[test]
+fn discriminant_parameter_is_range_constrained() {
+    let program = compile("match_witness").unwrap();
+
+    // 3 is not the discriminant of any variant, and would otherwise take no arm
+    let inputs = vec![
+        ("op", field(3)),
+        ("balance", field(10)),
+        ("amount", field(3)),
+        ("expected", field(10)),
+    ];
+    assert!(solve(&program, inputs).is_err());
+}
+
+#[test]
+fn constraints_are_only_enforced_in_taken_arm() {
+    let program = compile("match_untaken_arms").unwrap();
+
+    let inputs = |op, x, y| vec![("op", field(op)), ("x", field(x)), ("y", field(y))];
+    assert!(solve(&program, inputs(0, 0, 0)).is_ok());
+    assert!(solve(&program, inputs(1, 200, 200)).is_ok());
+    assert!(solve(&program, inputs(2, 1000, 0)).is_ok());
+    assert!(solve(&program, inputs(0, 1000, 0)).is_err());
+    assert!(solve(&program, inputs(1, 1000, 232)).is_err());
+}
+
+#[test]
+fn selectors_are_one_hot() {
+    let program = compile("match_witness").unwrap();
+
+    // The last selector is one minus the sum of the others,
+    // so only two of the three variants need an equality check
+    assert_eq!(count_inverses(&program.circuit.gates), 2);
+}
+
+#[test]
+fn constant_discriminant_needs_no_selectors() {
+    let program = compile("match_constant").unwrap();
+
+    assert_eq!(count_inverses(&program.circuit.gates), 0);
+    assert!(solve(&program, vec![("x", field(1)), ("y", field(2))]).is_ok());
+    assert!(solve(&program, vec![("x", field(1)), ("y", field(1))]).is_err());
+}
@@ -0,0 +1,18 @@
+mod common;
+
+use common::{array, compile, field, solve};
+
+#[test]
+fn generic_lengths_are_monomorphised() {
+    let program = compile("generic_lengths").unwrap();
+
+    let inputs = |expected| {
+        vec![
+            ("small", array(&[1, 2])),
+            ("large", array(&[3, 4, 5, 6])),
+            ("expected", field(expected)),
+        ]
+    };
+    assert!(solve(&program, inputs(21)).is_ok());
+    assert!(solve(&program, inputs(20)).is_err());
+}
@@ -0,0 +1,21 @@
+mod common;
+
+use acvm::acir::circuit::gate::Gate;
+use common::compile;
+
+#[test]
+fn hash_gadgets_return_full_digests() {
+    let program = compile("hash_digests").unwrap();
+
+    // One call to sha256 and one to blake2s, each with a 32 byte digest
+    let outputs: Vec<_> = program
+        .circuit
+        .gates
+        .iter()
+        .filter_map(|gate| match gate {
+            Gate::GadgetCall(call) => Some(call.outputs.len()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(outputs, vec![32, 32]);
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use common::{compile, field, solve};
+use noirc_evaluator::RuntimeErrorKind;
+
+#[test]
+fn witness_condition_selects_branch() {
+    let program = compile("if_witness_condition").unwrap();
+
+    let inputs = |c, z| {
+        vec![
+            ("c", field(c)),
+            ("x", field(3)),
+            ("y", field(4)),
+            ("z", field(z)),
+        ]
+    };
+    assert!(solve(&program, inputs(1, 3)).is_ok());
+    assert!(solve(&program, inputs(0, 4)).is_ok());
+    assert!(solve(&program, inputs(1, 4)).is_err());
+    assert!(solve(&program, inputs(0, 3)).is_err());
+}
+
+#[test]
+fn constrain_is_only_enforced_in_taken_branch() {
+    let program = compile("if_nested_constrain").unwrap();
+
+    let inputs = |a, b, x| vec![("a", field(a)), ("b", field(b)), ("x", field(x))];
+    assert!(solve(&program, inputs(0, 0, 5)).is_ok());
+    assert!(solve(&program, inputs(0, 1, 5)).is_ok());
+    assert!(solve(&program, inputs(1, 1, 1)).is_ok());
+    assert!(solve(&program, inputs(1, 0, 2)).is_ok());
+    assert!(solve(&program, inputs(1, 1, 2)).is_err());
+    assert!(solve(&program, inputs(1, 0, 1)).is_err());
+}
+
+#[test]
+fn range_constraints_are_only_enforced_in_taken_branch() {
+    let program = compile("if_untaken_range").unwrap();
+
+    let inputs = |c, x, y| vec![("c", field(c)), ("x", field(x)), ("y", field(y))];
+    assert!(solve(&program, inputs(1, 7, 7)).is_ok());
+    assert!(solve(&program, inputs(1, 255, 255)).is_ok());
+    assert!(solve(&program, inputs(0, 1000, 8)).is_ok());
+    assert!(solve(&program, inputs(1, 1000, 232)).is_err());
+}
+
+#[test]
+fn inverse_is_only_enforced_in_taken_branch() {
+    let program = compile("if_untaken_division").unwrap();
+
+    assert!(solve(&program, vec![("x", field(0)), ("y", field(0))]).is_ok());
+    assert!(solve(&program, vec![("x", field(5)), ("y", field(1))]).is_ok());
+    assert!(solve(&program, vec![("x", field(5)), ("y", field(0))]).is_err());
+}
+
+#[test]
+fn witness_condition_must_be_boolean() {
+    let program = compile("if_field_condition").unwrap();
+
+    assert!(solve(&program, vec![("c", field(0)), ("x", field(5))]).is_ok());
+    assert!(solve(&program, vec![("c", field(1)), ("x", field(1))]).is_ok());
+    assert!(solve(&program, vec![("c", field(2)), ("x", field(1))]).is_err());
+}
+
+#[test]
+fn constant_condition_must_be_boolean() {
+    match compile("if_constant_condition") {
+        Err(RuntimeErrorKind::UnstructuredError { message, .. }) => {
+            assert_eq!(
+                message,
+                "the condition of an if expression must be either 0 or 1"
+            )
+        }
+        _ => panic!("expected an error for a constant condition which is not 0 or 1"),
+    }
+}
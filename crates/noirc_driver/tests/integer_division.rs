@@ -0,0 +1,57 @@
+mod common;
+
+use acvm::acir::circuit::gate::{Directive, Gate};
+use common::{compile, field, solve};
+
+#[test]
+fn division_uses_the_quotient_directive() {
+    let program = compile("integer_division").unwrap();
+
+    let has_quotient = program.circuit.gates.iter().any(|gate| match gate {
+        Gate::Directive(Directive::Quotient { .. }) => true,
+        _ => false,
+    });
+    assert!(has_quotient);
+}
+
+#[test]
+fn division_is_euclidean() {
+    let program = compile("integer_division").unwrap();
+
+    let inputs = |x, y, q, r| {
+        vec![
+            ("x", field(x)),
+            ("y", field(y)),
+            ("q", field(q)),
+            ("r", field(r)),
+        ]
+    };
+    assert!(solve(&program, inputs(17, 7, 2, 3)).is_ok());
+    assert!(solve(&program, inputs(6, 7, 0, 6)).is_ok());
+    assert!(solve(&program, inputs(4294967295, 1, 4294967295, 0)).is_ok());
+    assert!(solve(&program, inputs(17, 7, 1, 10)).is_err());
+    assert!(solve(&program, inputs(17, 7, 3, 3)).is_err());
+}
+
+#[test]
+fn division_by_zero_is_unsatisfiable() {
+    let program = compile("integer_division").unwrap();
+
+    let inputs = vec![
+        ("x", field(17)),
+        ("y", field(0)),
+        ("q", field(0)),
+        ("r", field(17)),
+    ];
+    assert!(solve(&program, inputs).is_err());
+}
+
+#[test]
+fn field_division_uses_the_inverse() {
+    let program = compile("field_division").unwrap();
+
+    let inputs = |f, g, h| vec![("f", field(f)), ("g", field(g)), ("h", field(h))];
+    assert!(solve(&program, inputs(10, 5, 2)).is_ok());
+    assert!(solve(&program, inputs(-10, 5, -2)).is_ok());
+    assert!(solve(&program, inputs(10, 0, 0)).is_err());
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use common::{compile, field, solve, structure};
+use noirc_abi::input_parser::InputValue;
+
+fn point(x: i128, y: i128) -> InputValue {
+    structure(vec![("x", field(x)), ("y", field(y))])
+}
+
+#[test]
+fn methods_are_called_on_struct_values() {
+    let program = compile("methods").unwrap();
+
+    let inputs = |c, sum, dot| {
+        vec![
+            ("c", field(c)),
+            ("p", point(1, 2)),
+            ("q", point(3, 4)),
+            ("sum", sum),
+            ("dot", field(dot)),
+        ]
+    };
+    assert!(solve(&program, inputs(1, point(4, 6), 5)).is_ok());
+    assert!(solve(&program, inputs(0, point(4, 6), 11)).is_ok());
+    assert!(solve(&program, inputs(1, point(4, 6), 11)).is_err());
+    assert!(solve(&program, inputs(0, point(4, 6), 5)).is_err());
+    assert!(solve(&program, inputs(1, point(4, 5), 5)).is_err());
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::{array, compile, field, solve};
+
+#[test]
+fn accumulator_is_updated_in_each_iteration() {
+    let program = compile("mutable_accumulator").unwrap();
+
+    let inputs = |expected| {
+        vec![
+            ("values", array(&[1, 2, 3, 4])),
+            ("expected", field(expected)),
+        ]
+    };
+    assert!(solve(&program, inputs(1234)).is_ok());
+    assert!(solve(&program, inputs(4321)).is_err());
+    assert!(solve(&program, inputs(10)).is_err());
+}
+
+#[test]
+fn assignments_in_witness_branches_are_merged() {
+    let program = compile("mutable_if_assignment").unwrap();
+
+    let inputs = |c, y, z| {
+        vec![
+            ("c", field(c)),
+            ("x", field(5)),
+            ("y_expected", field(y)),
+            ("z_expected", field(z)),
+        ]
+    };
+    assert!(solve(&program, inputs(1, 6, 7)).is_ok());
+    assert!(solve(&program, inputs(0, 10, 5)).is_ok());
+
+    // The values assigned in the other branch are not used
+    assert!(solve(&program, inputs(1, 10, 7)).is_err());
+    assert!(solve(&program, inputs(0, 6, 5)).is_err());
+    assert!(solve(&program, inputs(0, 10, 7)).is_err());
+    assert!(solve(&program, inputs(1, 6, 5)).is_err());
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use common::{compile, field, solve};
+
+#[test]
+fn arithmetic_wraps_in_twos_complement() {
+    let program = compile("signed_wrapping").unwrap();
+
+    let inputs = |x, y, sum, product| {
+        vec![
+            ("x", field(x)),
+            ("y", field(y)),
+            ("sum", field(sum)),
+            ("product", field(product)),
+        ]
+    };
+    assert!(solve(&program, inputs(-1, -3, -4, 3)).is_ok());
+    assert!(solve(&program, inputs(127, 127, -2, 1)).is_ok());
+    assert!(solve(&program, inputs(-128, -1, 127, -128)).is_ok());
+    assert!(solve(&program, inputs(127, 127, 2, 1)).is_err());
+}
+
+#[test]
+fn comparisons_are_signed() {
+    let program = compile("signed_comparison").unwrap();
+
+    let inputs = |x, y| vec![("x", field(x)), ("y", field(y))];
+    assert!(solve(&program, inputs(-1, 1)).is_ok());
+    assert!(solve(&program, inputs(-128, 127)).is_ok());
+    assert!(solve(&program, inputs(-3, -2)).is_ok());
+    assert!(solve(&program, inputs(1, -1)).is_err());
+    assert!(solve(&program, inputs(-2, -3)).is_err());
+}
+
+#[test]
+fn casts_extend_the_sign() {
+    let program = compile("signed_cast").unwrap();
+
+    let inputs = |x, wide, unsigned| {
+        vec![
+            ("x", field(x)),
+            ("wide", field(wide)),
+            ("unsigned", field(unsigned)),
+        ]
+    };
+    assert!(solve(&program, inputs(-1, -1, 255)).is_ok());
+    assert!(solve(&program, inputs(5, 5, 5)).is_ok());
+    assert!(solve(&program, inputs(-128, -128, 128)).is_ok());
+    assert!(solve(&program, inputs(-1, 255, 255)).is_err());
+}
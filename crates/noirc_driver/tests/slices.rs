@@ -0,0 +1,22 @@
+mod common;
+
+use common::{array, compile, solve};
+
+#[test]
+fn slices_and_concatenation_rearrange_elements() {
+    let program = compile("slice_rotation").unwrap();
+
+    let inputs = |rotated| vec![("arr", array(&[1, 2, 3, 4])), ("rotated", array(rotated))];
+    assert!(solve(&program, inputs(&[2, 3, 4, 1])).is_ok());
+    assert!(solve(&program, inputs(&[1, 2, 3, 4])).is_err());
+}
+
+#[test]
+fn slices_and_concatenation_add_no_gates() {
+    let sliced = compile("slice_rotation").unwrap();
+    let indexed = compile("index_rotation").unwrap();
+
+    // Only the equality of the elements is constrained
+    assert_eq!(sliced.circuit.gates.len(), indexed.circuit.gates.len());
+    assert_eq!(sliced.circuit.gates.len(), 4);
+}
@@ -0,0 +1,27 @@
+mod common;
+
+use common::{compile, solve};
+use noirc_abi::input_parser::InputValue;
+use noirc_evaluator::RuntimeErrorKind;
+
+#[test]
+fn strings_are_compared_byte_by_byte() {
+    let program = compile("string_equality").unwrap();
+
+    let inputs = |message: &str| vec![("message", InputValue::String(message.to_owned()))];
+    assert!(solve(&program, inputs("hello")).is_ok());
+    assert!(solve(&program, inputs("hellp")).is_err());
+}
+
+#[test]
+fn different_constant_strings_cannot_be_constrained() {
+    match compile("string_constant_mismatch") {
+        Err(RuntimeErrorKind::UnsatisfiableConstraint { span }) => {
+            // The error points at the second constrain statement
+            let source = include_str!("evaluator/string_constant_mismatch.nr");
+            let start = source.find(r#""hello" == "world""#).unwrap();
+            assert_eq!(span.start.to_byte_index().to_usize(), start);
+        }
+        _ => panic!("expected an error for a constraint which can never be satisfied"),
+    }
+}
@@ -1,6 +1,16 @@
 use noirc_driver::Driver;
 use std::path::PathBuf;
 
+// Programs made of several modules are kept in their own directory, rooted at main.nr,
+// so that their child modules are not compiled as programs of their own
+fn crate_root(path: PathBuf) -> PathBuf {
+    if path.is_dir() {
+        path.join("main.nr")
+    } else {
+        path
+    }
+}
+
 #[test]
 fn fail() {
     let mut fail_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -9,7 +19,7 @@ fn fail() {
     let paths = std::fs::read_dir(fail_dir).unwrap();
 
     for path in paths {
-        let path = path.unwrap().path();
+        let path = crate_root(path.unwrap().path());
         assert!(!Driver::file_compiles(&path), "path: {}", path.display())
     }
 }
@@ -21,7 +31,7 @@ fn pass() {
     let paths = std::fs::read_dir(pass_dir).unwrap();
 
     for path in paths {
-        let path = path.unwrap().path();
+        let path = crate_root(path.unwrap().path());
         assert!(Driver::file_compiles(&path), "path: {}", path.display())
     }
 }
@@ -0,0 +1,26 @@
+mod common;
+
+use common::{compile, field, solve, structure};
+
+#[test]
+fn trait_methods_dispatch_to_the_implementor() {
+    let program = compile("trait_dispatch").unwrap();
+
+    let inputs = |square_area, rectangle_area| {
+        vec![
+            ("square", structure(vec![("side", field(3))])),
+            (
+                "rectangle",
+                structure(vec![("width", field(3)), ("height", field(5))]),
+            ),
+            ("square_area", field(square_area)),
+            ("rectangle_area", field(rectangle_area)),
+        ]
+    };
+    assert!(solve(&program, inputs(9, 15)).is_ok());
+
+    // Each implementor has its own body
+    assert!(solve(&program, inputs(15, 15)).is_err());
+    assert!(solve(&program, inputs(9, 9)).is_err());
+    assert!(solve(&program, inputs(15, 9)).is_err());
+}
@@ -0,0 +1,27 @@
+mod common;
+
+use common::{compile, field, solve};
+
+#[test]
+fn tuples_are_returned_and_indexed() {
+    let program = compile("tuples").unwrap();
+
+    let inputs = |c, q, r, selected, first| {
+        vec![
+            ("c", field(c)),
+            ("x", field(17)),
+            ("y", field(5)),
+            ("q", field(q)),
+            ("r", field(r)),
+            ("selected", field(selected)),
+            ("first", field(first)),
+        ]
+    };
+    assert!(solve(&program, inputs(1, 3, 2, 3, 3)).is_ok());
+    assert!(solve(&program, inputs(0, 3, 2, 2, 5)).is_ok());
+    assert!(solve(&program, inputs(1, 3, 2, 2, 3)).is_err());
+    assert!(solve(&program, inputs(0, 3, 2, 3, 5)).is_err());
+    assert!(solve(&program, inputs(1, 2, 3, 3, 3)).is_err());
+    assert!(solve(&program, inputs(1, 3, 2, 3, 5)).is_err());
+    assert!(solve(&program, inputs(0, 3, 2, 2, 3)).is_err());
+}
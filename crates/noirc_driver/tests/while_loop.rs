@@ -0,0 +1,40 @@
+mod common;
+
+use common::{array, compile, driver, field, solve};
+use noirc_evaluator::RuntimeErrorKind;
+
+#[test]
+fn while_loops_are_unrolled() {
+    let program = compile("while_loop").unwrap();
+
+    let inputs = |expected| {
+        vec![
+            ("values", array(&[1, 2, 3, 4])),
+            ("expected", field(expected)),
+        ]
+    };
+    assert!(solve(&program, inputs(10)).is_ok());
+    assert!(solve(&program, inputs(9)).is_err());
+}
+
+#[test]
+fn unbounded_loops_hit_the_iteration_limit() {
+    match compile("while_loop_unbounded") {
+        Err(RuntimeErrorKind::LoopIterationLimit { limit, .. }) => assert!(limit > 0),
+        _ => panic!("expected the loop to hit the iteration limit"),
+    }
+}
+
+#[test]
+fn iteration_limit_is_set_by_the_driver() {
+    let mut limited = driver("while_loop");
+    limited.set_max_loop_iterations(3);
+    match limited.compile_main(acvm::BackendPointer::default()) {
+        Err(RuntimeErrorKind::LoopIterationLimit { limit, .. }) => assert_eq!(limit, 3),
+        _ => panic!("expected the loop to hit the iteration limit"),
+    }
+
+    let mut limited = driver("while_loop");
+    limited.set_max_loop_iterations(4);
+    assert!(limited.compile_main(acvm::BackendPointer::default()).is_ok());
+}
@@ -39,17 +39,35 @@ impl CustomDiagnostic {
     pub fn add_secondary(&mut self, message: String, span: Span) {
         self.secondaries.push(CustomLabel::new(message, span));
     }
+    /// Adds a label whose span is in another file than the one the diagnostic is reported in
+    pub fn add_secondary_in_file(&mut self, message: String, span: Span, file_id: fm::FileId) {
+        let mut label = CustomLabel::new(message, span);
+        label.file_id = Some(file_id);
+        self.secondaries.push(label);
+    }
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
 }
 
 #[derive(Debug)]
 struct CustomLabel {
     pub message: String,
     pub span: Span,
+    // When this is None, the label is in the file that the diagnostic is reported in
+    pub file_id: Option<fm::FileId>,
 }
 
 impl CustomLabel {
     pub fn new(message: String, span: Span) -> CustomLabel {
-        CustomLabel { message, span }
+        CustomLabel {
+            message,
+            span,
+            file_id: None,
+        }
     }
 }
 
@@ -71,6 +89,7 @@ impl Reporter {
                     .map(|sl| {
                         let start_span = sl.span.start.to_byte_index().to_usize();
                         let end_span = sl.span.end.to_byte_index().to_usize() + 1;
+                        let file_id = sl.file_id.map_or(file_id, |id| id.as_usize());
                         Label::secondary(file_id, start_span..end_span).with_message(&sl.message)
                     })
                     .collect();
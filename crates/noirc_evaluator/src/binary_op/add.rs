@@ -19,6 +19,9 @@ pub fn handle_add_op(
         // You cannot add array objects to anything that is not an array
         (Object::Array(_), _) | (_, Object::Array(_)) => Err(handle_cannot_add("Arrays")),
         //
+        // Structs can only be used in equality checks, which are handled by the sub op
+        (Object::Struct(_), _) | (_, Object::Struct(_)) => Err(handle_cannot_add("Structs")),
+        //
         // Delegate logic for integer addition to the integer module
         (Object::Integer(x), y) | (y, Object::Integer(x)) => {
             Ok(Object::Integer(x.add(y, evaluator)?))
@@ -7,6 +7,15 @@ pub fn handle_and_op(
 ) -> Result<Object, RuntimeErrorKind> {
     match (left, right) {
         (Object::Integer(x), Object::Integer(y)) => Ok(Object::Integer(x.and(y, evaluator)?)),
+        // Constants, such as masks, take the width of the integer they are applied to
+        (Object::Integer(x), Object::Constants(c)) | (Object::Constants(c), Object::Integer(x)) => {
+            let y = x.bitwise_operand(c, evaluator)?;
+            Ok(Object::Integer(x.and(y, evaluator)?))
+        }
+        // Booleans are either 0 or 1, so their conjunction is their product
+        (x, y) if super::is_boolean(&x) && super::is_boolean(&y) => {
+            super::handle_mul_op(x, y, evaluator)
+        }
         (x, y) => {
             let err=  RuntimeErrorKind::UnstructuredError{span : Default::default(), message : format!("currently we only support bitwise operations on ranged operations, found types {} and {}", x.r#type(), y.r#type())};
             Err(err)
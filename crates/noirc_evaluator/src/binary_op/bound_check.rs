@@ -12,6 +12,9 @@ use crate::{Evaluator, FieldElement, Integer, Linear, Object, RuntimeErrorKind};
 
 // a <= b => b - a is always positive
 // a < b => b - a - 1  is always positive
+//
+// Signed integers are first mapped onto unsigned integers by flipping their sign bit.
+// This mapping preserves the ordering, so the checks above can then be applied.
 
 fn bound_check(
     lower_bound: Object,
@@ -19,6 +22,14 @@ fn bound_check(
     upper_bound_included: bool,
     evaluator: &mut Evaluator,
 ) -> Result<Object, RuntimeErrorKind> {
+    let (lower_bound, upper_bound) = match signed_num_bits(&lower_bound, &upper_bound) {
+        Some(num_bits) => (
+            flip_sign_bit(lower_bound, num_bits, evaluator)?,
+            flip_sign_bit(upper_bound, num_bits, evaluator)?,
+        ),
+        None => (lower_bound, upper_bound),
+    };
+
     let offset = if upper_bound_included {
         FieldElement::zero()
     } else {
@@ -53,7 +64,21 @@ fn bound_check(
         (lower_bound, Object::Constants(y)) => {
             let max_bound_bits = y.num_bits();
 
-            let k = handle_sub_op(Object::Constants(y - offset), lower_bound, evaluator)?;
+            // An integer cannot be subtracted from a constant, so the lower bound is converted
+            // to an arithmetic struct, as above
+            let lower_bound_as_arith =
+                lower_bound
+                    .into_arithmetic()
+                    .ok_or(RuntimeErrorKind::UnstructuredError {
+                        span: Default::default(),
+                        message: format!("invalid lower bound being used in bound check"),
+                    })?;
+
+            let k = handle_sub_op(
+                Object::Constants(y - offset),
+                Object::Arithmetic(lower_bound_as_arith),
+                evaluator,
+            )?;
             Integer::from_object(k, max_bound_bits, evaluator)
         }
         (_, y) => {
@@ -65,6 +90,45 @@ fn bound_check(
     Ok(Object::Null)
 }
 
+// Returns the bit size of the operands, if either of them is a signed integer
+pub(super) fn signed_num_bits(lhs: &Object, rhs: &Object) -> Option<u32> {
+    match (lhs, rhs) {
+        (Object::Integer(integer), _) | (_, Object::Integer(integer)) if integer.is_signed() => {
+            Some(integer.num_bits)
+        }
+        _ => None,
+    }
+}
+
+pub(super) fn flip_sign_bit(
+    object: Object,
+    num_bits: u32,
+    evaluator: &mut Evaluator,
+) -> Result<Object, RuntimeErrorKind> {
+    match object {
+        Object::Integer(integer) => {
+            let (flipped, _) = integer.flip_sign_bit(evaluator)?;
+            Ok(Object::Integer(flipped))
+        }
+        Object::Constants(constant) => {
+            let constant =
+                constant.twos_complement(num_bits) + FieldElement::pow_of_two(num_bits - 1);
+            // The addition may have overflowed into the next bit, which is the
+            // same as flipping the sign bit
+            let constant = if constant.num_bits() > num_bits {
+                constant - FieldElement::pow_of_two(num_bits)
+            } else {
+                constant
+            };
+            Ok(Object::Constants(constant))
+        }
+        x => Err(RuntimeErrorKind::UnstructuredError {
+            span: Default::default(),
+            message: format!("cannot compare a signed integer with a {}", x.r#type()),
+        }),
+    }
+}
+
 pub fn handle_less_than_op(
     left: Object,
     right: Object,
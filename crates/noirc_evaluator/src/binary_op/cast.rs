@@ -1,12 +1,17 @@
+use noirc_frontend::Signedness;
+
 use crate::{Evaluator, Integer, Linear, Object, RuntimeErrorKind, Type};
 
 pub fn handle_cast_op(
     evaluator: &mut Evaluator,
     left: Object,
+    left_type: Type,
     right: Type,
 ) -> Result<Object, RuntimeErrorKind> {
-    let num_bits = match right {
-        Type::Integer(_, _sign, num_bits) => num_bits,
+    let (sign, num_bits) = match right {
+        Type::Integer(_, sign, num_bits) => (sign, num_bits),
+        // Every value is a field element, so this does not require any constraints
+        Type::FieldElement(_) => return cast_to_field(left),
         _ => {
             return Err(RuntimeErrorKind::UnstructuredError {
                 span: Default::default(),
@@ -15,6 +20,21 @@ pub fn handle_cast_op(
         }
     };
 
+    // A boolean is either 0 or 1, so it fits in every integer type
+    if left_type == Type::Bool {
+        let casted_integer = match left {
+            Object::Constants(_) => return Ok(left),
+            left => {
+                let arith = left
+                    .into_arithmetic()
+                    .expect("ice: booleans should be represented as witnesses or constants");
+                let (_, witness) = evaluator.create_intermediate_variable(arith);
+                Integer::from_witness(witness, num_bits)
+            }
+        };
+        return Ok(Object::Integer(casted_integer.with_sign(sign)));
+    }
+
     let casted_integer = match left {
         Object::Arithmetic(arith) => {
             let casted_integer = Integer::from_arithmetic(arith, num_bits, evaluator);
@@ -32,6 +52,25 @@ pub fn handle_cast_op(
             casted_integer.constrain(evaluator)?;
             casted_integer
         }
+        Object::Integer(integer) if integer.is_signed() && integer.num_bits < num_bits => {
+            // Casting a signed integer to a larger integer preserves it's value
+            // So the new bits must be filled with the sign bit
+            integer.sign_extend(num_bits, evaluator)?
+        }
+        Object::Integer(integer)
+            if integer.num_bits > num_bits
+                && (integer.is_signed() || sign == Signedness::Signed) =>
+        {
+            // Casting to a smaller integer, where either side is signed,
+            // keeps the least significant bits. ie -1 as i16 as i8 is still -1
+            let (casted_integer, _) = Integer::truncate(
+                Linear::from(integer.witness).into(),
+                num_bits,
+                integer.num_bits,
+                evaluator,
+            )?;
+            casted_integer
+        }
         Object::Integer(integer) => {
             // If we are casting a u8 to a u32, then this would require no extra constraints
             // Since all u8s can fit into u32
@@ -53,5 +92,16 @@ pub fn handle_cast_op(
             })
         }
     };
-    Ok(Object::Integer(casted_integer))
+    Ok(Object::Integer(casted_integer.with_sign(sign)))
+}
+
+fn cast_to_field(left: Object) -> Result<Object, RuntimeErrorKind> {
+    match left {
+        Object::Integer(integer) => Ok(Object::from_witness(integer.witness)),
+        Object::Linear(_) | Object::Arithmetic(_) | Object::Constants(_) => Ok(left),
+        x => Err(RuntimeErrorKind::UnstructuredError {
+            span: Default::default(),
+            message: format!("cannot cast {} to a field element", x.r#type()),
+        }),
+    }
 }
@@ -0,0 +1,164 @@
+use noirc_frontend::hir_def::expr::HirBinaryOpKind;
+
+use super::bound_check::{flip_sign_bit, signed_num_bits};
+use super::{handle_mul_op, handle_sub_op, maybe_equal};
+use crate::{Evaluator, FieldElement, Integer, Object, RuntimeErrorKind};
+
+// Comparisons which are used as values, such as `priv b = x < y;`, do not constrain their operands.
+// Instead, they return a witness which is 1 if the comparison holds and 0 otherwise.
+//
+// x == y uses the is_zero gadget on x - y
+//
+// For n bit integers, x < y is computed by decomposing y - x - 1 + 2^n into n + 1 bits.
+// y - x - 1 + 2^n lies in [0, 2^(n+1)), and its most significant bit is set if and only if y - x - 1 >= 0
+// x <= y is computed similarly, using y - x + 2^n
+//
+// Signed integers are first mapped onto unsigned integers by flipping their sign bit,
+// as is done in the bound check.
+pub fn handle_comparison_op(
+    left: Object,
+    right: Object,
+    kind: HirBinaryOpKind,
+    evaluator: &mut Evaluator,
+) -> Result<Object, RuntimeErrorKind> {
+    // Comparisons between constants are evaluated at compile time
+    if let (Object::Constants(x), Object::Constants(y)) = (&left, &right) {
+        let result = match kind {
+            HirBinaryOpKind::Equal => x == y,
+            HirBinaryOpKind::NotEqual => x != y,
+            HirBinaryOpKind::Less => x < y,
+            HirBinaryOpKind::LessEqual => x <= y,
+            HirBinaryOpKind::Greater => x > y,
+            HirBinaryOpKind::GreaterEqual => x >= y,
+            _ => unreachable!("ice: {:?} is not a comparison operator", kind),
+        };
+        return Ok(Object::Constants(FieldElement::from(result as i128)));
+    }
+
+    match kind {
+        HirBinaryOpKind::Equal => is_equal(left, right, evaluator),
+        HirBinaryOpKind::NotEqual => {
+            let equal = is_equal(left, right, evaluator)?;
+            handle_sub_op(Object::Constants(FieldElement::one()), equal, evaluator)
+        }
+        HirBinaryOpKind::Less => is_less_than(left, right, false, evaluator),
+        HirBinaryOpKind::LessEqual => is_less_than(left, right, true, evaluator),
+        HirBinaryOpKind::Greater => is_less_than(right, left, false, evaluator),
+        HirBinaryOpKind::GreaterEqual => is_less_than(right, left, true, evaluator),
+        _ => unreachable!("ice: {:?} is not a comparison operator", kind),
+    }
+}
+
+fn is_equal(
+    left: Object,
+    right: Object,
+    evaluator: &mut Evaluator,
+) -> Result<Object, RuntimeErrorKind> {
+    let (left_contents, right_contents) = match (left, right) {
+        (Object::Array(lhs), Object::Array(rhs)) => (lhs.contents, rhs.contents),
+        (Object::Struct(lhs), Object::Struct(rhs)) => (lhs.contents, rhs.contents),
+        (Object::Null, _) | (_, Object::Null) => {
+            return Err(RuntimeErrorKind::UnstructuredError {
+                span: Default::default(),
+                message: "cannot compare expressions which do not return a value".to_owned(),
+            })
+        }
+        (lhs, rhs) => {
+            // Signed constants are stored in two's complement, like the integer they are compared to
+            let (lhs, rhs) = match signed_num_bits(&lhs, &rhs) {
+                Some(num_bits) => (
+                    normalise_signed_constant(lhs, num_bits),
+                    normalise_signed_constant(rhs, num_bits),
+                ),
+                None => (lhs, rhs),
+            };
+            let equal = maybe_equal(lhs, rhs, evaluator)?;
+            return Ok(Object::from_witness(equal.witness));
+        }
+    };
+
+    // Both values are equal if all of their elements are equal
+    let mut result = Object::Constants(FieldElement::one());
+    for (lhs, rhs) in left_contents.into_iter().zip(right_contents) {
+        let equal = is_equal(lhs, rhs, evaluator)?;
+        result = handle_mul_op(result, equal, evaluator)?;
+        if let Object::Arithmetic(arith) = result {
+            let (intermediate, _) = evaluator.create_intermediate_variable(arith);
+            result = intermediate;
+        }
+    }
+    Ok(result)
+}
+
+fn is_less_than(
+    lower: Object,
+    upper: Object,
+    include_equal: bool,
+    evaluator: &mut Evaluator,
+) -> Result<Object, RuntimeErrorKind> {
+    let num_bits = match (&lower, &upper) {
+        (Object::Integer(integer), _) | (_, Object::Integer(integer)) => integer.num_bits,
+        (x, y) => {
+            return Err(RuntimeErrorKind::UnstructuredError {
+                span: Default::default(),
+                message: format!(
+                    "comparisons can only be used as values on integers, found types {} and {}",
+                    x.r#type(),
+                    y.r#type()
+                ),
+            })
+        }
+    };
+
+    let (lower, upper) = match signed_num_bits(&lower, &upper) {
+        Some(num_bits) => (
+            flip_sign_bit(lower, num_bits, evaluator)?,
+            flip_sign_bit(upper, num_bits, evaluator)?,
+        ),
+        None => (lower, upper),
+    };
+    check_constant_fits(&lower, num_bits)?;
+    check_constant_fits(&upper, num_bits)?;
+
+    let offset = if include_equal {
+        FieldElement::pow_of_two(num_bits)
+    } else {
+        FieldElement::pow_of_two(num_bits) - FieldElement::one()
+    };
+
+    let lower = lower
+        .into_arithmetic()
+        .expect("ice: integers and constants can be converted to arithmetic");
+    let upper = upper
+        .into_arithmetic()
+        .expect("ice: integers and constants can be converted to arithmetic");
+    let difference = (&upper - &lower) + &offset;
+
+    let (_, most_significant_bit) =
+        Integer::truncate(difference, num_bits, num_bits + 1, evaluator)?;
+    Ok(Object::from_witness(most_significant_bit.witness))
+}
+
+fn normalise_signed_constant(object: Object, num_bits: u32) -> Object {
+    match object {
+        Object::Constants(constant) => Object::Constants(constant.twos_complement(num_bits)),
+        object => object,
+    }
+}
+
+fn check_constant_fits(object: &Object, num_bits: u32) -> Result<(), RuntimeErrorKind> {
+    match object {
+        Object::Constants(constant) if constant.num_bits() > num_bits => {
+            Err(RuntimeErrorKind::UnstructuredError {
+                span: Default::default(),
+                message: format!(
+                    "cannot compare a {} bit integer with the constant {}, as it does not fit in {} bits",
+                    num_bits,
+                    constant.to_u128(),
+                    num_bits
+                ),
+            })
+        }
+        _ => Ok(()),
+    }
+}
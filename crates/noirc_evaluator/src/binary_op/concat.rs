@@ -0,0 +1,12 @@
+use crate::{object::Array, Object, RuntimeErrorKind};
+
+/// Concatenates two arrays.
+/// The elements are only rearranged, so no constraints are needed
+pub fn handle_concat_op(left: Object, right: Object) -> Result<Object, RuntimeErrorKind> {
+    match (left, right) {
+        (Object::Array(lhs), Object::Array(rhs)) => Ok(Object::Array(Array::concat(lhs, rhs))),
+        (Object::Array(_), object) | (object, _) => {
+            Err(RuntimeErrorKind::expected_type("array", object.r#type()))
+        }
+    }
+}
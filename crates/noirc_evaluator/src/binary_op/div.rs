@@ -1,13 +1,43 @@
 use super::{invert, mul::handle_mul_op};
-use crate::{Evaluator, Object, RuntimeErrorKind};
+use crate::{Evaluator, Integer, Object, RuntimeErrorKind};
 
 /// For a / b . First compute the 1/b and constraint it to be the inverse of b
 /// Then multiply this inverse by a
+///
+/// If either a or b is an integer, then integer division is used instead
 pub fn handle_div_op(
     left: Object,
     right: Object,
     evaluator: &mut Evaluator,
 ) -> Result<Object, RuntimeErrorKind> {
+    if left.integer().is_some() || right.integer().is_some() {
+        let (quotient, _) = euclidean_division(left, right, evaluator)?;
+        return Ok(Object::Integer(quotient));
+    }
+
     let right_inv = invert(right, evaluator)?;
     handle_mul_op(left, right_inv, evaluator)
 }
+
+/// Returns the quotient and the remainder of a / b, where either a or b is an integer
+pub(super) fn euclidean_division(
+    left: Object,
+    right: Object,
+    evaluator: &mut Evaluator,
+) -> Result<(Integer, Integer), RuntimeErrorKind> {
+    match (left, right) {
+        (Object::Integer(integer), rhs) => integer.euclidean_division(rhs, evaluator),
+        (Object::Constants(constant), Object::Integer(integer)) => {
+            // The dividend needs to be a witness, so that the quotient directive can be applied
+            let (_, witness) = evaluator.create_intermediate_variable((&constant).into());
+            let dividend = Integer::from_witness(witness, integer.num_bits).with_sign(integer.sign);
+            dividend.euclidean_division(Object::Integer(integer), evaluator)
+        }
+        (lhs, rhs) => Err(RuntimeErrorKind::UnsupportedOp {
+            span: Default::default(),
+            op: "div".to_owned(),
+            first_type: lhs.r#type().to_owned(),
+            second_type: rhs.r#type().to_owned(),
+        }),
+    }
+}
@@ -35,7 +35,8 @@ pub fn handle_equal_op(
                 .gates
                 .push(Gate::Arithmetic(witness_linear.into()))
         }
-        Object::Array(arr) => arr.constrain_zero(evaluator),
+        Object::Array(arr) => arr.constrain_zero(evaluator)?,
+        Object::Struct(structure) => structure.constrain_zero(evaluator)?,
     }
     Ok(Object::Null)
 }
@@ -3,10 +3,17 @@ pub mod add;
 pub mod and;
 pub mod bound_check;
 pub mod cast;
+pub mod comparison;
+pub mod concat;
 pub mod div;
 pub mod equal;
+pub mod modulo;
 pub mod mul;
+pub mod negate;
 pub mod neq;
+pub mod not;
+pub mod or;
+pub mod shift;
 pub mod sub;
 pub mod xor;
 
@@ -23,34 +30,74 @@ pub use bound_check::handle_greater_than_op;
 pub use bound_check::handle_less_than_equal_op;
 pub use bound_check::handle_less_than_op;
 pub use cast::handle_cast_op;
+pub use comparison::handle_comparison_op;
+pub use concat::handle_concat_op;
 pub use div::handle_div_op;
 pub use equal::handle_equal_op;
+pub use modulo::handle_modulo_op;
 pub use mul::handle_mul_op;
+pub use negate::handle_negate_op;
 pub use neq::handle_neq_op;
+pub use not::handle_not_op;
+pub use or::handle_or_op;
+pub use shift::{handle_shift_left_op, handle_shift_right_op};
 pub use sub::handle_sub_op;
 pub use xor::handle_xor_op;
 
+use crate::conditional::apply_predicate;
 use crate::{object::Integer, Evaluator, FieldElement, Object, RuntimeErrorKind};
 
 /// Creates a new witness and constrains it to be the inverse of the polynomial passed in
 pub fn invert(x: Object, evaluator: &mut Evaluator) -> Result<Object, RuntimeErrorKind> {
-    // Create a fresh witness
+    if let Object::Constants(constant) = x {
+        if constant.is_zero() {
+            return Err(RuntimeErrorKind::UnstructuredError {
+                span: Default::default(),
+                message: "attempted to divide by zero".to_owned(),
+            });
+        }
+        return Ok(Object::Constants(constant.inverse()));
+    }
 
-    let inverse_witness = evaluator.add_witness_to_cs();
-    let inverse_obj = Object::from_witness(inverse_witness);
+    // The directive can only be applied to a witness
+    let x_witness = match x.witness() {
+        Some(witness) => witness,
+        None => {
+            let arith = x
+                .into_arithmetic()
+                .ok_or_else(|| RuntimeErrorKind::expected_type("witness", x.r#type()))?;
+            evaluator.create_intermediate_variable(arith).1
+        }
+    };
 
-    // Multiply inverse by original value
-    let should_be_one = handle_mul_op(x, inverse_obj.clone(), evaluator)?;
+    // Create a fresh witness, which the directive sets to the inverse
+    let inverse_witness = evaluator.add_witness_to_cs();
+    evaluator.gates.push(Gate::Directive(Directive::Invert {
+        x: x_witness,
+        result: inverse_witness,
+    }));
 
-    // Constrain x * x_inv = 1
-    let _ = handle_equal_op(
-        should_be_one,
-        Object::Constants(FieldElement::one()),
-        evaluator,
-    );
+    // Constrain x * x_inv - 1 = 0
+    // Inside of a branch with a witness condition, x may be zero if the branch is not taken
+    // so this is multiplied by the predicate
+    let product: Arithmetic =
+        (Linear::from_witness(x_witness) * Linear::from_witness(inverse_witness)).into();
+    let should_be_zero = product - &FieldElement::one();
+    let should_be_zero = apply_predicate(Object::Arithmetic(should_be_zero), evaluator)?;
+    evaluator.gates.push(Gate::Arithmetic(should_be_zero));
 
     // Return inverse
-    Ok(inverse_obj)
+    Ok(Object::from_witness(inverse_witness))
+}
+
+/// Booleans are represented as witnesses or constants which are either 0 or 1.
+/// The type checker ensures that only booleans reach the logical operations
+/// with these representations.
+pub(crate) fn is_boolean(object: &Object) -> bool {
+    match object {
+        Object::Linear(_) | Object::Arithmetic(_) | Object::Constants(_) => true,
+        _ => false,
+    }
 }
 
 /// Returns 1 if a == b else 0
@@ -0,0 +1,13 @@
+use super::div::euclidean_division;
+use crate::{Evaluator, Object, RuntimeErrorKind};
+
+/// For a % b, we compute the euclidean division of a by b and return the remainder
+/// This is only defined for integers
+pub fn handle_modulo_op(
+    left: Object,
+    right: Object,
+    evaluator: &mut Evaluator,
+) -> Result<Object, RuntimeErrorKind> {
+    let (_, remainder) = euclidean_division(left, right, evaluator)?;
+    Ok(Object::Integer(remainder))
+}
@@ -1,4 +1,4 @@
-use crate::{Arithmetic, Array, Evaluator, Linear, Object, RuntimeErrorKind};
+use crate::{object::Struct, Arithmetic, Array, Evaluator, Linear, Object, RuntimeErrorKind};
 
 ///   Dealing with multiplication
 /// - Multiplying an arithmetic gate with anything else except a constant requires an intermediate variable
@@ -16,6 +16,10 @@ pub fn handle_mul_op(
 
         (Object::Array(_), Object::Array(_)) => Err(general_err),
 
+        (Object::Struct(_), Object::Struct(_))
+        | (Object::Struct(_), Object::Array(_))
+        | (Object::Array(_), Object::Struct(_)) => Err(general_err),
+
         (Object::Arithmetic(x), y) | (y, Object::Arithmetic(x)) => {
             handle_arithmetic_mul(x, y, evaluator)
         }
@@ -81,6 +85,14 @@ fn handle_linear_mul(
                 length: arr.length,
             }))
         }
+        Object::Struct(structure) => {
+            let mut result = Vec::with_capacity(structure.contents.len());
+            for field in structure.contents.into_iter() {
+                result.push(handle_linear_mul(linear, field, evaluator)?);
+            }
+
+            Ok(Object::Struct(Struct { contents: result }))
+        }
         Object::Null => Err(err_cannot_mul("()", "Witness")),
     }
 }
@@ -0,0 +1,18 @@
+use crate::{Evaluator, Object, RuntimeErrorKind};
+
+/// Negates an object
+/// Signed integers wrap around, so that the result is still an integer.
+/// For all other objects, the field element is negated
+pub fn handle_negate_op(
+    rhs: Object,
+    evaluator: &mut Evaluator,
+) -> Result<Object, RuntimeErrorKind> {
+    match rhs {
+        Object::Integer(x) if x.is_signed() => Ok(Object::Integer(x.negate(evaluator)?)),
+        Object::Null => Err(RuntimeErrorKind::UnstructuredError {
+            span: Default::default(),
+            message: "cannot negate an expression which does not return a value".to_owned(),
+        }),
+        x => Ok(x.negate()),
+    }
+}
@@ -1,5 +1,8 @@
 use super::{invert, sub::handle_sub_op};
-use crate::{object::Array, Evaluator, Object, RuntimeErrorKind};
+use crate::{
+    object::{Array, Struct},
+    Evaluator, Object, RuntimeErrorKind,
+};
 
 /// This calls the sub op under the hood
 /// Then asserts that the result has an inverse
@@ -13,10 +16,19 @@ pub fn handle_neq_op(
         (Object::Array(left_arr), Object::Array(right_arr)) => {
             Array::not_equal(left_arr, right_arr, evaluator)?;
         }
+        (Object::Struct(left_struct), Object::Struct(right_struct)) => {
+            Struct::not_equal(left_struct, right_struct, evaluator)?;
+        }
         (left, right) => {
             let result = handle_sub_op(left, right, evaluator)?;
+            if result.is_constant() {
+                return Err(RuntimeErrorKind::UnstructuredError {
+                    span: Default::default(),
+                    message: format!("cannot constrain two constants"),
+                });
+            }
             // Add an inversion to ensure that the inverse exists
-            let _ = invert(result, evaluator);
+            invert(result, evaluator)?;
         }
     }
     Ok(Object::Null)
@@ -0,0 +1,21 @@
+use crate::{Evaluator, FieldElement, Object, RuntimeErrorKind};
+
+/// Computes the bitwise not of an integer, or the logical not of a boolean
+pub fn handle_not_op(rhs: Object, evaluator: &mut Evaluator) -> Result<Object, RuntimeErrorKind> {
+    match rhs {
+        Object::Integer(x) => Ok(Object::Integer(x.not(evaluator)?)),
+        // Booleans are witnesses or constants which have been constrained to be 0 or 1
+        // So their negation is 1 - x
+        rhs if super::is_boolean(&rhs) => {
+            let one = Object::Constants(FieldElement::one());
+            super::handle_sub_op(one, rhs, evaluator)
+        }
+        x => Err(RuntimeErrorKind::UnstructuredError {
+            span: Default::default(),
+            message: format!(
+                "the not operation is only available on integers and booleans, found type : {}",
+                x.r#type()
+            ),
+        }),
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{Evaluator, Object, RuntimeErrorKind};
+
+pub fn handle_or_op(
+    left: Object,
+    right: Object,
+    evaluator: &mut Evaluator,
+) -> Result<Object, RuntimeErrorKind> {
+    match (left, right) {
+        (Object::Integer(x), Object::Integer(y)) => Ok(Object::Integer(x.or(y, evaluator)?)),
+        // Constants, such as masks, take the width of the integer they are applied to
+        (Object::Integer(x), Object::Constants(c)) | (Object::Constants(c), Object::Integer(x)) => {
+            let y = x.bitwise_operand(c, evaluator)?;
+            Ok(Object::Integer(x.or(y, evaluator)?))
+        }
+        // For booleans, x | y = x + y - xy
+        (x, y) if super::is_boolean(&x) && super::is_boolean(&y) => {
+            let product = super::handle_mul_op(x.clone(), y.clone(), evaluator)?;
+            let sum = super::handle_add_op(x, y, evaluator)?;
+            super::handle_sub_op(sum, product, evaluator)
+        }
+        (x, y) => Err(RuntimeErrorKind::UnstructuredError {
+            span: Default::default(),
+            message: format!(
+                "bitwise operations are only available on integers, found types : {} and {}",
+                x.r#type(),
+                y.r#type()
+            ),
+        }),
+    }
+}
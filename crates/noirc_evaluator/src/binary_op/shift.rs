@@ -0,0 +1,41 @@
+use crate::{Evaluator, Integer, Object, RuntimeErrorKind};
+
+/// Shifts are only supported for integers and by a constant amount
+/// a << b is computed as a * 2^b, truncated to the bit size of a
+pub fn handle_shift_left_op(
+    left: Object,
+    right: Object,
+    evaluator: &mut Evaluator,
+) -> Result<Object, RuntimeErrorKind> {
+    let (integer, shift) = shift_operands(left, right)?;
+    Ok(Object::Integer(integer.shift_left(shift, evaluator)?))
+}
+
+/// a >> b is computed as the quotient of a / 2^b
+pub fn handle_shift_right_op(
+    left: Object,
+    right: Object,
+    evaluator: &mut Evaluator,
+) -> Result<Object, RuntimeErrorKind> {
+    let (integer, shift) = shift_operands(left, right)?;
+    Ok(Object::Integer(integer.shift_right(shift, evaluator)?))
+}
+
+fn shift_operands(left: Object, right: Object) -> Result<(Integer, u32), RuntimeErrorKind> {
+    match (left, right) {
+        (Object::Integer(integer), Object::Constants(shift)) => {
+            // Shifting by more than u32::MAX bits is always an error,
+            // so we saturate here and let the integer report it
+            let shift = shift.to_u128().min(u32::MAX as u128) as u32;
+            Ok((integer, shift))
+        }
+        (x, y) => Err(RuntimeErrorKind::UnstructuredError {
+            span: Default::default(),
+            message: format!(
+                "shifts are only available on integers, by a constant amount. found types : {} and {}",
+                x.r#type(),
+                y.r#type()
+            ),
+        }),
+    }
+}
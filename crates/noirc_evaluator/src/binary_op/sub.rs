@@ -1,5 +1,8 @@
 use super::add::handle_add_op;
-use crate::{object::Array, Evaluator, Object, RuntimeErrorKind};
+use crate::{
+    object::{Array, Struct},
+    Evaluator, Object, RuntimeErrorKind,
+};
 
 /// This calls the add op under the hood
 /// We negate the RHS and send it to the add op
@@ -44,6 +47,21 @@ pub fn handle_sub_op(
                 }
             }
         }
+        Object::Struct(right_struct) => match left.structure() {
+            Some(left_struct) => {
+                return Ok(Object::Struct(Struct::sub(
+                    left_struct,
+                    right_struct,
+                    evaluator,
+                )?))
+            }
+            None => {
+                return Err(RuntimeErrorKind::UnstructuredError {
+                    span: Default::default(),
+                    message: format!("rhs is a struct, however the lhs is not"),
+                })
+            }
+        },
     };
 
     handle_add_op(left, negated_right, evaluator)
@@ -1,4 +1,4 @@
-use crate::{Evaluator, Object, RuntimeErrorKind};
+use crate::{Evaluator, FieldElement, Object, RuntimeErrorKind};
 
 pub fn handle_xor_op(
     left: Object,
@@ -7,6 +7,19 @@ pub fn handle_xor_op(
 ) -> Result<Object, RuntimeErrorKind> {
     match (left, right) {
         (Object::Integer(x), Object::Integer(y)) => Ok(Object::Integer(x.xor(y, evaluator)?)),
+        // Constants, such as masks, take the width of the integer they are applied to
+        (Object::Integer(x), Object::Constants(c)) | (Object::Constants(c), Object::Integer(x)) => {
+            let y = x.bitwise_operand(c, evaluator)?;
+            Ok(Object::Integer(x.xor(y, evaluator)?))
+        }
+        // For booleans, x ^ y = x + y - 2xy
+        (x, y) if super::is_boolean(&x) && super::is_boolean(&y) => {
+            let product = super::handle_mul_op(x.clone(), y.clone(), evaluator)?;
+            let two = FieldElement::one() + FieldElement::one();
+            let double_product = super::handle_mul_op(product, Object::Constants(two), evaluator)?;
+            let sum = super::handle_add_op(x, y, evaluator)?;
+            super::handle_sub_op(sum, double_product, evaluator)
+        }
         (x, y) => {
             return Err(RuntimeErrorKind::UnstructuredError {
                 span: Default::default(),
@@ -0,0 +1,351 @@
+/// This module handles if expressions whose condition is a witness.
+///
+/// Since the condition is not known at compile time, both branches are evaluated.
+/// The result of the if expression is then selected using the condition `c`
+/// such that `result = c * consequence + (1 - c) * alternative`.
+///
+/// Constraints which are added inside of a branch are only enforced if the
+/// branch is taken. This is done by multiplying them with a predicate, which
+/// is the product of the conditions of all the branches that we are in.
+///
+/// Constraints which can fail, such as range constraints and the constraint that
+/// a divisor has an inverse, are also predicated. They are applied to `predicate * x`
+/// instead of `x`, which is `x` if the branch is taken and zero otherwise.
+///
+/// Match expressions on an enum whose discriminant is a witness are lowered in
+/// the same way. Each variant has a one-hot selector `s_i = (discriminant == i)`,
+/// which is used as the condition of the arms matching that variant.
+use acvm::acir::circuit::gate::{Directive, Gate};
+use acvm::acir::native_types::{Arithmetic, Linear, Witness};
+use noir_field::FieldElement;
+use noirc_frontend::hir_def::expr::{HirBinaryOp, HirBinaryOpKind};
+
+use crate::binary_op::{handle_add_op, handle_comparison_op, handle_mul_op, handle_sub_op};
+use crate::object::{Array, Integer, Object, Struct};
+use crate::{Evaluator, RuntimeErrorKind};
+
+/// Converts the condition of an if expression into a witness which is either 0 or 1
+pub fn boolean_condition(
+    condition: Object,
+    evaluator: &mut Evaluator,
+) -> Result<Object, RuntimeErrorKind> {
+    match condition {
+        // A u1 has already been constrained to be a boolean
+        Object::Integer(integer) if integer.num_bits == 1 => {
+            Ok(Object::from_witness(integer.witness))
+        }
+        Object::Integer(integer) => to_boolean(Object::from_witness(integer.witness), evaluator),
+        Object::Linear(_) | Object::Arithmetic(_) => to_boolean(condition, evaluator),
+        _ => Err(RuntimeErrorKind::expected_type(
+            "witness",
+            condition.r#type(),
+        )),
+    }
+}
+
+/// Returns `predicate * x`, where the predicate is the condition under which the constraints
+/// being added are enforced. Outside of a branch with a witness condition, this is `x`.
+pub fn apply_predicate(
+    x: Object,
+    evaluator: &mut Evaluator,
+) -> Result<Arithmetic, RuntimeErrorKind> {
+    let predicate = match &evaluator.predicate {
+        // An integer predicate is multiplied as a linear polynomial, since an integer
+        // multiplication would apply a range constraint, which is predicated itself
+        Some(predicate) => match predicate.linear() {
+            Some(linear) => Object::Linear(linear),
+            None => predicate.clone(),
+        },
+        None => return into_arithmetic(&x),
+    };
+    let product = handle_mul_op(predicate, x, evaluator)?;
+    into_arithmetic(&product)
+}
+
+/// Returns a witness which is equal to `witness` if the current predicate is 1, and zero otherwise.
+///
+/// Zero satisfies range and boolean constraints, so these are applied to the returned witness
+/// in order to only enforce them if the branch that we are in is taken.
+pub fn predicated_witness(
+    witness: Witness,
+    evaluator: &mut Evaluator,
+) -> Result<Witness, RuntimeErrorKind> {
+    if evaluator.predicate.is_none() {
+        return Ok(witness);
+    }
+    let product = apply_predicate(Object::from_witness(witness), evaluator)?;
+    let (_, witness) = evaluator.create_intermediate_variable(product);
+    Ok(witness)
+}
+
+fn to_boolean(condition: Object, evaluator: &mut Evaluator) -> Result<Object, RuntimeErrorKind> {
+    let boolean = Integer::from_object(condition, 1, evaluator)?;
+    boolean.constrain(evaluator)?;
+    Ok(Object::from_witness(boolean.witness))
+}
+
+/// Selects between the results of both branches of an if expression.
+///
+/// `condition` must be a witness which has been constrained to be a boolean.
+pub fn select(
+    condition: &Object,
+    consequence: Object,
+    alternative: Object,
+    evaluator: &mut Evaluator,
+) -> Result<Object, RuntimeErrorKind> {
+    match (consequence, alternative) {
+        (Object::Null, Object::Null) => Ok(Object::Null),
+        (Object::Array(consequence), Object::Array(alternative)) => {
+            check_num_elements(consequence.contents.len(), alternative.contents.len())?;
+
+            let mut contents = Vec::with_capacity(consequence.contents.len());
+            for (lhs, rhs) in consequence.contents.into_iter().zip(alternative.contents) {
+                contents.push(select(condition, lhs, rhs, evaluator)?);
+            }
+
+            Ok(Object::Array(Array {
+                contents,
+                length: consequence.length,
+            }))
+        }
+        (Object::Struct(consequence), Object::Struct(alternative)) => {
+            check_num_elements(consequence.contents.len(), alternative.contents.len())?;
+
+            let mut contents = Vec::with_capacity(consequence.contents.len());
+            for (lhs, rhs) in consequence.contents.into_iter().zip(alternative.contents) {
+                contents.push(select(condition, lhs, rhs, evaluator)?);
+            }
+
+            Ok(Object::Struct(Struct { contents }))
+        }
+        (consequence, alternative) => {
+            let integer_type = match (consequence.integer(), alternative.integer()) {
+                (Some(lhs), Some(rhs)) if lhs.num_bits == rhs.num_bits => {
+                    // Both values are in range, so the selected value will be too
+                    let result = select_field(condition, &consequence, &alternative, evaluator)?;
+                    let (_, witness) = evaluator.create_intermediate_variable(result);
+                    let integer = Integer::from_witness(witness, lhs.num_bits).with_sign(lhs.sign);
+                    return Ok(Object::Integer(integer));
+                }
+                (Some(integer), _) | (_, Some(integer)) => Some(integer),
+                (None, None) => None,
+            };
+
+            let result = select_field(condition, &consequence, &alternative, evaluator)?;
+            match integer_type {
+                // One of the values is not known to be in range, so we need to constrain the result
+                Some(integer_type) => {
+                    let integer =
+                        Integer::from_arithmetic(result, integer_type.num_bits, evaluator);
+                    integer.constrain(evaluator)?;
+                    Ok(Object::Integer(integer.with_sign(integer_type.sign)))
+                }
+                None => Ok(Object::Arithmetic(result)),
+            }
+        }
+    }
+}
+
+// Computes `c * (a - b) + b`, which is `a` if `c` is 1 and `b` if `c` is 0
+fn select_field(
+    condition: &Object,
+    consequence: &Object,
+    alternative: &Object,
+    evaluator: &mut Evaluator,
+) -> Result<Arithmetic, RuntimeErrorKind> {
+    let alternative = into_arithmetic(alternative)?;
+    let difference = &into_arithmetic(consequence)? - &alternative;
+
+    let scaled_difference =
+        handle_mul_op(condition.clone(), Object::Arithmetic(difference), evaluator)?;
+    let result = handle_add_op(
+        scaled_difference,
+        Object::Arithmetic(alternative),
+        evaluator,
+    )?;
+
+    Ok(result
+        .into_arithmetic()
+        .expect("ice: the sum of two arithmetic objects should be arithmetic"))
+}
+
+/// Constrains the discriminant of an enum to be less than the number of variants,
+/// by enforcing `(d - 0) * (d - 1) * ... * (d - (n - 1)) = 0`.
+pub fn constrain_discriminant(
+    discriminant: Object,
+    num_variants: usize,
+    evaluator: &mut Evaluator,
+) -> Result<(), RuntimeErrorKind> {
+    if num_variants == 0 {
+        return Err(RuntimeErrorKind::Spanless(format!(
+            "an enum without any variants cannot be used as a parameter of main"
+        )));
+    }
+
+    let mut product = discriminant.clone();
+    for variant in 1..num_variants {
+        let difference = handle_sub_op(
+            discriminant.clone(),
+            Object::Constants(FieldElement::from(variant as i128)),
+            evaluator,
+        )?;
+        product = handle_mul_op(product, difference, evaluator)?;
+    }
+    product.constrain_zero(evaluator)?;
+    Ok(())
+}
+
+/// Returns the selector `s_i = (discriminant == i)` of each variant of an enum.
+///
+/// The discriminant is always less than the number of variants, so exactly one
+/// of the selectors is 1. The last selector is therefore `1 - (s_0 + ... + s_{n-2})`
+/// which does not require an equality check.
+pub fn one_hot_selectors(
+    discriminant: Object,
+    num_variants: usize,
+    evaluator: &mut Evaluator,
+) -> Result<Vec<Object>, RuntimeErrorKind> {
+    let mut selectors = Vec::with_capacity(num_variants);
+    let mut last_selector = Object::Constants(FieldElement::one());
+    for variant in 0..num_variants.saturating_sub(1) {
+        let selector = handle_comparison_op(
+            discriminant.clone(),
+            Object::Constants(FieldElement::from(variant as i128)),
+            HirBinaryOpKind::Equal,
+            evaluator,
+        )?;
+        last_selector = handle_sub_op(last_selector, selector.clone(), evaluator)?;
+        selectors.push(selector);
+    }
+    if num_variants > 0 {
+        selectors.push(last_selector);
+    }
+    Ok(selectors)
+}
+
+/// Applies a constrain statement, which is only enforced when the predicate is equal to 1.
+pub fn handle_predicated_constrain(
+    predicate: &Object,
+    lhs: Object,
+    rhs: Object,
+    op: HirBinaryOp,
+    evaluator: &mut Evaluator,
+) -> Result<(), RuntimeErrorKind> {
+    match op.kind {
+        HirBinaryOpKind::Equal => predicated_equal(predicate, lhs, rhs, evaluator),
+        HirBinaryOpKind::NotEqual => predicated_not_equal(predicate, lhs, rhs, evaluator),
+        HirBinaryOpKind::Less
+        | HirBinaryOpKind::LessEqual
+        | HirBinaryOpKind::Greater
+        | HirBinaryOpKind::GreaterEqual => {
+            // At the top level, a field element is compared to a constant by range constraining
+            // their difference, which would fail the proof even if the branch is not taken.
+            // Comparing the field elements as values would need a decomposition at the full
+            // width of the field, which is not supported
+            if !matches!(lhs, Object::Integer(_)) && !matches!(rhs, Object::Integer(_)) {
+                return Err(RuntimeErrorKind::UnstructuredError {
+                    span: Default::default(),
+                    message: format!(
+                        "field elements cannot be compared with {:?} in a constrain statement which depends on a witness condition, found types {} and {}. Cast them to an integer type first",
+                        op.kind,
+                        lhs.r#type(),
+                        rhs.r#type()
+                    ),
+                });
+            }
+            // The comparison does not constrain its operands, so it is safe to evaluate
+            // even if the branch is not taken
+            let holds = handle_comparison_op(lhs, rhs, op.kind, evaluator)?;
+            predicated_equal(
+                predicate,
+                holds,
+                Object::Constants(FieldElement::one()),
+                evaluator,
+            )
+        }
+        _ => Err(RuntimeErrorKind::Unimplemented(format!(
+            "the {:?} operation cannot be used in a constrain statement which depends on a witness condition",
+            op.kind
+        ))),
+    }
+}
+
+// predicate * (lhs - rhs) = 0
+fn predicated_equal(
+    predicate: &Object,
+    lhs: Object,
+    rhs: Object,
+    evaluator: &mut Evaluator,
+) -> Result<(), RuntimeErrorKind> {
+    let (lhs_contents, rhs_contents) = match (lhs, rhs) {
+        (Object::Array(lhs), Object::Array(rhs)) => (lhs.contents, rhs.contents),
+        (Object::Struct(lhs), Object::Struct(rhs)) => (lhs.contents, rhs.contents),
+        (lhs, rhs) => {
+            // Integers are not subtracted using the integer sub op, as it would
+            // range constrain the difference, even if the branch is not taken
+            let difference = &into_arithmetic(&lhs)? - &into_arithmetic(&rhs)?;
+            let result =
+                handle_mul_op(predicate.clone(), Object::Arithmetic(difference), evaluator)?;
+            result.constrain_zero(evaluator)?;
+            return Ok(());
+        }
+    };
+
+    check_num_elements(lhs_contents.len(), rhs_contents.len())?;
+    for (lhs, rhs) in lhs_contents.into_iter().zip(rhs_contents) {
+        predicated_equal(predicate, lhs, rhs, evaluator)?;
+    }
+    Ok(())
+}
+
+// predicate * ((lhs - rhs) * inverse - 1) = 0
+fn predicated_not_equal(
+    predicate: &Object,
+    lhs: Object,
+    rhs: Object,
+    evaluator: &mut Evaluator,
+) -> Result<(), RuntimeErrorKind> {
+    let difference = &into_arithmetic(&lhs)? - &into_arithmetic(&rhs)?;
+    let (_, difference) = evaluator.create_intermediate_variable(difference);
+
+    // If the branch is not taken, the difference may be zero
+    // in which case the inverse will also be zero
+    let inverse = evaluator.add_witness_to_cs();
+    evaluator.gates.push(Gate::Directive(Directive::Invert {
+        x: difference,
+        result: inverse,
+    }));
+
+    let product: Arithmetic =
+        (Linear::from_witness(difference) * Linear::from_witness(inverse)).into();
+    let should_be_zero = product - &FieldElement::one();
+
+    let result = handle_mul_op(
+        predicate.clone(),
+        Object::Arithmetic(should_be_zero),
+        evaluator,
+    )?;
+    result.constrain_zero(evaluator)?;
+    Ok(())
+}
+
+fn into_arithmetic(object: &Object) -> Result<Arithmetic, RuntimeErrorKind> {
+    object
+        .into_arithmetic()
+        .ok_or_else(|| RuntimeErrorKind::expected_type("witness", object.r#type()))
+}
+
+fn check_num_elements(lhs_len: usize, rhs_len: usize) -> Result<(), RuntimeErrorKind> {
+    // The type checker ensures that both values have the same type
+    // so this is only a sanity check.
+    if lhs_len != rhs_len {
+        return Err(RuntimeErrorKind::UnstructuredError {
+            span: Default::default(),
+            message: format!(
+                "both branches of an if expression must contain the same number of elements. lhs : {} , rhs : {}",
+                lhs_len, rhs_len
+            ),
+        });
+    }
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use super::errors::RuntimeErrorKind;
 use super::object::{Array, Object};
 use acvm::acir::native_types::Witness;
@@ -14,9 +16,19 @@ pub enum FuncContext {
     NonMain,
 }
 
+/// Records the value a variable held before it was assigned to,
+/// so that the assignment can be undone.
+struct Assignment {
+    tree: usize,
+    scope: usize,
+    name: String,
+    old_value: Object,
+}
+
 pub struct Environment {
     pub func_context: FuncContext,
     env: ScopeForest,
+    assignments: Vec<Assignment>,
 }
 
 impl Environment {
@@ -32,6 +44,7 @@ impl Environment {
         Environment {
             func_context,
             env: ScopeForest::new(),
+            assignments: Vec::new(),
         }
     }
 
@@ -50,11 +63,74 @@ impl Environment {
         self.env.end_for_loop();
     }
 
+    pub fn start_if_branch(&mut self) {
+        self.env.start_if_branch()
+    }
+
+    pub fn end_if_branch(&mut self) {
+        self.env.end_if_branch();
+    }
+
     pub fn store(&mut self, name: String, object: Object) {
         let scope = self.env.get_mut_scope();
         scope.add_key_value(name.clone(), object);
     }
 
+    /// Rebinds a variable which has already been declared.
+    ///
+    /// Each assignment creates a new version of the variable, objects which
+    /// refer to the old version are not affected.
+    pub fn assign(&mut self, name: &String, object: Object) {
+        let tree = self.env.0.len() - 1;
+        let scope_tree = self.env.current_scope_tree();
+        let (scope, old_value) = scope_tree
+            .0
+            .iter_mut()
+            .enumerate()
+            .rev()
+            .find_map(|(index, scope)| scope.find(name).map(|value| (index, value)))
+            .expect("ice: the resolver ensures that assigned variables have been declared");
+        let old_value = std::mem::replace(old_value, object);
+
+        self.assignments.push(Assignment {
+            tree,
+            scope,
+            name: name.clone(),
+            old_value,
+        });
+    }
+
+    /// Returns a marker which can later be passed to `undo_assignments`
+    pub fn assignments_mark(&self) -> usize {
+        self.assignments.len()
+    }
+
+    /// Undoes all of the assignments which were made since `mark` was taken.
+    ///
+    /// Returns the last value assigned to each variable which is still in scope.
+    /// This is used to merge the assignments made in both branches of an if expression.
+    pub fn undo_assignments(&mut self, mark: usize) -> BTreeMap<String, Object> {
+        let mut last_values = BTreeMap::new();
+        while self.assignments.len() > mark {
+            let assignment = self.assignments.pop().unwrap();
+
+            // Variables declared in a scope which has since ended can be ignored
+            let value = self
+                .env
+                .0
+                .get_mut(assignment.tree)
+                .and_then(|tree| tree.0.get_mut(assignment.scope))
+                .and_then(|scope| scope.find(&assignment.name));
+
+            if let Some(value) = value {
+                let last_value = std::mem::replace(value, assignment.old_value);
+                // Assignments are undone in reverse, so the first value we see is the last one assigned
+                last_values.entry(assignment.name).or_insert(last_value);
+            }
+        }
+        last_values
+    }
+
     pub fn get(&mut self, name: &String) -> Object {
         let scope = self.env.current_scope_tree();
         scope.find(name).unwrap().clone()
@@ -66,9 +142,11 @@ impl Environment {
         let mut found = None;
         for scope in self.env.current_scope_tree().0.iter().rev() {
             found = scope.0.iter().find_map(|(k, v)| match v {
-                Object::Null | Object::Array(_) | Object::Constants(_) | Object::Arithmetic(_) => {
-                    None
-                }
+                Object::Null
+                | Object::Array(_)
+                | Object::Struct(_)
+                | Object::Constants(_)
+                | Object::Arithmetic(_) => None,
                 Object::Integer(x) => {
                     // Integers are assumed to always be unit
                     (&x.witness == val).then(|| k)
@@ -30,6 +30,20 @@ pub enum RuntimeErrorKind {
     #[error("unimplemented")]
     Unimplemented(String),
 
+    // The span is that of the constrain statement, which is
+    // added once the error reaches the statement
+    #[error("Constraint can never be satisfied")]
+    UnsatisfiableConstraint { span: Span },
+
+    #[error("Loop iteration limit exceeded")]
+    LoopIterationLimit { limit: u64, span: Span },
+
+    // This is not an error. It stops the evaluation of the statements after a return
+    // statement, until the function or the branch with a witness condition that the
+    // return statement is in has been evaluated, where it is caught.
+    #[error("return statement outside of a function")]
+    Return,
+
     #[error("Unsupported operation error")]
     UnsupportedOp {
         span: Span,
@@ -80,6 +94,22 @@ impl DiagnosableError for RuntimeErrorKind {
                 ),
                 *span,
             ),
+            RuntimeErrorKind::LoopIterationLimit { limit, span } => {
+                let mut diag = Diagnostic::simple_error(
+                    "while loop exceeded the maximum number of iterations".to_owned(),
+                    format!("this condition was still true after {} iterations", limit),
+                    *span,
+                );
+                diag.add_note(
+                    "while loops are unrolled at compile time, so their condition must eventually become false".to_owned(),
+                );
+                diag
+            }
+            RuntimeErrorKind::UnsatisfiableConstraint { span } => Diagnostic::simple_error(
+                "constraint can never be satisfied".to_owned(),
+                "the two sides differ by a non-zero constant".to_owned(),
+                *span,
+            ),
             RuntimeErrorKind::Spanless(message) => Diagnostic::from_message(&message),
             RuntimeErrorKind::Unimplemented(message) => Diagnostic::from_message(&message),
             RuntimeErrorKind::FunctionNonMainContext { func_name, span } => {
@@ -89,6 +119,9 @@ impl DiagnosableError for RuntimeErrorKind {
                     *span,
                 )
             }
+            RuntimeErrorKind::Return => {
+                unreachable!("ice: return statements are caught by the function they are in")
+            }
         }
     }
 }
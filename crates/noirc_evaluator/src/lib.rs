@@ -1,6 +1,7 @@
 mod binary_op;
 
 mod builtin;
+mod conditional;
 mod environment;
 mod errors;
 mod low_level_function_impl;
@@ -13,19 +14,25 @@ use acvm::acir::circuit::{
 use acvm::acir::native_types::{Arithmetic, Linear, Witness};
 use acvm::BackendPointer;
 use environment::{Environment, FuncContext};
-use errors::RuntimeErrorKind;
+pub use errors::RuntimeErrorKind;
 use noir_field::FieldElement;
+use noirc_errors::Span;
 use noirc_frontend::hir::Context;
 use noirc_frontend::hir_def::{
     expr::{
         HirBinaryOp, HirBinaryOpKind, HirBlockExpression, HirCallExpression, HirExpression,
-        HirForExpression, HirLiteral,
+        HirForExpression, HirIfExpression, HirLiteral, HirMatchExpression, HirMatchPattern,
+        HirUnaryOp, HirWhileExpression,
+    },
+    stmt::{
+        HirAssignStatement, HirConstrainStatement, HirLValue, HirLetStatement, HirPattern,
+        HirPrivateStatement, HirStatement,
     },
-    stmt::{HirConstrainStatement, HirLetStatement, HirPrivateStatement, HirStatement},
 };
 use noirc_frontend::node_interner::{ExprId, FuncId, IdentId, StmtId};
-use noirc_frontend::{FunctionKind, Type};
-use object::{Array, Integer, Object, RangedObject};
+use noirc_frontend::{FunctionKind, Signedness, Type};
+use object::{Array, Integer, Object, RangedObject, Struct};
+use std::collections::{BTreeMap, BTreeSet};
 pub struct Evaluator<'a> {
     // Why is this not u64?
     //
@@ -37,6 +44,45 @@ pub struct Evaluator<'a> {
     public_inputs: Vec<Witness>,
     main_function: FuncId,
     gates: Vec<Gate>,
+    // The condition under which the constraints being added are enforced.
+    // This is only set when evaluating a branch of an if expression with a witness condition.
+    predicate: Option<Object>,
+    // The number of iterations after which unrolling a while loop is aborted
+    max_loop_iterations: u64,
+    // The return statements evaluated in each function call that is being evaluated.
+    // The innermost call is last.
+    function_returns: Vec<FunctionReturn>,
+}
+
+/// The return statements which have been evaluated in a function call.
+///
+/// A return statement stops the evaluation of the function. However, if it is in a branch
+/// with a witness condition, the statements after the branch are still evaluated, since the
+/// branch may not be taken. So it only stops the evaluation of the branch, and the returned
+/// value is selected using the predicate of the branch. The statements after the branch
+/// are then evaluated under a predicate which excludes the paths which have returned, so
+/// their constraints, including those of divisions and range constraints, are only enforced
+/// on the paths which have not returned.
+#[derive(Default)]
+struct FunctionReturn {
+    // The value returned on the paths which have returned so far
+    value: Option<Object>,
+    // A witness which is 1 on the paths which have returned so far, and 0 otherwise
+    returned: Option<Object>,
+    num_returns: usize,
+    // The number of branches with a witness condition that we are in, inside of the function
+    branch_depth: usize,
+}
+
+/// The default number of iterations a while loop can be unrolled for
+pub const DEFAULT_MAX_LOOP_ITERATIONS: u64 = 10_000;
+
+// Signed integers are passed in two's complement, so they are constrained in the same way as unsigned integers
+fn abi_sign(sign: noirc_abi::Sign) -> Signedness {
+    match sign {
+        noirc_abi::Sign::Unsigned => Signedness::Unsigned,
+        noirc_abi::Sign::Signed => Signedness::Signed,
+    }
 }
 
 impl<'a> Evaluator<'a> {
@@ -55,9 +101,19 @@ impl<'a> Evaluator<'a> {
             context,
             main_function,
             gates: Vec::new(),
+            predicate: None,
+            max_loop_iterations: DEFAULT_MAX_LOOP_ITERATIONS,
+            function_returns: Vec::new(),
         }
     }
 
+    /// Sets the number of iterations after which unrolling a while loop fails.
+    /// This stops loops whose condition never becomes false from hanging the compiler.
+    pub fn with_max_loop_iterations(mut self, max_loop_iterations: u64) -> Self {
+        self.max_loop_iterations = max_loop_iterations;
+        self
+    }
+
     // Creates a new Witness index
     fn add_witness_to_cs(&mut self) -> Witness {
         self.current_witness_index += 1;
@@ -135,6 +191,7 @@ impl<'a> Evaluator<'a> {
             HirBinaryOpKind::Subtract => binary_op::handle_sub_op(lhs, rhs, self),
             HirBinaryOpKind::Multiply => binary_op::handle_mul_op(lhs, rhs, self),
             HirBinaryOpKind::Divide => binary_op::handle_div_op(lhs, rhs, self),
+            HirBinaryOpKind::Modulo => binary_op::handle_modulo_op(lhs, rhs, self),
             HirBinaryOpKind::NotEqual => binary_op::handle_neq_op(lhs, rhs, self),
             HirBinaryOpKind::Equal => binary_op::handle_equal_op(lhs, rhs, self),
             HirBinaryOpKind::And => binary_op::handle_and_op(lhs, rhs, self),
@@ -152,10 +209,10 @@ impl<'a> Evaluator<'a> {
                 );
                 return Err(err);
             }
-            HirBinaryOpKind::Or => {
-                let err = RuntimeErrorKind::Unimplemented("The Or operation is currently not implemented. First implement in Barretenberg.".to_owned());
-                return Err(err);
-            }
+            HirBinaryOpKind::Or => binary_op::handle_or_op(lhs, rhs, self),
+            HirBinaryOpKind::ShiftLeft => binary_op::handle_shift_left_op(lhs, rhs, self),
+            HirBinaryOpKind::ShiftRight => binary_op::handle_shift_right_op(lhs, rhs, self),
+            HirBinaryOpKind::Concat => binary_op::handle_concat_op(lhs, rhs),
         }
     }
 
@@ -177,9 +234,7 @@ impl<'a> Evaluator<'a> {
         // It is not possible now due to the aztec standard format requiring a particular ordering of inputs in the ABI
         let main_func_body = self.context.def_interner.function(&self.main_function);
         let block = main_func_body.block(&self.context.def_interner);
-        for stmt_id in block.statements() {
-            self.evaluate_statement(env, stmt_id)?;
-        }
+        self.eval_function_body(env, block.statements())?;
         Ok(())
     }
 
@@ -197,6 +252,11 @@ impl<'a> Evaluator<'a> {
 
         let abi = func_meta.parameters.to_abi(&self.context.def_interner);
 
+        // Constraining a discriminant requires intermediate witnesses, which would
+        // otherwise be interleaved with the witnesses of the parameters.
+        // The discriminants are therefore constrained once every parameter is allocated.
+        let mut discriminants = Vec::new();
+
         for (param_name, param_type) in abi.parameters.into_iter() {
             match param_type {
                 noirc_abi::AbiType::Array {
@@ -204,50 +264,25 @@ impl<'a> Evaluator<'a> {
                     length,
                     typ,
                 } => {
-                    let mut elements = Vec::with_capacity(length as usize);
-                    for _ in 0..length as usize {
-                        let witness = self.add_witness_to_cs();
-                        if visibility == noirc_abi::AbiFEType::Public {
-                            self.public_inputs.push(witness);
+                    // The elements of nested arrays are allocated in row-major order,
+                    // so the witnesses of each row follow the witnesses of the previous row
+                    let first_witness = self.current_witness_index + 1;
+                    let arr = self.allocate_private_param(
+                        noirc_abi::AbiType::Array {
+                            visibility: noirc_abi::AbiFEType::Private,
+                            length,
+                            typ,
+                        },
+                        &mut discriminants,
+                    )?;
+
+                    // Allocating the elements does not create any intermediate witnesses
+                    if visibility == noirc_abi::AbiFEType::Public {
+                        for index in first_witness..=self.current_witness_index {
+                            self.public_inputs.push(Witness(index));
                         }
-
-                        // Constrain each element in the array to be equal to the type declared in the parameter
-                        let object = match *typ {
-                            noirc_abi::AbiType::Integer {
-                                visibility: _,
-                                sign,
-                                width,
-                            } => {
-                                // XXX: Since this is in an array, the visibility
-                                // XXX: should not be settable. By default, it will be Private
-                                // since if the user does not supply a visibility,
-                                // then it is by default Private
-
-                                // Currently we do not support signed integers
-                                assert!(
-                                    sign != noirc_abi::Sign::Signed,
-                                    "signed integers are currently not supported"
-                                );
-
-                                let integer = Integer::from_witness(witness, width);
-                                integer.constrain(self)?;
-                                Object::Integer(integer)
-                            }
-                            noirc_abi::AbiType::Field(noirc_abi::AbiFEType::Private) => {
-                                Object::from_witness(witness)
-                            }
-                            _ => unimplemented!(
-                                "currently we only support arrays of integer and witness types"
-                            ),
-                        };
-
-                        elements.push(object);
                     }
-                    let arr = Array {
-                        contents: elements,
-                        length,
-                    };
-                    env.store(param_name, Object::Array(arr));
+                    env.store(param_name, arr);
                 }
                 noirc_abi::AbiType::Field(noirc_abi::AbiFEType::Private) => {
                     let witness = self.add_witness_to_cs();
@@ -262,13 +297,8 @@ impl<'a> Evaluator<'a> {
                     if visibility == noirc_abi::AbiFEType::Public {
                         self.public_inputs.push(witness);
                     }
-                    // Currently we do not support signed integers
-                    assert!(
-                        sign != noirc_abi::Sign::Signed,
-                        "signed integers are currently not supported"
-                    );
 
-                    let integer = Integer::from_witness(witness, width);
+                    let integer = Integer::from_witness(witness, width).with_sign(abi_sign(sign));
                     integer.constrain(self)?;
 
                     env.store(param_name, Object::Integer(integer));
@@ -278,12 +308,102 @@ impl<'a> Evaluator<'a> {
                     self.public_inputs.push(witness);
                     self.add_witness_to_env(param_name, witness, env);
                 }
+                noirc_abi::AbiType::Boolean => {
+                    let witness = self.add_witness_to_cs();
+
+                    // Booleans are stored as witnesses, so that the logical operations
+                    // do not treat them as integers
+                    Integer::from_witness(witness, 1).constrain(self)?;
+                    self.add_witness_to_env(param_name, witness, env);
+                }
+                noirc_abi::AbiType::Enum { variants } => {
+                    let object = self.allocate_private_param(
+                        noirc_abi::AbiType::Enum { variants },
+                        &mut discriminants,
+                    )?;
+                    env.store(param_name, object);
+                }
+                noirc_abi::AbiType::Struct { fields } => {
+                    let object = self.allocate_private_param(
+                        noirc_abi::AbiType::Struct { fields },
+                        &mut discriminants,
+                    )?;
+                    env.store(param_name, object);
+                }
+                noirc_abi::AbiType::Tuple { fields } => {
+                    let object = self.allocate_private_param(
+                        noirc_abi::AbiType::Tuple { fields },
+                        &mut discriminants,
+                    )?;
+                    env.store(param_name, object);
+                }
             }
         }
 
+        for (discriminant, num_variants) in discriminants {
+            conditional::constrain_discriminant(discriminant, num_variants, self)?;
+        }
+
         Ok(())
     }
 
+    /// Allocates the witnesses for a private parameter in main.
+    ///
+    /// This is used for the fields of a struct or tuple and the elements of an array,
+    /// which cannot have a visibility.
+    /// Witnesses are allocated in the order that the fields were declared,
+    /// which is the same order that the ABI lists them in.
+    /// Enum discriminants are pushed onto `discriminants` to be constrained later.
+    fn allocate_private_param(
+        &mut self,
+        param_type: noirc_abi::AbiType,
+        discriminants: &mut Vec<(Object, usize)>,
+    ) -> Result<Object, RuntimeErrorKind> {
+        let object = match param_type {
+            noirc_abi::AbiType::Field(_) => Object::from_witness(self.add_witness_to_cs()),
+            noirc_abi::AbiType::Integer { sign, width, .. } => {
+                let witness = self.add_witness_to_cs();
+                let integer = Integer::from_witness(witness, width).with_sign(abi_sign(sign));
+                integer.constrain(self)?;
+                Object::Integer(integer)
+            }
+            noirc_abi::AbiType::Boolean => {
+                let witness = self.add_witness_to_cs();
+                Integer::from_witness(witness, 1).constrain(self)?;
+                Object::from_witness(witness)
+            }
+            noirc_abi::AbiType::Enum { variants } => {
+                let discriminant = Object::from_witness(self.add_witness_to_cs());
+                discriminants.push((discriminant.clone(), variants.len()));
+                discriminant
+            }
+            noirc_abi::AbiType::Array { length, typ, .. } => {
+                let mut contents = Vec::with_capacity(length as usize);
+                for _ in 0..length {
+                    contents.push(self.allocate_private_param(*typ.clone(), discriminants)?);
+                }
+                Object::Array(Array { contents, length })
+            }
+            noirc_abi::AbiType::Struct { fields } => {
+                let mut contents = Vec::with_capacity(fields.len());
+                for (_, field_type) in fields {
+                    contents.push(self.allocate_private_param(field_type, discriminants)?);
+                }
+                Object::Struct(Struct { contents })
+            }
+            // Tuples are represented in the same way as structs
+            noirc_abi::AbiType::Tuple { fields } => {
+                let mut contents = Vec::with_capacity(fields.len());
+                for field_type in fields {
+                    contents.push(self.allocate_private_param(field_type, discriminants)?);
+                }
+                Object::Struct(Struct { contents })
+            }
+        };
+
+        Ok(object)
+    }
+
     fn evaluate_statement(
         &mut self,
         env: &mut Environment,
@@ -299,7 +419,13 @@ impl<'a> Evaluator<'a> {
                 let variable_name: String = self.context.def_interner.ident_name(&x.identifier);
                 // const can only be integers/Field elements, cannot involve the witness, so we can possibly move this to
                 // analysis. Right now it would not make a difference, since we are not compiling to an intermediate Noir format
-                let value = self.evaluate_integer(env, &x.expression)?;
+                let value = match self.context.def_interner.expression(&x.expression) {
+                    // Strings are the only constants which are not integers
+                    HirExpression::Literal(HirLiteral::Str(string)) => {
+                        Object::Array(Array::from_string(&string))
+                    }
+                    _ => self.evaluate_integer(env, &x.expression)?,
+                };
 
                 env.store(variable_name, value);
                 Ok(Object::Null)
@@ -313,6 +439,126 @@ impl<'a> Evaluator<'a> {
 
                 Ok(Object::Null)
             }
+            HirStatement::Assign(assign_stmt) => {
+                self.handle_assign_statement(env, assign_stmt)?;
+
+                Ok(Object::Null)
+            }
+            HirStatement::Return(expr) => {
+                let value = self.expression_to_object(env, &expr)?;
+                self.handle_return(value)
+            }
+        }
+    }
+
+    // Records the value returned by a return statement, then stops the evaluation of
+    // the statements after it by returning `RuntimeErrorKind::Return`
+    fn handle_return(&mut self, value: Object) -> Result<Object, RuntimeErrorKind> {
+        let mut function_return = self
+            .function_returns
+            .pop()
+            .expect("ice: return statements are always in a function");
+
+        let previous_value = function_return.value.take();
+        let (value, returned) = if function_return.branch_depth == 0 {
+            // The function returns here on every path which has not returned yet
+            let value = match (&function_return.returned, previous_value) {
+                (Some(returned), Some(previous_value)) => {
+                    conditional::select(returned, previous_value, value, self)?
+                }
+                _ => value,
+            };
+            (value, Object::Constants(FieldElement::one()))
+        } else {
+            // The function only returns here on the paths where the branches we are in are taken.
+            // These paths have not returned yet, as the predicate excludes the paths which have.
+            let predicate = self
+                .predicate
+                .clone()
+                .expect("ice: branches with a witness condition have a predicate");
+            let value = match previous_value {
+                Some(previous_value) => {
+                    conditional::select(&predicate, value, previous_value, self)?
+                }
+                None => value,
+            };
+            let returned = match function_return.returned.take() {
+                Some(returned) => binary_op::handle_add_op(returned, predicate, self)?,
+                None => predicate,
+            };
+            (value, returned)
+        };
+
+        // The value and the condition are stored in witnesses, as they are used
+        // in the gates which select the value returned by the function, and the
+        // condition is also used in the predicate of the constraints after the branch
+        function_return.value = Some(self.into_witness(value));
+        function_return.returned = Some(self.into_witness(returned));
+        function_return.num_returns += 1;
+        self.function_returns.push(function_return);
+
+        Err(RuntimeErrorKind::Return)
+    }
+
+    fn handle_assign_statement(
+        &mut self,
+        env: &mut Environment,
+        assign_stmt: HirAssignStatement,
+    ) -> Result<(), RuntimeErrorKind> {
+        let value = self.expression_to_object(env, &assign_stmt.expression)?;
+
+        // Each assignment gives the variable a new version. If the value is an arithmetic
+        // expression, it is stored in a new witness, so that expressions do not keep
+        // growing when a variable is assigned to on every iteration of a loop.
+        let value = match value {
+            Object::Arithmetic(arith) => self.create_intermediate_variable(arith).0,
+            value => value,
+        };
+
+        match assign_stmt.lvalue {
+            HirLValue::Ident(ident_id) => {
+                let variable_name = self.context.def_interner.ident_name(&ident_id);
+                env.assign(&variable_name, value);
+            }
+            HirLValue::Index { .. } => {
+                let (array, indices) = self.lvalue_indices(env, assign_stmt.lvalue)?;
+                let arr_name = self.context.def_interner.ident_name(&array);
+                let mut arr = env.get_array(&arr_name)?;
+
+                arr.set_nested(&indices, value)?;
+                env.assign(&arr_name, Object::Array(arr));
+            }
+        }
+        Ok(())
+    }
+
+    // Returns the array variable which is being assigned to, and the constant
+    // indices of the element, from the outermost array inwards.
+    fn lvalue_indices(
+        &mut self,
+        env: &mut Environment,
+        lvalue: HirLValue,
+    ) -> Result<(IdentId, Vec<(u128, Span)>), RuntimeErrorKind> {
+        match lvalue {
+            HirLValue::Ident(ident_id) => Ok((ident_id, Vec::new())),
+            HirLValue::Index { array, index } => {
+                let (array, mut indices) = self.lvalue_indices(env, *array)?;
+
+                let span = self.context.def_interner.expr_span(&index);
+                let index_as_obj = self.expression_to_object(env, &index)?;
+                let index_as_constant =
+                    index_as_obj
+                        .constant()
+                        .map_err(|_| RuntimeErrorKind::UnstructuredError {
+                            span,
+                            message:
+                                "array elements can only be assigned to using a constant index"
+                                    .to_owned(),
+                        })?;
+
+                indices.push((index_as_constant.to_u128(), span));
+                Ok((array, indices))
+            }
         }
     }
 
@@ -388,12 +634,43 @@ impl<'a> Evaluator<'a> {
         let lhs_poly = self.expression_to_object(env, &constrain_stmt.0.lhs)?;
         let rhs_poly = self.expression_to_object(env, &constrain_stmt.0.rhs)?;
 
+        // A constraint which can never be satisfied is only detected when the gates are
+        // created, which does not know where the constrain statement is
+        let interner = &self.context.def_interner;
+        let span = interner
+            .expr_span(&constrain_stmt.0.lhs)
+            .merge(interner.expr_span(&constrain_stmt.0.rhs));
+        let add_span = |err| match err {
+            RuntimeErrorKind::UnsatisfiableConstraint { .. } => {
+                RuntimeErrorKind::UnsatisfiableConstraint { span }
+            }
+            err => err,
+        };
+
+        // Inside of a branch which depends on a witness, the constraint must only hold
+        // if the branch is taken. The optimisation below does not apply here either,
+        // as the two sides are not always equal.
+        if let Some(predicate) = &self.predicate {
+            let predicate = predicate.clone();
+            conditional::handle_predicated_constrain(
+                &predicate,
+                lhs_poly,
+                rhs_poly,
+                constrain_stmt.0.operator,
+                self,
+            )
+            .map_err(add_span)?;
+            return Ok(Object::Null);
+        }
+
         // Evaluate the constrain infix statement
-        let _ = self.evaluate_infix_expression(
-            lhs_poly.clone(),
-            rhs_poly.clone(),
-            constrain_stmt.0.operator,
-        )?;
+        let _ = self
+            .evaluate_infix_expression(
+                lhs_poly.clone(),
+                rhs_poly.clone(),
+                constrain_stmt.0.operator,
+            )
+            .map_err(add_span)?;
 
         // The code below is an optimisation strategy for when either side is of the form
         //
@@ -436,26 +713,34 @@ impl<'a> Evaluator<'a> {
         env: &mut Environment,
         let_stmt: HirLetStatement,
     ) -> Result<Object, RuntimeErrorKind> {
-        // Convert the LHS into an identifier
-        let variable_name = self.context.def_interner.ident_name(&let_stmt.identifier);
-
-        // XXX: Currently we only support arrays using this, when other types are introduced
-        // we can extend into a separate (generic) module
-
-        // Extract the array
+        // Unlike a private statement, the variables are bound directly to the object
+        // so no constraint is added here
         let rhs_poly = self.expression_to_object(env, &let_stmt.expression)?;
 
-        match rhs_poly {
-            Object::Array(arr) => {
-                env.store(variable_name.into(), Object::Array(arr));
-            }
-            _ => unimplemented!(
-                "logic for types that are not arrays in a let statement, not implemented yet!"
-            ),
-        };
+        self.bind_pattern(env, &let_stmt.pattern, rhs_poly);
 
         Ok(Object::Null)
     }
+    // Binds each identifier in the pattern to the part of the object that it destructures
+    fn bind_pattern(&mut self, env: &mut Environment, pattern: &HirPattern, object: Object) {
+        match (pattern, object) {
+            (_, Object::Null) => {
+                unreachable!("ice: the type checker does not allow unit values in a let statement")
+            }
+            (HirPattern::Identifier(ident_id), object) => {
+                let variable_name = self.context.def_interner.ident_name(ident_id);
+                env.store(variable_name, object);
+            }
+            (HirPattern::Tuple(patterns, _), Object::Struct(tuple)) => {
+                for (pattern, element) in patterns.iter().zip(tuple.contents) {
+                    self.bind_pattern(env, pattern, element);
+                }
+            }
+            (HirPattern::Tuple(..), _) => {
+                unreachable!("ice: the type checker only allows tuples to be destructured")
+            }
+        }
+    }
     fn handle_for_expr(
         &mut self,
         env: &mut Environment,
@@ -483,16 +768,384 @@ impl<'a> Evaluator<'a> {
 
             let block = self.expression_to_block(&for_expr.block);
             let statements = block.statements();
-            let return_typ = self.eval_block(env, statements)?;
-            contents.push(return_typ);
+            let return_typ = self.eval_block(env, statements);
 
+            // The loop is ended even if the block returned, as the
+            // environment is still used if the return is in a branch
             env.end_for_loop();
+            contents.push(return_typ?);
         }
         let length = contents.len() as u128;
 
         Ok(Object::Array(Array { contents, length }))
     }
 
+    fn handle_while_expr(
+        &mut self,
+        env: &mut Environment,
+        while_expr: HirWhileExpression,
+    ) -> Result<Object, RuntimeErrorKind> {
+        let condition_span = self.context.def_interner.expr_span(&while_expr.condition);
+
+        // The loop is unrolled, so the condition must be known at compile time
+        // on every iteration
+        let mut iterations = 0;
+        loop {
+            let condition = self.expression_to_object(env, &while_expr.condition)?;
+            let condition = match condition {
+                Object::Constants(condition) => condition,
+                _ => {
+                    return Err(RuntimeErrorKind::UnstructuredError {
+                        span: condition_span,
+                        message: "the condition of a while loop must be known at compile time"
+                            .to_owned(),
+                    })
+                }
+            };
+            if condition.is_zero() {
+                break;
+            }
+
+            if iterations == self.max_loop_iterations {
+                return Err(RuntimeErrorKind::LoopIterationLimit {
+                    limit: self.max_loop_iterations,
+                    span: condition_span,
+                });
+            }
+            iterations += 1;
+
+            env.start_for_loop();
+
+            let block = self.expression_to_block(&while_expr.block);
+            let result = self.eval_block(env, block.statements());
+
+            env.end_for_loop();
+            result?;
+        }
+
+        Ok(Object::Null)
+    }
+
+    fn handle_if_expr(
+        &mut self,
+        env: &mut Environment,
+        if_expr: HirIfExpression,
+    ) -> Result<Object, RuntimeErrorKind> {
+        let condition = self.expression_to_object(env, &if_expr.condition)?;
+
+        // If the condition is known at compile time, then we only evaluate the branch which is taken
+        if let Object::Constants(condition) = condition {
+            let branch = if condition.is_zero() {
+                if_expr.alternative
+            } else if condition == FieldElement::one() {
+                Some(if_expr.consequence)
+            } else {
+                // A witness condition is constrained to be either 0 or 1, so this is enforced
+                // for a constant condition too
+                return Err(RuntimeErrorKind::UnstructuredError {
+                    span: self.context.def_interner.expr_span(&if_expr.condition),
+                    message: "the condition of an if expression must be either 0 or 1".to_owned(),
+                });
+            };
+            return match branch {
+                Some(branch) => self.eval_if_branch(env, &branch),
+                None => Ok(Object::Null),
+            };
+        }
+
+        // Otherwise both branches are evaluated, each under their own predicate
+        //
+        // Comparisons already return a witness which is either 0 or 1
+        let condition = match self.context.def_interner.id_type(&if_expr.condition) {
+            Type::Bool => condition,
+            _ => conditional::boolean_condition(condition, self)?,
+        };
+
+        // Variables which are assigned to in a branch are restored once the branch
+        // has been evaluated, and then merged using the condition
+        let mark = env.assignments_mark();
+
+        // The variables assigned to in a branch which returned from the function
+        // are not used after the if expression, on the paths where it is taken
+        let consequence_predicate = self.and_predicate(condition.clone())?;
+        let consequence = self.eval_predicated(consequence_predicate, |evaluator| {
+            evaluator.eval_if_branch(env, &if_expr.consequence)
+        })?;
+        let mut consequence_assignments = env.undo_assignments(mark);
+        if consequence.is_none() {
+            consequence_assignments.clear();
+        }
+
+        let (alternative, alternative_assignments) = match if_expr.alternative {
+            Some(alternative) => {
+                let negated_condition = binary_op::handle_sub_op(
+                    Object::Constants(FieldElement::one()),
+                    condition.clone(),
+                    self,
+                )?;
+                let alternative_predicate = self.and_predicate(negated_condition)?;
+                let alternative = self.eval_predicated(alternative_predicate, |evaluator| {
+                    evaluator.eval_if_branch(env, &alternative)
+                })?;
+                let mut alternative_assignments = env.undo_assignments(mark);
+                if alternative.is_none() {
+                    alternative_assignments.clear();
+                }
+                (Some(alternative), alternative_assignments)
+            }
+            None => (None, BTreeMap::new()),
+        };
+
+        self.merge_assignments(
+            env,
+            &condition,
+            consequence_assignments,
+            alternative_assignments,
+        )?;
+
+        match (consequence, alternative) {
+            (Some(consequence), Some(Some(alternative))) => {
+                conditional::select(&condition, consequence, alternative, self)
+            }
+            // The value of the if expression is only used on the paths where
+            // the branch which did not return is taken
+            (Some(value), Some(None)) | (None, Some(Some(value))) => Ok(value),
+            // Both branches returned, so the statements after the if expression are never evaluated
+            (None, Some(None)) => Err(RuntimeErrorKind::Return),
+            // Without an else branch, the if expression does not return a value
+            (_, None) => Ok(Object::Null),
+        }
+    }
+
+    fn handle_match_expr(
+        &mut self,
+        env: &mut Environment,
+        match_expr: HirMatchExpression,
+    ) -> Result<Object, RuntimeErrorKind> {
+        let discriminant = self.expression_to_object(env, &match_expr.expression)?;
+
+        // If the discriminant is known at compile time, then we only evaluate the arm which is taken
+        if let Object::Constants(discriminant) = discriminant {
+            let arm = match_expr.arms.iter().find(|(pattern, _)| match pattern {
+                HirMatchPattern::Variant(variant, _) => {
+                    FieldElement::from(variant.index as i128) == discriminant
+                }
+                HirMatchPattern::Wildcard(_) => true,
+            });
+            return match arm {
+                Some((_, arm)) => self.eval_match_arm(env, arm),
+                None => Ok(Object::Null),
+            };
+        }
+
+        // Otherwise every arm is evaluated, each under the sum of the selectors
+        // of the variants it matches. A wildcard matches the variants which
+        // were not matched by a previous arm.
+        let num_variants = match self.context.def_interner.id_type(&match_expr.expression) {
+            Type::Enum(enum_type) => enum_type.variants.len(),
+            _ => unreachable!("ice: the type checker ensures that only enums are matched on"),
+        };
+        let selectors = conditional::one_hot_selectors(discriminant, num_variants, self)?;
+        let mut unmatched = vec![true; num_variants];
+
+        let mark = env.assignments_mark();
+        let has_arms = !match_expr.arms.is_empty();
+        let mut arms = Vec::with_capacity(match_expr.arms.len());
+        for (pattern, arm) in match_expr.arms {
+            let variants: Vec<usize> = match pattern {
+                HirMatchPattern::Variant(variant, _) => vec![variant.index],
+                HirMatchPattern::Wildcard(_) => (0..num_variants)
+                    .filter(|variant| unmatched[*variant])
+                    .collect(),
+            };
+
+            let mut selector = Object::Constants(FieldElement::zero());
+            for variant in variants {
+                unmatched[variant] = false;
+                selector = binary_op::handle_add_op(selector, selectors[variant].clone(), self)?;
+            }
+
+            let predicate = self.and_predicate(selector.clone())?;
+            let result =
+                self.eval_predicated(predicate, |evaluator| evaluator.eval_match_arm(env, &arm))?;
+            let assignments = env.undo_assignments(mark);
+
+            // An arm which returned from the function does not produce a value,
+            // and its assignments are not used after the match expression
+            if let Some(result) = result {
+                arms.push((selector, result, assignments));
+            }
+        }
+
+        // Exactly one of the selectors is 1, so the result is `s_0 * arm_0 + ... + s_n * arm_n`.
+        // This is computed by selecting between each arm and the result of the arms after it.
+        let (_, mut result, mut assignments) = match arms.pop() {
+            Some(last_arm) => last_arm,
+            // Every arm returned, so the statements after the match expression are never evaluated
+            None if has_arms => return Err(RuntimeErrorKind::Return),
+            None => return Ok(Object::Null),
+        };
+        while let Some((selector, arm_result, arm_assignments)) = arms.pop() {
+            result = conditional::select(&selector, arm_result, result, self)?;
+            assignments = self.select_assignments(env, &selector, arm_assignments, assignments)?;
+        }
+
+        for (name, value) in assignments {
+            env.assign(&name, value);
+        }
+        Ok(result)
+    }
+
+    fn eval_match_arm(
+        &mut self,
+        env: &mut Environment,
+        arm: &ExprId,
+    ) -> Result<Object, RuntimeErrorKind> {
+        env.start_if_branch();
+
+        // Unlike if branches, an arm is not necessarily a block
+        let result = match self.context.def_interner.expression(arm) {
+            HirExpression::Block(block) => self.eval_block(env, block.statements()),
+            _ => self.expression_to_object(env, arm),
+        };
+
+        env.end_if_branch();
+        result
+    }
+
+    // Assigns `condition ? consequence_value : alternative_value` to each variable which
+    // was assigned to in either branch. A branch which did not assign to the variable
+    // leaves it with its value from before the if expression.
+    fn merge_assignments(
+        &mut self,
+        env: &mut Environment,
+        condition: &Object,
+        consequence_assignments: BTreeMap<String, Object>,
+        alternative_assignments: BTreeMap<String, Object>,
+    ) -> Result<(), RuntimeErrorKind> {
+        let merged = self.select_assignments(
+            env,
+            condition,
+            consequence_assignments,
+            alternative_assignments,
+        )?;
+        for (name, value) in merged {
+            env.assign(&name, value);
+        }
+        Ok(())
+    }
+
+    // Returns `condition ? consequence_value : alternative_value` for each variable which
+    // was assigned to in either branch, without assigning it.
+    fn select_assignments(
+        &mut self,
+        env: &mut Environment,
+        condition: &Object,
+        mut consequence_assignments: BTreeMap<String, Object>,
+        mut alternative_assignments: BTreeMap<String, Object>,
+    ) -> Result<BTreeMap<String, Object>, RuntimeErrorKind> {
+        let names: BTreeSet<String> = consequence_assignments
+            .keys()
+            .chain(alternative_assignments.keys())
+            .cloned()
+            .collect();
+
+        let mut selected = BTreeMap::new();
+        for name in names {
+            let consequence = consequence_assignments
+                .remove(&name)
+                .unwrap_or_else(|| env.get(&name));
+            let alternative = alternative_assignments
+                .remove(&name)
+                .unwrap_or_else(|| env.get(&name));
+
+            let merged = conditional::select(condition, consequence, alternative, self)?;
+            selected.insert(name, merged);
+        }
+        Ok(selected)
+    }
+
+    // Combines the condition of a branch with the predicate of the branches we are currently in
+    fn and_predicate(&mut self, condition: Object) -> Result<Object, RuntimeErrorKind> {
+        match self.predicate.clone() {
+            Some(predicate) => binary_op::handle_mul_op(predicate, condition, self),
+            None => Ok(condition),
+        }
+    }
+
+    // Evaluates a branch with a witness condition, whose constraints are only enforced under
+    // the given predicate. Returns None if the branch returned from the function.
+    fn eval_predicated<F>(
+        &mut self,
+        predicate: Object,
+        eval_branch: F,
+    ) -> Result<Option<Object>, RuntimeErrorKind>
+    where
+        F: FnOnce(&mut Self) -> Result<Object, RuntimeErrorKind>,
+    {
+        // The predicate is multiplied with every constraint which is predicated,
+        // so it is stored in a witness instead of being an arithmetic expression
+        let predicate = self.into_witness(predicate);
+        let outer_predicate = self.predicate.replace(predicate);
+        let function_return = self.function_return();
+        function_return.branch_depth += 1;
+        let num_returns = function_return.num_returns;
+
+        let result = eval_branch(self);
+
+        let function_return = self.function_return();
+        function_return.branch_depth -= 1;
+        let has_returned = function_return.num_returns != num_returns;
+        self.predicate = outer_predicate;
+
+        let result = match result {
+            Ok(result) => Some(result),
+            Err(RuntimeErrorKind::Return) => None,
+            Err(err) => return Err(err),
+        };
+
+        // The constraints after the branch are only enforced on the paths which have not returned
+        if has_returned {
+            let returned = self.function_return().returned.clone();
+            let not_returned = binary_op::handle_sub_op(
+                Object::Constants(FieldElement::one()),
+                returned.expect("ice: a return statement was evaluated"),
+                self,
+            )?;
+            let predicate = self.and_predicate(not_returned)?;
+            self.predicate = Some(self.into_witness(predicate));
+        }
+        Ok(result)
+    }
+
+    // Stores an arithmetic expression in a new witness, other objects are left unchanged
+    fn into_witness(&mut self, object: Object) -> Object {
+        match object {
+            Object::Arithmetic(arith) => self.create_intermediate_variable(arith).0,
+            object => object,
+        }
+    }
+
+    fn function_return(&mut self) -> &mut FunctionReturn {
+        self.function_returns
+            .last_mut()
+            .expect("ice: statements are always evaluated in a function")
+    }
+
+    fn eval_if_branch(
+        &mut self,
+        env: &mut Environment,
+        branch: &ExprId,
+    ) -> Result<Object, RuntimeErrorKind> {
+        env.start_if_branch();
+
+        let block = self.expression_to_block(branch);
+        let result = self.eval_block(env, block.statements());
+
+        env.end_if_branch();
+        result
+    }
+
     fn expression_to_block(&mut self, expr_id: &ExprId) -> HirBlockExpression {
         match self.context.def_interner.expression(expr_id) {
             HirExpression::Block(block_expr) => block_expr,
@@ -524,34 +1177,58 @@ impl<'a> Evaluator<'a> {
         let expr = self.context.def_interner.expression(expr_id);
         match expr {
             HirExpression::Literal(HirLiteral::Integer(x)) => Ok(Object::Constants(x.into())),
+            HirExpression::Literal(HirLiteral::Bool(x)) => Ok(Object::Constants(if x {
+                FieldElement::one()
+            } else {
+                FieldElement::zero()
+            })),
             HirExpression::Literal(HirLiteral::Array(arr_lit)) => {
                 Ok(Object::Array(Array::from(self, env, arr_lit)?))
             }
+            HirExpression::Literal(HirLiteral::RepeatedArray(arr_lit)) => {
+                Ok(Object::Array(Array::repeated(self, env, arr_lit)?))
+            }
+            HirExpression::Literal(HirLiteral::Str(string)) => {
+                Ok(Object::Array(Array::from_string(&string)))
+            }
             HirExpression::Ident(x) => Ok(self.evaluate_identifier(&x, env)),
             HirExpression::Infix(infx) => {
                 let lhs = self.expression_to_object(env, &infx.lhs)?;
                 let rhs = self.expression_to_object(env, &infx.rhs)?;
+
                 self.evaluate_infix_expression(lhs, rhs, infx.operator)
             }
+            HirExpression::Predicate(infx) => {
+                let lhs = self.expression_to_object(env, &infx.lhs)?;
+                let rhs = self.expression_to_object(env, &infx.rhs)?;
+                binary_op::handle_comparison_op(lhs, rhs, infx.operator.kind, self)
+            }
             HirExpression::Cast(cast_expr) => {
                 let lhs = self.expression_to_object(env, &cast_expr.lhs)?;
-                binary_op::handle_cast_op(self,lhs, cast_expr.r#type)
+                let lhs_type = self.context.def_interner.id_type(&cast_expr.lhs);
+                binary_op::handle_cast_op(self,lhs, lhs_type, cast_expr.r#type)
             }
             HirExpression::Index(indexed_expr) => {
-                // Currently these only happen for arrays
-                let arr_name = self.context.def_interner.ident_name(&indexed_expr.collection_name);
-                let ident_span = self.context.def_interner.ident_span(&indexed_expr.collection_name);
-                let arr = env.get_array(&arr_name)?;
+                // Currently these only happen for arrays.
+                // The collection is evaluated first, so `matrix[i][j]` indexes into the row `matrix[i]`
+                let collection_span = self.context.def_interner.expr_span(&indexed_expr.collection);
+                let arr = Array::from_expression(self, env, &indexed_expr.collection)?;
                 //
                 // Evaluate the index expression
                 let index_as_obj = self.expression_to_object(env, &indexed_expr.index)?;
-                let index_as_constant = match index_as_obj.constant() {
-                    Ok(v) => v,
-                    Err(_) => panic!("Indexed expression does not evaluate to a constant")
-                };
-                //
-                let index_as_u128 = index_as_constant.to_u128();
-                arr.get(index_as_u128, ident_span)
+                match index_as_obj.constant() {
+                    Ok(index_as_constant) => {
+                        let index_as_u128 = index_as_constant.to_u128();
+                        arr.get(index_as_u128, collection_span)
+                    }
+                    // The index is only known at runtime
+                    Err(_) => arr.get_dynamic(index_as_obj, self),
+                }
+            }
+            HirExpression::Slice(slice_expr) => {
+                let span = self.context.def_interner.expr_span(expr_id);
+                let arr = Array::from_expression(self, env, &slice_expr.collection)?;
+                Ok(Object::Array(arr.slice(slice_expr.start, slice_expr.end, span)?))
             }
             HirExpression::Call(call_expr) => {
 
@@ -574,11 +1251,55 @@ impl<'a> Evaluator<'a> {
                     },
                 }
             }
+            HirExpression::MethodCall(_) => unreachable!("ice: method calls are replaced with calls to the method during type checking"),
+            HirExpression::Constructor(constructor) => {
+                // Fields are evaluated in the order they were written,
+                // then stored in the order they were declared in the struct
+                let struct_type = constructor.r#type.borrow();
+                let mut contents = vec![Object::Null; struct_type.fields.len()];
+                for (field_name, field_expr) in constructor.fields.iter() {
+                    let (index, _) = struct_type.field(field_name).expect("ice: constructor fields should have been checked in the resolver");
+                    contents[index] = self.expression_to_object(env, field_expr)?;
+                }
+                Ok(Object::Struct(Struct { contents }))
+            }
+            // Tuples are represented in the same way as structs
+            HirExpression::Tuple(elements) => {
+                let mut contents = Vec::with_capacity(elements.len());
+                for element in elements.iter() {
+                    contents.push(self.expression_to_object(env, element)?);
+                }
+                Ok(Object::Struct(Struct { contents }))
+            }
+            HirExpression::MemberAccess(access) => {
+                let lhs = self.expression_to_object(env, &access.lhs)?;
+                let structure = lhs.structure().ok_or_else(|| RuntimeErrorKind::expected_type("struct", lhs.r#type()))?;
+
+                // The type checker has already checked that the field exists
+                let index = match self.context.def_interner.id_type(&access.lhs) {
+                    Type::Struct(struct_type) => struct_type.borrow().field(&access.rhs).expect("ice: field should have been checked by the type checker").0,
+                    Type::Tuple(_) => access.rhs.0.contents.parse().expect("ice: tuple index should have been checked by the type checker"),
+                    _ => unreachable!("ice: the lhs of a member access should be a struct or tuple"),
+                };
+                Ok(structure.get(index))
+            }
             HirExpression::For(for_expr) => self.handle_for_expr(env,for_expr),
-            HirExpression::If(_) => todo!(),
-            HirExpression::Prefix(_) => todo!(),
-            HirExpression::Predicate(_) => todo!(),
+            HirExpression::While(while_expr) => self.handle_while_expr(env,while_expr),
+            HirExpression::If(if_expr) => self.handle_if_expr(env, if_expr),
+            HirExpression::Match(match_expr) => self.handle_match_expr(env, match_expr),
+            HirExpression::EnumVariant(variant) => {
+                Ok(Object::Constants(FieldElement::from(variant.index as i128)))
+            }
+            HirExpression::Prefix(prefix) => {
+                let rhs = self.expression_to_object(env, &prefix.rhs)?;
+                match prefix.operator {
+                    HirUnaryOp::Not => binary_op::handle_not_op(rhs, self),
+                    HirUnaryOp::Minus => binary_op::handle_negate_op(rhs, self),
+                }
+            }
             HirExpression::Literal(_) => todo!(),
+            // The value of a return statement without an expression
+            HirExpression::Block(block) if block.statements().is_empty() => Ok(Object::Null),
             HirExpression::Block(_) => todo!("currently block expressions not in for/if branches are not being evaluated. In the future, we should be able to unify the eval_block and all places which require block_expr here")
         }
     }
@@ -622,7 +1343,33 @@ impl<'a> Evaluator<'a> {
     ) -> Result<Object, RuntimeErrorKind> {
         let function = self.context.def_interner.function(func_id);
         let block = function.block(&self.context.def_interner);
-        self.eval_block(env, block.statements())
+        self.eval_function_body(env, block.statements())
+    }
+
+    // Evaluates the statements of a function, and returns the value returned by the function
+    fn eval_function_body(
+        &mut self,
+        env: &mut Environment,
+        block: &[StmtId],
+    ) -> Result<Object, RuntimeErrorKind> {
+        self.function_returns.push(FunctionReturn::default());
+        let outer_predicate = self.predicate.clone();
+
+        let result = self.eval_block(env, block);
+
+        // The predicate may exclude the paths which returned from this function
+        self.predicate = outer_predicate;
+        let function_return = self.function_returns.pop().unwrap();
+
+        match (result, function_return.value) {
+            (Err(RuntimeErrorKind::Return), Some(value)) => Ok(value),
+            // The function returned on some paths, otherwise the value is the last expression
+            (Ok(last_value), Some(value)) => {
+                let returned = function_return.returned.unwrap();
+                conditional::select(&returned, value, last_value, self)
+            }
+            (result, _) => result,
+        }
     }
 
     fn eval_block(
@@ -2,6 +2,7 @@ use super::GadgetCaller;
 use crate::object::{Array, Object};
 use crate::{Environment, Evaluator};
 use acvm::acir::circuit::gate::{GadgetCall, GadgetInput, Gate};
+use acvm::acir::native_types::Arithmetic;
 use acvm::acir::OPCODE;
 use noirc_frontend::hir_def::expr::HirCallExpression;
 
@@ -96,6 +97,12 @@ impl SchnorrVerifyGadget {
         for element in message.contents.into_iter() {
             let witness = match element {
                 Object::Integer(integer) => (integer.witness),
+                // The message may be a string literal, whose bytes are constants
+                Object::Constants(byte) => {
+                    let (_, witness) =
+                        evaluator.create_intermediate_variable(Arithmetic::from(&byte));
+                    witness
+                }
                 Object::Linear(lin) => {
                     if !lin.is_unit() {
                         unimplemented!(
@@ -1,10 +1,11 @@
-use super::RuntimeErrorKind;
-use crate::{binary_op::maybe_equal, object::Object};
+use super::{Integer, RuntimeErrorKind, Struct};
+use crate::{binary_op::maybe_equal, conditional, object::Object};
 use crate::{Environment, Evaluator};
-use acvm::acir::native_types::Witness;
+use acvm::acir::circuit::gate::{Directive, Gate};
+use acvm::acir::native_types::{Arithmetic, Witness};
 use noir_field::FieldElement;
 use noirc_errors::Span;
-use noirc_frontend::hir_def::expr::HirArrayLiteral;
+use noirc_frontend::hir_def::expr::{HirArrayLiteral, HirRepeatedArrayLiteral};
 use noirc_frontend::node_interner::ExprId;
 
 #[derive(Clone, Debug)]
@@ -32,6 +33,33 @@ impl Array {
             length: arr_lit.length,
         })
     }
+    /// Creates the array of bytes of a string literal.
+    /// Each byte is a constant, so no constraints are needed.
+    pub fn from_string(string: &str) -> Array {
+        let contents: Vec<_> = string
+            .bytes()
+            .map(|byte| Object::Constants(FieldElement::from(byte as i128)))
+            .collect();
+
+        Array {
+            length: contents.len() as u128,
+            contents,
+        }
+    }
+    /// Creates an array where every element is the same.
+    /// The element is only evaluated once, so its constraints are not duplicated.
+    pub fn repeated(
+        evaluator: &mut Evaluator,
+        env: &mut Environment,
+        arr_lit: HirRepeatedArrayLiteral,
+    ) -> Result<Array, RuntimeErrorKind> {
+        let element = evaluator.expression_to_object(env, &arr_lit.element)?;
+
+        Ok(Array {
+            contents: vec![element; arr_lit.length as usize],
+            length: arr_lit.length,
+        })
+    }
     pub fn get(&self, index: u128, span: Span) -> Result<Object, RuntimeErrorKind> {
         if index >= self.length {
             return Err(RuntimeErrorKind::ArrayOutOfBounds {
@@ -44,6 +72,137 @@ impl Array {
         Ok(self.contents[index as usize].clone())
     }
 
+    /// Returns the elements from `start` up to, but excluding, `end`.
+    /// The elements are only rearranged, so no constraints are needed
+    pub fn slice(&self, start: u128, end: u128, span: Span) -> Result<Array, RuntimeErrorKind> {
+        // The type checker can only check the bounds of arrays with a fixed length
+        if end > self.length {
+            return Err(RuntimeErrorKind::ArrayOutOfBounds {
+                index: end,
+                bound: self.length,
+                span,
+            });
+        };
+
+        Ok(Array {
+            contents: self.contents[start as usize..end as usize].to_vec(),
+            length: end - start,
+        })
+    }
+
+    /// Returns an array with the elements of `lhs` followed by the elements of `rhs`
+    pub fn concat(lhs: Array, rhs: Array) -> Array {
+        let mut contents = lhs.contents;
+        contents.extend(rhs.contents);
+
+        Array {
+            length: lhs.length + rhs.length,
+            contents,
+        }
+    }
+
+    /// Replaces the element at a constant index
+    pub fn set(&mut self, index: u128, value: Object, span: Span) -> Result<(), RuntimeErrorKind> {
+        if index >= self.length {
+            return Err(RuntimeErrorKind::ArrayOutOfBounds {
+                index,
+                bound: self.length,
+                span,
+            });
+        };
+
+        self.contents[index as usize] = value;
+        Ok(())
+    }
+
+    /// Replaces the element of a multi-dimensional array at constant indices,
+    /// which are given from the outermost array inwards
+    pub fn set_nested(
+        &mut self,
+        indices: &[(u128, Span)],
+        value: Object,
+    ) -> Result<(), RuntimeErrorKind> {
+        let ((index, span), inner_indices) = indices
+            .split_first()
+            .expect("ice: an array element has at least one index");
+        if inner_indices.is_empty() {
+            return self.set(*index, value, *span);
+        }
+
+        let mut row = match self.get(*index, *span)? {
+            Object::Array(row) => row,
+            element => return Err(RuntimeErrorKind::expected_type("array", element.r#type())),
+        };
+        row.set_nested(inner_indices, value)?;
+        self.set(*index, Object::Array(row), *span)
+    }
+
+    /// Returns the element at an index which is only known at runtime.
+    ///
+    /// The index is decomposed into a one-hot selector `s`, such that `s_i = 1`
+    /// if the index is `i` and `s_i = 0` otherwise. This is enforced with the constraints
+    /// `sum(s_i) = 1` and `sum(s_i * i) = index`, which also ensure that the index is in bounds
+    /// if the branch that we are in is taken.
+    /// The element is then computed as `sum(s_i * contents_i)`.
+    pub fn get_dynamic(
+        &self,
+        index: Object,
+        evaluator: &mut Evaluator,
+    ) -> Result<Object, RuntimeErrorKind> {
+        let index = match (index.integer(), index.into_arithmetic()) {
+            (Some(integer), _) => integer.witness,
+            (None, Some(arith)) => evaluator.create_intermediate_variable(arith).1,
+            (None, None) => {
+                return Err(RuntimeErrorKind::expected_type("witness", index.r#type()));
+            }
+        };
+
+        let selectors: Vec<_> = (0..self.contents.len())
+            .map(|_| evaluator.add_witness_to_cs())
+            .collect();
+        evaluator.gates.push(Gate::Directive(Directive::OneHot {
+            index,
+            selectors: selectors.clone(),
+        }));
+
+        for selector in selectors.iter() {
+            Integer::from_witness(*selector, 1).constrain(evaluator)?;
+        }
+
+        // The index may be out of bounds in a branch which is not taken,
+        // so both constraints are predicated
+        //
+        // sum(s_i) - 1 = 0
+        let selected = Arithmetic {
+            mul_terms: Vec::new(),
+            linear_combinations: selectors
+                .iter()
+                .map(|selector| (FieldElement::one(), *selector))
+                .collect(),
+            q_c: -FieldElement::one(),
+        };
+        let selected = conditional::apply_predicate(Object::Arithmetic(selected), evaluator)?;
+        evaluator.gates.push(Gate::Arithmetic(selected));
+
+        // sum(s_i * i) - index = 0
+        let mut position: Vec<_> = selectors
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, selector)| (FieldElement::from(i as i128), *selector))
+            .collect();
+        position.push((-FieldElement::one(), index));
+        let position = Arithmetic {
+            mul_terms: Vec::new(),
+            linear_combinations: position,
+            q_c: FieldElement::zero(),
+        };
+        let position = conditional::apply_predicate(Object::Arithmetic(position), evaluator)?;
+        evaluator.gates.push(Gate::Arithmetic(position));
+
+        select_element(&selectors, self.contents.clone(), evaluator)
+    }
+
     pub fn num_elements(&self) -> usize {
         self.contents.len()
     }
@@ -166,10 +325,11 @@ impl Array {
     }
 
     /// Constrains all elements in the array to be equal to zero
-    pub fn constrain_zero(&self, evaluator: &mut Evaluator) {
+    pub fn constrain_zero(&self, evaluator: &mut Evaluator) -> Result<(), RuntimeErrorKind> {
         for element in self.contents.iter() {
-            element.constrain_zero(evaluator)
+            element.constrain_zero(evaluator)?;
         }
+        Ok(())
     }
 
     pub fn from_expression(
@@ -184,3 +344,86 @@ impl Array {
         }
     }
 }
+
+// Computes `sum(s_i * elements_i)`, where exactly one of the selectors is equal to 1.
+// Arrays and structs are selected element-wise.
+fn select_element(
+    selectors: &[Witness],
+    elements: Vec<Object>,
+    evaluator: &mut Evaluator,
+) -> Result<Object, RuntimeErrorKind> {
+    match elements.first() {
+        Some(Object::Array(first)) => {
+            let length = first.length;
+            let contents = select_components(selectors, elements, evaluator, |element| {
+                element.array().map(|arr| arr.contents)
+            })?;
+            Ok(Object::Array(Array { contents, length }))
+        }
+        Some(Object::Struct(_)) => {
+            let contents = select_components(selectors, elements, evaluator, |element| {
+                element.structure().map(|structure| structure.contents)
+            })?;
+            Ok(Object::Struct(Struct { contents }))
+        }
+        Some(Object::Integer(first)) => {
+            let first = *first;
+            let result = select_scalar(selectors, elements, evaluator)?;
+            // The result is one of the elements, so it is already in range
+            let (_, witness) = evaluator.create_intermediate_variable(result);
+            let integer = Integer::from_witness(witness, first.num_bits).with_sign(first.sign);
+            Ok(Object::Integer(integer))
+        }
+        Some(_) => Ok(Object::Arithmetic(select_scalar(
+            selectors, elements, evaluator,
+        )?)),
+        None => Err(RuntimeErrorKind::UnstructuredError {
+            span: Span::default(),
+            message: format!("cannot index into an empty array"),
+        }),
+    }
+}
+
+// Transposes the components of each element, so that each component can be selected separately
+fn select_components(
+    selectors: &[Witness],
+    elements: Vec<Object>,
+    evaluator: &mut Evaluator,
+    components: impl Fn(Object) -> Option<Vec<Object>>,
+) -> Result<Vec<Object>, RuntimeErrorKind> {
+    let mut transposed: Vec<Vec<Object>> = Vec::new();
+    for element in elements {
+        let element_type = element.r#type();
+        let element_components = components(element)
+            .ok_or_else(|| RuntimeErrorKind::expected_type("homogeneous array", element_type))?;
+        if transposed.is_empty() {
+            transposed = element_components.into_iter().map(|c| vec![c]).collect();
+        } else {
+            for (column, component) in transposed.iter_mut().zip(element_components) {
+                column.push(component);
+            }
+        }
+    }
+
+    transposed
+        .into_iter()
+        .map(|column| select_element(selectors, column, evaluator))
+        .collect()
+}
+
+fn select_scalar(
+    selectors: &[Witness],
+    elements: Vec<Object>,
+    evaluator: &mut Evaluator,
+) -> Result<Arithmetic, RuntimeErrorKind> {
+    let mut result = Arithmetic::from(&FieldElement::zero());
+    for (selector, element) in selectors.iter().zip(elements) {
+        let selected =
+            crate::binary_op::handle_mul_op(Object::from_witness(*selector), element, evaluator)?;
+        let selected = selected
+            .into_arithmetic()
+            .ok_or_else(|| RuntimeErrorKind::expected_type("witness", selected.r#type()))?;
+        result = &result + &selected;
+    }
+    Ok(result)
+}
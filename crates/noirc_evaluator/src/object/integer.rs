@@ -1,6 +1,8 @@
-use crate::binary_op;
+use crate::{binary_op, conditional};
 use crate::{Gate, Object};
+use acvm::acir::circuit::gate::Directive;
 use acvm::acir::native_types::{Arithmetic, Linear, Witness};
+use noirc_frontend::Signedness;
 
 use crate::{AndGate, Evaluator, FieldElement, XorGate};
 
@@ -10,6 +12,9 @@ use super::RuntimeErrorKind;
 pub struct Integer {
     pub(crate) witness: Witness,
     pub(crate) num_bits: u32,
+    // Signed integers are stored in two's complement,
+    // so their witness is always in the range [0, 2^num_bits)
+    pub(crate) sign: Signedness,
 }
 
 // XXX: Most of the needed functionality seems to be to monitor the final num_bits and then constrain it.
@@ -17,30 +22,46 @@ pub struct Integer {
 
 impl Integer {
     pub fn from_witness(witness: Witness, num_bits: u32) -> Integer {
-        Integer { witness, num_bits }
+        Integer {
+            witness,
+            num_bits,
+            sign: Signedness::Unsigned,
+        }
     }
 
-    pub fn constrain(&self, evaluator: &mut Evaluator) -> Result<(), RuntimeErrorKind> {
-        if self.num_bits == 1 {
-            // Add a bool gate
-            let x = Linear::from_witness(self.witness);
-            let mut x_minus_one = Linear::from_witness(self.witness);
-            x_minus_one.add_scale = -noir_field::FieldElement::one();
-            let bool_constraint = &x_minus_one * &x;
+    pub fn with_sign(self, sign: Signedness) -> Integer {
+        Integer { sign, ..self }
+    }
 
-            evaluator.gates.push(Gate::Arithmetic(bool_constraint));
-        } else if self.num_bits == FieldElement::max_num_bits() {
+    pub fn is_signed(&self) -> bool {
+        self.sign == Signedness::Signed
+    }
+
+    pub fn constrain(&self, evaluator: &mut Evaluator) -> Result<(), RuntimeErrorKind> {
+        if self.num_bits == FieldElement::max_num_bits() {
             // Don't apply any constraints if the range is for the maximum number of bits
             let message = format!("All Witnesses are by default u{}. Applying this type does not apply any constraints.",FieldElement::max_num_bits());
             return Err(RuntimeErrorKind::UnstructuredError {
                 span: Default::default(),
                 message,
             });
+        }
+
+        // Inside of a branch with a witness condition, the integer only needs to be
+        // in range if the branch is taken
+        let witness = conditional::predicated_witness(self.witness, evaluator)?;
+
+        if self.num_bits == 1 {
+            // Add a bool gate
+            let x = Linear::from_witness(witness);
+            let mut x_minus_one = Linear::from_witness(witness);
+            x_minus_one.add_scale = -noir_field::FieldElement::one();
+            let bool_constraint = &x_minus_one * &x;
+
+            evaluator.gates.push(Gate::Arithmetic(bool_constraint));
         } else {
             // Note if the number of bits is odd, then barretenberg will panic
-            evaluator
-                .gates
-                .push(Gate::Range(self.witness, self.num_bits));
+            evaluator.gates.push(Gate::Range(witness, self.num_bits));
         }
         Ok(())
     }
@@ -56,6 +77,98 @@ impl Integer {
         Integer::from_witness(witness, num_bits)
     }
 
+    /// Reduces `arith` modulo 2^num_bits, where `arith` is known to fit in `max_bits` bits.
+    ///
+    /// Returns the remainder, which is constrained to `num_bits` bits, and the quotient.
+    /// This is used to implement the wraparound of signed integers.
+    pub fn truncate(
+        arith: Arithmetic,
+        num_bits: u32,
+        max_bits: u32,
+        evaluator: &mut Evaluator,
+    ) -> Result<(Integer, Integer), RuntimeErrorKind> {
+        let (_, value) = evaluator.create_intermediate_variable(arith);
+
+        let remainder = Integer::from_witness(evaluator.add_witness_to_cs(), num_bits);
+        let quotient = Integer::from_witness(evaluator.add_witness_to_cs(), max_bits - num_bits);
+        evaluator.gates.push(Gate::Directive(Directive::Truncate {
+            a: value,
+            b: remainder.witness,
+            c: quotient.witness,
+            bit_size: num_bits,
+        }));
+        remainder.constrain(evaluator)?;
+        quotient.constrain(evaluator)?;
+
+        // value = remainder + 2^num_bits * quotient
+        let quotient_scaled =
+            &Linear::from_witness(quotient.witness) * &FieldElement::pow_of_two(num_bits);
+        let sum = &Arithmetic::from(&remainder.witness) + &Arithmetic::from(quotient_scaled);
+        evaluator
+            .gates
+            .push(Gate::Arithmetic(&Arithmetic::from(&value) - &sum));
+
+        Ok((remainder, quotient))
+    }
+
+    // Reduces the result of an operation between two signed integers modulo 2^num_bits
+    fn wrap(
+        &self,
+        result: Object,
+        max_bits: u32,
+        evaluator: &mut Evaluator,
+    ) -> Result<Integer, RuntimeErrorKind> {
+        let arith = result
+            .into_arithmetic()
+            .expect("ice: the result of an integer operation should be arithmetic");
+        let (remainder, _) = Integer::truncate(arith, self.num_bits, max_bits, evaluator)?;
+        Ok(remainder.with_sign(self.sign))
+    }
+
+    /// Flips the most significant bit of the integer, by computing x + 2^(num_bits - 1) mod 2^num_bits.
+    ///
+    /// Returns the flipped integer and the original most significant bit, which is the sign bit.
+    /// Flipping the sign bit maps the signed integers in order onto the unsigned integers.
+    pub fn flip_sign_bit(
+        &self,
+        evaluator: &mut Evaluator,
+    ) -> Result<(Integer, Integer), RuntimeErrorKind> {
+        let shifted =
+            &Linear::from_witness(self.witness) + &FieldElement::pow_of_two(self.num_bits - 1);
+        Integer::truncate(shifted.into(), self.num_bits, self.num_bits + 1, evaluator)
+    }
+
+    // Constants used with signed integers are converted to their two's complement representation
+    fn normalise_constant(&self, object: Object) -> Object {
+        match object {
+            Object::Constants(constant) if self.is_signed() => {
+                Object::Constants(constant.twos_complement(self.num_bits))
+            }
+            object => object,
+        }
+    }
+
+    /// Sign extends a signed integer to `num_bits` bits
+    pub fn sign_extend(
+        &self,
+        num_bits: u32,
+        evaluator: &mut Evaluator,
+    ) -> Result<Integer, RuntimeErrorKind> {
+        assert!(num_bits > self.num_bits);
+
+        let (_, sign_bit) = self.flip_sign_bit(evaluator)?;
+
+        // If the sign bit is set, then all of the new bits must be set too
+        // result = x + sign_bit * (2^num_bits - 2^self.num_bits)
+        let extension =
+            FieldElement::pow_of_two(num_bits) - FieldElement::pow_of_two(self.num_bits);
+        let result = &Arithmetic::from(&self.witness)
+            + &Arithmetic::from(&Linear::from_witness(sign_bit.witness) * &extension);
+        let (_, witness) = evaluator.create_intermediate_variable(result);
+
+        Ok(Integer::from_witness(witness, num_bits).with_sign(self.sign))
+    }
+
     /// Constrains the integer to be equal to zero
     pub fn constrain_zero(&self, evaluator: &mut Evaluator) {
         let witness_linear = Linear::from_witness(self.witness);
@@ -95,6 +208,7 @@ impl Integer {
     ) -> Result<Integer, RuntimeErrorKind> {
         // You can only sub an integer from an integer and they must have the same number of bits
         let (witness_rhs, num_bits) = extract_witness_and_num_bits(self.num_bits, poly)?;
+        let witness_rhs = self.normalise_constant(witness_rhs);
 
         assert_eq!(
             self.num_bits, num_bits,
@@ -105,6 +219,10 @@ impl Integer {
         let res =
             binary_op::handle_add_op(Object::from_witness(self.witness), witness_rhs, evaluator)?;
 
+        if self.is_signed() {
+            return self.wrap(res, self.num_bits + 1, evaluator);
+        }
+
         Ok(Integer::from_object(res, self.num_bits, evaluator)?)
     }
     pub fn sub(
@@ -113,6 +231,7 @@ impl Integer {
         evaluator: &mut Evaluator,
     ) -> Result<Integer, RuntimeErrorKind> {
         let (witness_rhs, num_bits) = extract_witness_and_num_bits(self.num_bits, poly)?;
+        let witness_rhs = self.normalise_constant(witness_rhs);
 
         if self.num_bits != num_bits {
             let err = RuntimeErrorKind::Spanless(format!(
@@ -126,10 +245,30 @@ impl Integer {
         let res =
             binary_op::handle_sub_op(Object::from_witness(self.witness), witness_rhs, evaluator)?;
 
+        if self.is_signed() {
+            // Adding 2^num_bits ensures that the result is positive
+            let res = binary_op::handle_add_op(
+                res,
+                Object::Constants(FieldElement::pow_of_two(self.num_bits)),
+                evaluator,
+            )?;
+            return self.wrap(res, self.num_bits + 1, evaluator);
+        }
+
         // Constrain the result to be equal to an integer in range of 2^num_bits
         Ok(Integer::from_object(res, self.num_bits, evaluator)?)
     }
 
+    /// Negates a signed integer, wrapping around if the result does not fit.
+    /// ie -(-128) is -128 for an i8
+    pub fn negate(&self, evaluator: &mut Evaluator) -> Result<Integer, RuntimeErrorKind> {
+        // 2^num_bits - x is in the range (0, 2^num_bits], which is -x in two's complement
+        // once it has been truncated to num_bits
+        let negated =
+            &-&Linear::from_witness(self.witness) + &FieldElement::pow_of_two(self.num_bits);
+        self.wrap(Object::Linear(negated), self.num_bits + 1, evaluator)
+    }
+
     pub fn logic(
         &self,
         rhs: Integer,
@@ -141,19 +280,23 @@ impl Integer {
             return Err(RuntimeErrorKind::Spanless(message));
         }
 
+        // Inside of a branch with a witness condition, the operands may not be in range
+        // if the branch is not taken, which the gates do not allow
+        let a = conditional::predicated_witness(self.witness, evaluator)?;
+        let b = conditional::predicated_witness(rhs.witness, evaluator)?;
         let result = evaluator.add_witness_to_cs();
 
         if is_xor_gate {
             evaluator.gates.push(Gate::Xor(XorGate {
-                a: self.witness,
-                b: rhs.witness,
+                a,
+                b,
                 result,
                 num_bits: self.num_bits,
             }));
         } else {
             evaluator.gates.push(Gate::And(AndGate {
-                a: self.witness,
-                b: rhs.witness,
+                a,
+                b,
                 result,
                 num_bits: self.num_bits,
             }));
@@ -164,6 +307,7 @@ impl Integer {
         Ok(Integer {
             witness: result,
             num_bits: self.num_bits,
+            sign: self.sign,
         })
     }
     pub fn xor(
@@ -180,6 +324,109 @@ impl Integer {
     ) -> Result<Integer, RuntimeErrorKind> {
         self.logic(rhs, false, evaluator)
     }
+    pub fn or(&self, rhs: Integer, evaluator: &mut Evaluator) -> Result<Integer, RuntimeErrorKind> {
+        // a | b = (a ^ b) ^ (a & b)
+        let xor = self.xor(rhs, evaluator)?;
+        let and = self.and(rhs, evaluator)?;
+        xor.xor(and, evaluator)
+    }
+
+    /// Flips every bit of the integer, by computing the xor with a mask where all `num_bits` bits are set
+    pub fn not(&self, evaluator: &mut Evaluator) -> Result<Integer, RuntimeErrorKind> {
+        let mask = FieldElement::pow_of_two(self.num_bits) - FieldElement::one();
+        let mask = self.bitwise_operand(mask, evaluator)?;
+        self.xor(mask, evaluator)
+    }
+
+    /// Converts a constant, which is the other operand of a bitwise operation on this integer,
+    /// into an integer witness of the same width and sign. Negative constants are converted
+    /// to two's complement, which is how signed integers are stored
+    pub fn bitwise_operand(
+        &self,
+        constant: FieldElement,
+        evaluator: &mut Evaluator,
+    ) -> Result<Integer, RuntimeErrorKind> {
+        let constant = if self.is_signed() {
+            constant.twos_complement(self.num_bits)
+        } else {
+            constant
+        };
+        if constant.num_bits() > self.num_bits {
+            return Err(RuntimeErrorKind::UnstructuredError {
+                span: Default::default(),
+                message: format!(
+                    "the constant {} does not fit in the {} bits of the other operand",
+                    constant.to_u128(),
+                    self.num_bits
+                ),
+            });
+        }
+
+        let (_, witness) = evaluator.create_intermediate_variable(Arithmetic::from(&constant));
+        Ok(Integer::from_witness(witness, self.num_bits).with_sign(self.sign))
+    }
+
+    /// Shifts the integer to the left, discarding the bits which no longer fit into `num_bits`
+    ///
+    /// This is a multiplication by 2^shift, followed by a truncation
+    pub fn shift_left(
+        &self,
+        shift: u32,
+        evaluator: &mut Evaluator,
+    ) -> Result<Integer, RuntimeErrorKind> {
+        self.check_shift(shift)?;
+        if shift == 0 {
+            return Ok(*self);
+        }
+
+        let shifted = &Linear::from_witness(self.witness) * &FieldElement::pow_of_two(shift);
+        let (result, _) = Integer::truncate(
+            shifted.into(),
+            self.num_bits,
+            self.num_bits + shift,
+            evaluator,
+        )?;
+        Ok(result.with_sign(self.sign))
+    }
+
+    /// Shifts the integer to the right
+    ///
+    /// This is a division by 2^shift, where the quotient is the result
+    pub fn shift_right(
+        &self,
+        shift: u32,
+        evaluator: &mut Evaluator,
+    ) -> Result<Integer, RuntimeErrorKind> {
+        self.check_shift(shift)?;
+        if self.is_signed() {
+            return Err(RuntimeErrorKind::Unimplemented(
+                "right shifts are currently only supported for unsigned integers".to_owned(),
+            ));
+        }
+        if shift == 0 {
+            return Ok(*self);
+        }
+
+        let (_, quotient) = Integer::truncate(
+            Linear::from_witness(self.witness).into(),
+            shift,
+            self.num_bits,
+            evaluator,
+        )?;
+        // The quotient fits in `num_bits - shift` bits, so it also fits in `num_bits` bits
+        Ok(Integer::from_witness(quotient.witness, self.num_bits))
+    }
+
+    fn check_shift(&self, shift: u32) -> Result<(), RuntimeErrorKind> {
+        if shift >= self.num_bits {
+            let message = format!(
+                "cannot shift a {} bit integer by {} bits",
+                self.num_bits, shift
+            );
+            return Err(RuntimeErrorKind::Spanless(message));
+        }
+        Ok(())
+    }
 
     pub fn mul(
         &self,
@@ -188,6 +435,7 @@ impl Integer {
     ) -> Result<Integer, RuntimeErrorKind> {
         // You can only mul an integer with another integer and they must have the same number of bits
         let (witness_rhs, num_bits) = extract_witness_and_num_bits(self.num_bits, poly)?;
+        let witness_rhs = self.normalise_constant(witness_rhs);
 
         if self.num_bits != num_bits {
             let message = format!(
@@ -203,12 +451,89 @@ impl Integer {
         let res =
             binary_op::handle_mul_op(Object::from_witness(self.witness), witness_rhs, evaluator)?;
 
+        if self.is_signed() {
+            return self.wrap(res, self.num_bits + num_bits, evaluator);
+        }
+
         Ok(Integer::from_object(
             res,
             self.num_bits + num_bits,
             evaluator,
         )?)
     }
+
+    /// Computes the quotient and the remainder of the division of the integer by `poly`.
+    ///
+    /// Both are computed using a directive, and are then constrained such that
+    /// self = quotient * poly + remainder, with remainder < poly
+    pub fn euclidean_division(
+        &self,
+        poly: Object,
+        evaluator: &mut Evaluator,
+    ) -> Result<(Integer, Integer), RuntimeErrorKind> {
+        let (divisor, num_bits) = extract_witness_and_num_bits(self.num_bits, poly)?;
+
+        if self.num_bits != num_bits {
+            let message = format!(
+                "Both integers must have the same integer type. expected u{}, got u{}",
+                self.num_bits, num_bits
+            );
+            return Err(RuntimeErrorKind::UnstructuredError {
+                span: Default::default(),
+                message,
+            });
+        }
+        if self.is_signed() {
+            return Err(RuntimeErrorKind::Unimplemented(
+                "division is currently only supported for unsigned integers".to_owned(),
+            ));
+        }
+        if divisor.constant().map_or(false, |c| c.is_zero()) {
+            return Err(RuntimeErrorKind::UnstructuredError {
+                span: Default::default(),
+                message: "attempted to divide by zero".to_owned(),
+            });
+        }
+
+        // The directive can only be applied to witnesses
+        let divisor_witness = match divisor.witness() {
+            Some(witness) => witness,
+            None => {
+                let arith = divisor
+                    .into_arithmetic()
+                    .expect("ice: the divisor should be a witness or a constant");
+                let (_, witness) = evaluator.create_intermediate_variable(arith);
+                witness
+            }
+        };
+
+        let quotient = Integer::from_witness(evaluator.add_witness_to_cs(), self.num_bits);
+        let remainder = Integer::from_witness(evaluator.add_witness_to_cs(), self.num_bits);
+        evaluator.gates.push(Gate::Directive(Directive::Quotient {
+            a: self.witness,
+            b: divisor_witness,
+            q: quotient.witness,
+            r: remainder.witness,
+        }));
+
+        quotient.constrain(evaluator)?;
+        remainder.constrain(evaluator)?;
+
+        // remainder < divisor
+        let upper_bound = match divisor {
+            Object::Constants(_) => divisor,
+            _ => Object::Integer(Integer::from_witness(divisor_witness, num_bits)),
+        };
+        binary_op::handle_less_than_op(Object::Integer(remainder), upper_bound, evaluator)?;
+
+        // self = quotient * divisor + remainder
+        let product =
+            &Linear::from_witness(quotient.witness) * &Linear::from_witness(divisor_witness);
+        let sum = &product + &Linear::from_witness(remainder.witness);
+        evaluator.gates.push(Gate::Arithmetic(&sum - &self.witness));
+
+        Ok((quotient, remainder))
+    }
 }
 
 fn extract_witness_and_num_bits(
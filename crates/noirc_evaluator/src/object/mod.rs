@@ -1,12 +1,15 @@
 mod array;
 mod integer;
+mod structure;
 
 pub use array::Array;
 pub use integer::Integer;
+pub use structure::Struct;
 
 use acvm::acir::circuit::gate::Gate;
 use acvm::acir::native_types::{Arithmetic, Linear, Witness};
 use noir_field::FieldElement;
+use noirc_errors::Span;
 
 use crate::Evaluator;
 
@@ -17,6 +20,7 @@ pub enum Object {
     Null,
     Integer(Integer),
     Array(Array),
+    Struct(Struct),
     Arithmetic(Arithmetic),
     Constants(FieldElement),
     Linear(Linear), // These will be selector * witness(var_name) + selector // Note that this is not a gate Eg `5x+6` does not apply a gate
@@ -27,22 +31,32 @@ impl Object {
         match self {
             Object::Integer(_) | Object::Arithmetic(_) | Object::Linear(_) => "witness",
             Object::Array(_) => "collection",
+            Object::Struct(_) => "struct",
             Object::Constants(_) => "constant",
             Object::Null => "()",
         }
     }
 
-    pub fn constrain_zero(&self, evaluator: &mut Evaluator) {
+    pub fn constrain_zero(&self, evaluator: &mut Evaluator) -> Result<(), RuntimeErrorKind> {
         match self {
             Object::Null => unreachable!(),
-            Object::Constants(_) => unreachable!("cannot constrain a constant to be zero"),
+            // Arrays and structs may contain constants, such as the bytes of a string literal.
+            // A constant which is not zero can never satisfy the constraint.
+            Object::Constants(constant) if constant.is_zero() => {}
+            Object::Constants(_) => {
+                return Err(RuntimeErrorKind::UnsatisfiableConstraint {
+                    span: Span::default(),
+                })
+            }
             Object::Integer(integer) => integer.constrain_zero(evaluator),
-            Object::Array(arr) => arr.constrain_zero(evaluator),
+            Object::Array(arr) => arr.constrain_zero(evaluator)?,
+            Object::Struct(structure) => structure.constrain_zero(evaluator)?,
             Object::Arithmetic(arith) => evaluator.gates.push(Gate::Arithmetic(arith.clone())),
             Object::Linear(linear) => evaluator
                 .gates
                 .push(Gate::Arithmetic(linear.clone().into())),
         }
+        Ok(())
     }
     pub fn negate(self) -> Self {
         match self {
@@ -70,6 +84,17 @@ impl Object {
                     length: arr.length,
                 })
             }
+            Object::Struct(structure) => {
+                let negated_contents: Vec<_> = structure
+                    .contents
+                    .into_iter()
+                    .map(|field| field.negate())
+                    .collect();
+
+                Object::Struct(Struct {
+                    contents: negated_contents,
+                })
+            }
             Object::Arithmetic(arith) => Object::Arithmetic(-&arith),
             Object::Constants(constant) => Object::Constants(-constant),
             Object::Linear(linear) => Object::Linear(-&linear),
@@ -81,6 +106,7 @@ impl Object {
             Object::Null => None,
             Object::Integer(integer) => Some(Linear::from_witness(integer.witness).into()),
             Object::Array(_) => None,
+            Object::Struct(_) => None,
             Object::Arithmetic(arith) => Some(arith.clone()),
             Object::Constants(constant) => Some(constant.into()),
             Object::Linear(linear) => Some(linear.into()),
@@ -116,6 +142,7 @@ impl Object {
             Object::Linear(x) => Some(x.into()),
             Object::Integer(x) => Some((&x.witness).into()),
             Object::Array(_) => None,
+            Object::Struct(_) => None,
             Object::Constants(_) => None,
             Object::Null => None,
         }
@@ -127,6 +154,7 @@ impl Object {
             Object::Linear(x) => x.can_defer_constraint(),
             Object::Integer(x) => x.witness.can_defer_constraint(),
             Object::Array(_) => false,
+            Object::Struct(_) => false,
             Object::Constants(_) => false,
             Object::Null => false,
         }
@@ -164,6 +192,12 @@ impl Object {
             _ => None,
         }
     }
+    pub fn structure(&self) -> Option<Struct> {
+        match self {
+            Object::Struct(structure) => Some(structure.clone()),
+            _ => None,
+        }
+    }
     // Returns true if the linear Object is a regular witness that has not been scaled
     pub fn is_unit_witness(&self) -> bool {
         match self {
@@ -200,6 +234,14 @@ impl Object {
                     length: arr.length,
                 })
             }
+            Object::Struct(structure) => {
+                let mut result = Vec::with_capacity(structure.contents.len());
+                for field in structure.contents.iter() {
+                    result.push(field.mul_constant(constant)?);
+                }
+
+                Object::Struct(Struct { contents: result })
+            }
             Object::Linear(lin) => Object::Linear(lin * &constant),
             Object::Integer(integer) => {
                 let result = &Linear::from_witness(integer.witness) * &constant;
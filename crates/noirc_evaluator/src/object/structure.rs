@@ -0,0 +1,81 @@
+use super::RuntimeErrorKind;
+use crate::{object::Array, object::Object, Evaluator};
+
+/// A struct is a collection of objects, one for each field.
+///
+/// The fields are stored in the order that they were declared in the
+/// struct definition. The type checker is used to map a field name to it's index.
+#[derive(Clone, Debug)]
+pub struct Struct {
+    pub contents: Vec<Object>,
+}
+
+impl Struct {
+    pub fn get(&self, index: usize) -> Object {
+        self.contents[index].clone()
+    }
+
+    /// Given two structs A, B
+    /// This method creates a new struct C
+    /// such that C.x = A.x - B.x for all fields x.
+    pub fn sub(
+        lhs: Struct,
+        rhs: Struct,
+        evaluator: &mut Evaluator,
+    ) -> Result<Struct, RuntimeErrorKind> {
+        Struct::check_num_fields(&lhs, &rhs)?;
+
+        let mut contents = Vec::with_capacity(lhs.contents.len());
+        for (lhs_field, rhs_field) in lhs.contents.into_iter().zip(rhs.contents.into_iter()) {
+            let out_field = crate::binary_op::handle_sub_op(lhs_field, rhs_field, evaluator)?;
+            contents.push(out_field);
+        }
+
+        Ok(Struct { contents })
+    }
+
+    /// Given two structs A, B
+    /// This method checks that A.x != B.x for some field x.
+    pub fn not_equal(
+        lhs: Struct,
+        rhs: Struct,
+        evaluator: &mut Evaluator,
+    ) -> Result<(), RuntimeErrorKind> {
+        Struct::check_num_fields(&lhs, &rhs)?;
+
+        // This is the same check as for arrays, where each field is an element
+        Array::not_equal(lhs.into_array(), rhs.into_array(), evaluator)
+    }
+
+    /// Constrains all fields in the struct to be equal to zero
+    pub fn constrain_zero(&self, evaluator: &mut Evaluator) -> Result<(), RuntimeErrorKind> {
+        for field in self.contents.iter() {
+            field.constrain_zero(evaluator)?;
+        }
+        Ok(())
+    }
+
+    fn into_array(self) -> Array {
+        let length = self.contents.len() as u128;
+        Array {
+            contents: self.contents,
+            length,
+        }
+    }
+
+    fn check_num_fields(lhs: &Struct, rhs: &Struct) -> Result<(), RuntimeErrorKind> {
+        // The type checker ensures that both structs are of the same type
+        // so this is only a sanity check.
+        if lhs.contents.len() != rhs.contents.len() {
+            return Err(RuntimeErrorKind::UnstructuredError {
+                span: Default::default(),
+                message: format!(
+                    "structs must contain the same number of fields. lhs : {} , rhs : {}",
+                    lhs.contents.len(),
+                    rhs.contents.len()
+                ),
+            });
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::token::{Attribute, Token};
-use crate::{Ident, Path, Statement, Type};
+use crate::{Ident, Path, Statement, Type, Visibility};
 use noir_field::FieldElement;
 use noirc_errors::{Span, Spanned};
 
@@ -10,12 +10,19 @@ pub enum ExpressionKind {
     Block(BlockExpression),
     Prefix(Box<PrefixExpression>),
     Index(Box<IndexExpression>),
+    Slice(Box<SliceExpression>),
+    Constructor(Box<ConstructorExpression>),
+    MemberAccess(Box<MemberAccessExpression>),
+    MethodCall(Box<MethodCallExpression>),
     Call(Box<CallExpression>),
     Cast(Box<CastExpression>),
     Infix(Box<InfixExpression>),
     Predicate(Box<InfixExpression>),
     For(Box<ForExpression>),
+    While(Box<WhileExpression>),
     If(Box<IfExpression>),
+    Match(Box<MatchExpression>),
+    Tuple(Vec<Expression>),
     Path(Path),
 }
 
@@ -105,6 +112,12 @@ pub struct ForExpression {
     pub block: BlockExpression,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct WhileExpression {
+    pub condition: Expression,
+    pub block: BlockExpression,
+}
+
 pub type BinaryOp = Spanned<BinaryOpKind>;
 
 #[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Debug, Copy, Clone)]
@@ -113,6 +126,7 @@ pub enum BinaryOpKind {
     Subtract,
     Multiply,
     Divide,
+    Modulo,
     Equal,
     NotEqual,
     Less,
@@ -122,6 +136,10 @@ pub enum BinaryOpKind {
     And,
     Or,
     Xor,
+    ShiftLeft,
+    ShiftRight,
+    // Concatenates two arrays, this is the only binary operator which is defined on arrays
+    Concat,
     // Assign is the only binary operator which cannot be used in a constrain statement
     Assign,
 }
@@ -147,6 +165,7 @@ impl BinaryOpKind {
             BinaryOpKind::Subtract => "-",
             BinaryOpKind::Multiply => "*",
             BinaryOpKind::Divide => "/",
+            BinaryOpKind::Modulo => "%",
             BinaryOpKind::Equal => "==",
             BinaryOpKind::NotEqual => "!=",
             BinaryOpKind::Less => "<",
@@ -156,6 +175,9 @@ impl BinaryOpKind {
             BinaryOpKind::And => "&",
             BinaryOpKind::Or => "|",
             BinaryOpKind::Xor => "^",
+            BinaryOpKind::ShiftLeft => "<<",
+            BinaryOpKind::ShiftRight => ">>",
+            BinaryOpKind::Concat => "++",
             BinaryOpKind::Assign => "=",
         }
     }
@@ -171,12 +193,16 @@ impl From<&Token> for Option<BinaryOpKind> {
             Token::Minus => BinaryOpKind::Subtract,
             Token::Star => BinaryOpKind::Multiply,
             Token::Slash => BinaryOpKind::Divide,
+            Token::Percent => BinaryOpKind::Modulo,
             Token::Equal => BinaryOpKind::Equal,
             Token::NotEqual => BinaryOpKind::NotEqual,
             Token::Less => BinaryOpKind::Less,
             Token::LessEqual => BinaryOpKind::LessEqual,
             Token::Greater => BinaryOpKind::Greater,
             Token::GreaterEqual => BinaryOpKind::GreaterEqual,
+            Token::ShiftLeft => BinaryOpKind::ShiftLeft,
+            Token::ShiftRight => BinaryOpKind::ShiftRight,
+            Token::PlusPlus => BinaryOpKind::Concat,
             Token::Assign => BinaryOpKind::Assign,
             _ => return None,
         };
@@ -204,6 +230,7 @@ impl UnaryOp {
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Literal {
     Array(ArrayLiteral),
+    RepeatedArray(RepeatedArrayLiteral),
     Bool(bool),
     Integer(FieldElement),
     Str(String),
@@ -236,10 +263,40 @@ pub struct IfExpression {
     pub alternative: Option<BlockExpression>,
 }
 
+/// A match expression on the value of an enum.
+///
+/// The arms are checked in order, a wildcard arm matches
+/// every variant which was not matched by a previous arm.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MatchExpression {
+    pub expression: Expression,
+    pub arms: Vec<(MatchPattern, Expression)>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MatchPattern {
+    // The path to an enum variant, for example `Op::Deposit`
+    Variant(Path),
+    // `_`
+    Wildcard(Span),
+}
+
+impl MatchPattern {
+    pub fn span(&self) -> Span {
+        match self {
+            MatchPattern::Variant(path) => path.span(),
+            MatchPattern::Wildcard(span) => *span,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct FunctionDefinition {
     pub name: Ident,
+    pub visibility: Visibility,
     pub attribute: Option<Attribute>, // XXX: Currently we only have one attribute defined. If more attributes are needed per function, we can make this a vector and make attribute definition more expressive
+    pub generics: Vec<Ident>,
+    pub trait_bounds: Vec<(Ident, Path)>, // The traits that a type generic must implement, such as `T` in `<T: Hasher>`
     pub parameters: Vec<(Ident, Type)>,
     pub body: BlockExpression,
     pub span: Span,
@@ -248,11 +305,19 @@ pub struct FunctionDefinition {
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ArrayLiteral {
-    pub length: u128, // XXX: Maybe allow field element, so that the user can define the length using a constant
+    pub length: u128,
     pub r#type: Type,
     pub contents: Vec<Expression>,
 }
 
+// An array literal of the form `[element; length]`
+// The length must be a constant expression, which is evaluated during name resolution
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RepeatedArrayLiteral {
+    pub element: Box<Expression>,
+    pub length: Box<Expression>,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct CallExpression {
     pub func_name: Path,
@@ -260,10 +325,40 @@ pub struct CallExpression {
 }
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct IndexExpression {
-    pub collection_name: Ident, // XXX: For now, this will be the name of the array, as we do not support other collections
+    pub collection: Expression, // XXX: For now, this must evaluate to an array, as we do not support other collections
     pub index: Expression, // XXX: We accept two types of indices, either a normal integer or a constant
 }
 
+// A slice of the form `collection[start..end]`, which excludes the element at `end`
+// The bounds must be constant expressions, which are evaluated during name resolution
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SliceExpression {
+    pub collection: Expression,
+    pub start: Expression,
+    pub end: Expression,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ConstructorExpression {
+    pub type_name: Path,
+    pub fields: Vec<(Ident, Expression)>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MemberAccessExpression {
+    pub lhs: Expression,
+    pub rhs: Ident,
+}
+
+// A call of the form `object.method_name(arguments)`
+// The method is looked up using the type of the object
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MethodCallExpression {
+    pub object: Expression,
+    pub method_name: Ident,
+    pub arguments: Vec<Expression>,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct BlockExpression(pub Vec<Statement>);
 
@@ -52,6 +52,9 @@ impl NoirFunction {
     pub fn name_ident(&self) -> &Ident {
         &self.def.name
     }
+    pub fn generics(&self) -> &Vec<Ident> {
+        &self.def.generics
+    }
     pub fn parameters(&self) -> &Vec<(Ident, Type)> {
         &self.def.parameters
     }
@@ -3,16 +3,26 @@
 mod expression;
 mod function;
 mod statement;
+mod structure;
+
+use std::{cell::RefCell, rc::Rc};
 
 pub use expression::*;
 pub use function::*;
 use noirc_abi::{AbiFEType, AbiType};
 pub use statement::*;
+pub use structure::*;
+
+use crate::hir_def::types::{EnumType, StructType};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum ArraySize {
     Variable,
     Fixed(u128),
+    // The length of the array is a generic parameter of the function, which is inferred at each call site
+    Generic(Ident),
+    // A constant expression which has not been evaluated yet. The resolver will replace it with a Fixed size
+    Unevaluated(Box<Expression>),
 }
 
 impl ArraySize {
@@ -23,11 +33,15 @@ impl ArraySize {
         }
     }
     fn is_variable(&self) -> bool {
-        !self.is_fixed()
+        match self {
+            ArraySize::Variable => true,
+            _ => false,
+        }
     }
 
     fn is_a_super_type_of(&self, argument: &ArraySize) -> bool {
-        (self.is_variable() && argument.is_fixed()) || (self == argument)
+        // A generic length is only known at the call site, so it can be passed to a variable sized parameter
+        (self.is_variable() && !argument.is_variable()) || (self == argument)
     }
 }
 
@@ -36,6 +50,8 @@ impl std::fmt::Display for ArraySize {
         match self {
             ArraySize::Variable => write!(f, "[]"),
             ArraySize::Fixed(size) => write!(f, "[{}]", size),
+            ArraySize::Generic(name) => write!(f, "[{}]", name.0.contents),
+            ArraySize::Unevaluated(_) => write!(f, "[_]"),
         }
     }
 }
@@ -93,7 +109,12 @@ pub enum Type {
     Array(FieldElementType, ArraySize, Box<Type>), // [4]Witness = Array(4, Witness)
     Integer(FieldElementType, Signedness, u32),    // u32 = Integer(unsigned, 32)
     Bool,
-    Error,       // XXX: Currently have not implemented structs, so this type is a stub
+    Struct(Rc<RefCell<StructType>>),
+    Enum(Rc<EnumType>),
+    Tuple(Vec<Type>),
+    Unresolved(Path), // A named type which has not been resolved yet. The resolver will replace it with a Struct
+    Generic(Ident), // A type parameter of a function. It is replaced with a concrete type when the function is monomorphised
+    Error, // This is used for types which could not be resolved, so that we can continue reporting errors
     Unspecified, // This is for when the user declares a variable without specifying it's type
     Unknown, // This is mainly used for array literals, where the parser cannot figure out the type for the literal
     Unit,
@@ -123,6 +144,14 @@ impl std::fmt::Display for Type {
                 Signedness::Unsigned => write!(f, "{} u{}", fe_type, num_bits),
             },
             Type::Bool => write!(f, "bool"),
+            Type::Struct(def) => write!(f, "{}", def.borrow().name.0.contents),
+            Type::Enum(def) => write!(f, "{}", def.name.0.contents),
+            Type::Tuple(elements) => {
+                let elements: Vec<_> = elements.iter().map(ToString::to_string).collect();
+                write!(f, "({})", elements.join(", "))
+            }
+            Type::Unresolved(path) => write!(f, "{}", path.as_string()),
+            Type::Generic(name) => write!(f, "{}", name.0.contents),
             Type::Error => write!(f, "Error"),
             Type::Unspecified => write!(f, "unspecified"),
             Type::Unknown => write!(f, "unknown"),
@@ -137,6 +166,8 @@ impl Type {
         match self {
             Type::FieldElement(FieldElementType::Private) => true,
             Type::Integer(field_type, _, _) => field_type == &FieldElementType::Private,
+            // Comparisons return a boolean witness
+            Type::Bool => true,
             _ => false,
         }
     }
@@ -161,6 +192,13 @@ impl Type {
 
                 return is_super_type && arity_check;
             }
+            (Type::Tuple(param_elements), Type::Tuple(arg_elements)) => {
+                return param_elements.len() == arg_elements.len()
+                    && param_elements
+                        .iter()
+                        .zip(arg_elements)
+                        .all(|(param, arg)| param.is_super_type_of(arg));
+            }
             _ => {}
         }
 
@@ -171,6 +209,24 @@ impl Type {
         self == argument
     }
 
+    /// Returns the type of a value of this type, once it has been placed in a witness.
+    /// Constants become private, everything else is left unchanged.
+    pub fn as_witness(self) -> Type {
+        match self {
+            Type::FieldElement(FieldElementType::Constant) => Type::WITNESS,
+            Type::Integer(FieldElementType::Constant, sign, num_bits) => {
+                Type::Integer(FieldElementType::Private, sign, num_bits)
+            }
+            Type::Array(fe_type, size, typ) => {
+                Type::Array(fe_type, size, Box::new(typ.as_witness()))
+            }
+            Type::Tuple(elements) => {
+                Type::Tuple(elements.into_iter().map(Type::as_witness).collect())
+            }
+            typ => typ,
+        }
+    }
+
     pub fn is_field_element(&self) -> bool {
         match self {
             Type::FieldElement(_) | Type::Bool | Type::Integer(_, _, _) => true,
@@ -182,11 +238,23 @@ impl Type {
     /// Arrays and Structs will be the only data structures to return more than one
 
     pub fn num_elements(&self) -> usize {
-        let arr_size = match self {
-            Type::Array(_, size, _) => size,
+        let (arr_size, element_type) = match self {
+            Type::Array(_, size, typ) => (size, typ),
+            Type::Struct(def) => {
+                return def
+                    .borrow()
+                    .fields
+                    .iter()
+                    .map(|(_, typ)| typ.num_elements())
+                    .sum()
+            }
+            Type::Tuple(elements) => return elements.iter().map(Type::num_elements).sum(),
             Type::FieldElement(_)
             | Type::Integer(_, _, _)
             | Type::Bool
+            | Type::Enum(_)
+            | Type::Unresolved(_)
+            | Type::Generic(_)
             | Type::Error
             | Type::Unspecified
             | Type::Unknown
@@ -194,8 +262,9 @@ impl Type {
         };
 
         match arr_size {
-            ArraySize::Variable => unreachable!("ice : this method is only ever called when we want to compare the prover inputs with the abi in main. The ABI should not have variable input. The program should be compiled before calling this"),
-            ArraySize::Fixed(fixed_size) => *fixed_size as usize
+            ArraySize::Variable | ArraySize::Generic(_) | ArraySize::Unevaluated(_) => unreachable!("ice : this method is only ever called when we want to compare the prover inputs with the abi in main. The ABI should not have variable input. The program should be compiled before calling this"),
+            // The elements of nested arrays are counted individually
+            ArraySize::Fixed(fixed_size) => *fixed_size as usize * element_type.num_elements()
         }
     }
 
@@ -211,7 +280,7 @@ impl Type {
             None => return false,
             Some(arr) => arr,
         };
-        !sized.is_fixed()
+        sized.is_variable()
     }
 
     fn array(&self) -> Option<(&ArraySize, &Type)> {
@@ -221,14 +290,56 @@ impl Type {
         }
     }
 
+    pub fn is_struct(&self) -> bool {
+        match self {
+            Type::Struct(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_enum(&self) -> bool {
+        match self {
+            Type::Enum(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_tuple(&self) -> bool {
+        match self {
+            Type::Tuple(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_generic(&self) -> bool {
+        match self {
+            Type::Generic(_) => true,
+            _ => false,
+        }
+    }
+
     // Returns true if the Type can be used in a Let statement
     pub fn can_be_used_in_let(&self) -> bool {
-        self.is_fixed_sized_array() || self.is_variable_sized_array()
+        self.array().is_some()
+            || self.is_struct()
+            || self.is_tuple()
+            || self.is_enum()
+            || self.is_base_type()
+            || self == &Type::Bool
+            // The type that a generic is bound to is checked in each instance
+            || self.is_generic()
     }
     // Returns true if the Type can be used in a Constrain statement
     pub fn can_be_used_in_constrain(&self) -> bool {
         match self {
-            Type::FieldElement(_) | Type::Integer(_, _, _) | Type::Array(_, _, _) => true,
+            Type::FieldElement(_)
+            | Type::Integer(_, _, _)
+            | Type::Bool
+            | Type::Array(_, _, _)
+            | Type::Struct(_)
+            | Type::Tuple(_) => true,
+            // The type that a generic is bound to is checked in each instance
+            Type::Generic(_) => true,
             _ => false,
         }
     }
@@ -285,6 +396,12 @@ impl Type {
                 crate::ArraySize::Variable => {
                     panic!("cannot have variable sized array in entry point")
                 }
+                crate::ArraySize::Generic(_) => {
+                    unreachable!("ice: the resolver ensures that main is not generic")
+                }
+                crate::ArraySize::Unevaluated(_) => {
+                    unreachable!("ice : array lengths are evaluated during name resolution")
+                }
                 crate::ArraySize::Fixed(length) => AbiType::Array {
                     visibility: fet_to_abi(fe_type),
                     length: *length,
@@ -303,7 +420,32 @@ impl Type {
                     visibility: fet_to_abi(fe_type),
                 }
             }
-            Type::Bool => panic!("currently, cannot have a bool in the entry point function"),
+            Type::Bool => AbiType::Boolean,
+            Type::Struct(def) => {
+                // Fields are laid out in the order that they were declared.
+                // The witness indices for a struct parameter follow this order.
+                let fields = def
+                    .borrow()
+                    .fields
+                    .iter()
+                    .map(|(name, typ)| (name.0.contents.clone(), typ.as_abi_type()))
+                    .collect();
+                AbiType::Struct { fields }
+            }
+            // The discriminant of an enum is range constrained to the number of variants
+            Type::Enum(def) => AbiType::Enum {
+                variants: def
+                    .variants
+                    .iter()
+                    .map(|variant| variant.0.contents.clone())
+                    .collect(),
+            },
+            // The elements of a tuple are flattened in order
+            Type::Tuple(elements) => AbiType::Tuple {
+                fields: elements.iter().map(Type::as_abi_type).collect(),
+            },
+            Type::Unresolved(_) => unreachable!(),
+            Type::Generic(_) => unreachable!("ice: the resolver ensures that main is not generic"),
             Type::Error => unreachable!(),
             Type::Unspecified => unreachable!(),
             Type::Unknown => unreachable!(),
@@ -323,6 +465,13 @@ impl Type {
             }
         }
     }
+
+    /// Returns the type of a string of the given length.
+    /// Strings are arrays of bytes, so `str<N>` is the same type as `[N]u8`.
+    pub fn string(field_type: FieldElementType, length: ArraySize) -> Type {
+        let byte = Type::Integer(FieldElementType::Private, Signedness::Unsigned, 8);
+        Type::Array(field_type, length, Box::new(byte))
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -1,5 +1,5 @@
 use crate::lexer::token::SpannedToken;
-use crate::{Expression, ExpressionKind, InfixExpression, Type};
+use crate::{Expression, ExpressionKind, InfixExpression, Type, Visibility};
 use noirc_errors::{Span, Spanned};
 
 #[derive(PartialOrd, Eq, Ord, Debug, Clone)]
@@ -62,16 +62,24 @@ pub enum Statement {
     Const(ConstStatement),
     Constrain(ConstrainStatement),
     Private(PrivateStatement),
+    Assign(AssignStatement),
     Expression(Expression),
     // This is an expression with a trailing semi-colon
     // terminology Taken from rustc
     Semi(Expression),
+    // Returns the value of the expression from the function
+    Return(Expression),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ImportStatement {
     pub path: Path,
     pub alias: Option<Ident>,
+    // A glob import such as `use std::hash::*;` imports every item of the module
+    // that the path refers to. Glob imports cannot have an alias
+    pub is_glob: bool,
+    // Imports declared with `pub` re-export the items that they import
+    pub visibility: Visibility,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
@@ -144,9 +152,28 @@ impl Path {
 #[derive(Debug, PartialEq, Eq, Clone)]
 // This will be used for non primitive data types like Arrays and Structs
 pub struct LetStatement {
-    pub identifier: Ident,
+    pub pattern: Pattern,
     pub r#type: Type,
     pub expression: Expression,
+    // Only mutable variables can be assigned to after they are declared
+    pub mutable: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+/// The names that the value in a let statement is bound to
+pub enum Pattern {
+    Identifier(Ident),
+    // A tuple is destructured into its elements, eg `let (x, y) = (1, 2);`
+    Tuple(Vec<Pattern>, Span),
+}
+
+impl Pattern {
+    pub fn span(&self) -> Span {
+        match self {
+            Pattern::Identifier(ident) => ident.0.span(),
+            Pattern::Tuple(_, span) => *span,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -165,3 +192,20 @@ pub struct PrivateStatement {
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ConstrainStatement(pub InfixExpression);
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AssignStatement {
+    pub lvalue: LValue,
+    pub expression: Expression,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+/// The place which is being assigned to in an assignment statement
+pub enum LValue {
+    Ident(Ident),
+    // The array may itself be an element of an array, such as `matrix[i][j]`
+    Index {
+        array: Box<LValue>,
+        index: Expression,
+    },
+}
@@ -0,0 +1,79 @@
+use crate::{Ident, NoirFunction, Path, Type};
+use noirc_errors::Span;
+
+/// The visibility of an item declared at the module level.
+///
+/// Items are private unless they are declared with `pub`. A private item
+/// can only be used from the module it is declared in and from the
+/// descendants of that module.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Visibility {
+    Public,
+    Private,
+}
+
+/// A struct declaration as it appears in the source.
+///
+/// The field types are unresolved at this point, they may
+/// reference other structs through a path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoirStruct {
+    pub name: Ident,
+    pub visibility: Visibility,
+    pub fields: Vec<(Ident, Type)>,
+    pub span: Span,
+}
+
+impl NoirStruct {
+    pub fn new(
+        name: Ident,
+        visibility: Visibility,
+        fields: Vec<(Ident, Type)>,
+        span: Span,
+    ) -> NoirStruct {
+        NoirStruct {
+            name,
+            visibility,
+            fields,
+            span,
+        }
+    }
+}
+
+/// An enum declaration as it appears in the source.
+///
+/// Variants do not have any fields, each variant is represented
+/// by its position in the declaration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoirEnum {
+    pub name: Ident,
+    pub visibility: Visibility,
+    pub variants: Vec<Ident>,
+    pub span: Span,
+}
+
+/// An impl block as it appears in the source.
+///
+/// The methods are declared on the type that `type_path` refers to,
+/// which is resolved once all of the structs have been collected.
+/// An impl block of the form `impl Trait for Type` also declares
+/// that the type implements the trait that `trait_path` refers to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoirImpl {
+    pub trait_path: Option<Path>,
+    pub type_path: Path,
+    pub methods: Vec<NoirFunction>,
+    pub span: Span,
+}
+
+/// A trait declaration as it appears in the source.
+///
+/// A trait only declares the signatures of its methods, which have an empty body.
+/// `Self` may be used in the signatures to refer to the type implementing the trait.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoirTrait {
+    pub name: Ident,
+    pub visibility: Visibility,
+    pub methods: Vec<NoirFunction>,
+    pub span: Span,
+}
@@ -1,19 +1,22 @@
 use super::dc_mod::ModCollector;
 use super::errors::DefCollectorErrorKind;
-use crate::graph::CrateId;
+use crate::graph::{CrateId, CrateType};
 use crate::hir::def_map::{CrateDefMap, LocalModuleId, ModuleId};
+use crate::hir::monomorphisation::{monomorphise, GenericFunction};
+use crate::hir::resolution::errors::ResolverError;
 use crate::hir::resolution::resolver::Resolver;
 use crate::hir::resolution::{
-    import::{resolve_imports, ImportDirective},
+    import::{resolve_imports, visible_modules, ImportDirective, ImportError, PathResolutionError},
     path_resolver::FunctionPathResolver,
 };
 use crate::hir::Context;
-use crate::node_interner::{FuncId, NodeInterner};
-use crate::{NoirFunction, ParsedModule};
+use crate::node_interner::{FuncId, GlobalId, NodeInterner, StructId, TraitId};
+use crate::{ConstStatement, Ident, NoirFunction, NoirStruct, NoirTrait, ParsedModule, Path, Type};
 use fm::FileId;
 use noirc_errors::CollectedErrors;
 use noirc_errors::DiagnosableError;
-use std::collections::HashMap;
+use noirc_errors::{Span, Spanned};
+use std::collections::{HashMap, HashSet};
 
 /// Stores all of the unresolved functions in a particular file/mod
 pub struct UnresolvedFunctions {
@@ -27,11 +30,59 @@ impl UnresolvedFunctions {
     }
 }
 
+/// A struct whose fields have not been resolved yet
+pub struct UnresolvedStruct {
+    pub file_id: FileId,
+    pub module_id: LocalModuleId,
+    pub struct_id: StructId,
+    pub struct_def: NoirStruct,
+}
+
+/// A trait whose method signatures have not been resolved yet
+pub struct UnresolvedTrait {
+    pub file_id: FileId,
+    pub module_id: LocalModuleId,
+    pub trait_id: TraitId,
+    pub trait_def: NoirTrait,
+}
+
+/// A global constant whose value has not been evaluated yet
+pub struct UnresolvedGlobal {
+    pub file_id: FileId,
+    pub module_id: LocalModuleId,
+    pub global_id: GlobalId,
+    pub stmt: ConstStatement,
+}
+
+/// The methods of an impl block, which are attached to
+/// their type once the type has been resolved
+pub struct UnresolvedImpl {
+    pub file_id: FileId,
+    pub module_id: LocalModuleId,
+    pub trait_path: Option<Path>,
+    pub type_path: Path,
+    pub methods: Vec<(Ident, FuncId)>,
+}
+
+/// An impl block which implements a trait. Its methods are checked
+/// against the trait once the signatures of the methods have been resolved
+struct TraitImpl {
+    file_id: FileId,
+    struct_id: StructId,
+    trait_id: TraitId,
+    span: Span,
+    methods: Vec<(Ident, FuncId)>,
+}
+
 /// Given a Crate root, collect all definitions in that crate
 pub struct DefCollector {
     pub(crate) def_map: CrateDefMap,
     pub(crate) collected_imports: Vec<ImportDirective>,
     pub(crate) collected_functions: Vec<UnresolvedFunctions>,
+    pub(crate) collected_types: Vec<UnresolvedStruct>,
+    pub(crate) collected_traits: Vec<UnresolvedTrait>,
+    pub(crate) collected_globals: Vec<UnresolvedGlobal>,
+    pub(crate) collected_impls: Vec<UnresolvedImpl>,
 }
 
 impl DefCollector {
@@ -75,6 +126,10 @@ impl DefCollector {
             def_map,
             collected_imports: Vec::new(),
             collected_functions: Vec::new(),
+            collected_types: Vec::new(),
+            collected_traits: Vec::new(),
+            collected_globals: Vec::new(),
+            collected_impls: Vec::new(),
         };
 
         // Collecting module declarations with ModCollector
@@ -92,61 +147,555 @@ impl DefCollector {
         // Add the current crate to the collection of DefMaps
         context.def_maps.insert(crate_id, def_collector.def_map);
 
-        // Resolve unresolved imports collected from the crate
-        let (unresolved, resolved) =
-            resolve_imports(crate_id, def_collector.collected_imports, &context.def_maps);
-        
-        let mut unresolved_errors = Vec::new();
+        // Resolve unresolved imports collected from the crate and populate
+        // the module namespaces with the items they import
+        let import_errors = resolve_imports(
+            crate_id,
+            def_collector.collected_imports,
+            &mut context.def_maps,
+        );
 
         let current_def_map = context.def_maps.get(&crate_id).unwrap();
-            for unresolved_import in unresolved.into_iter(){
-                // File if that the import was declared
-                let file_id = current_def_map.modules[unresolved_import.module_id.0].origin.file_id();
-                let diagnostic = DefCollectorErrorKind::UnresolvedImport { import : unresolved_import }.to_diagnostic();
-                let err = CollectedErrors {
-                            file_id,
-                            errors: vec![diagnostic],
-                        };
-                    unresolved_errors.push(err);
-            }
-            if !unresolved_errors.is_empty() {
-                return Err(unresolved_errors)
-            }
+        let mut errors = Vec::new();
+        for import_error in import_errors {
+            let (module_id, diagnostic) = match import_error {
+                ImportError::Unresolved(import, PathResolutionError::Unresolved(_)) => {
+                    let module_id = import.module_id;
+                    let err = DefCollectorErrorKind::UnresolvedImport { import };
+                    (module_id, err.to_diagnostic())
+                }
+                ImportError::Unresolved(
+                    import,
+                    PathResolutionError::Private {
+                        segment,
+                        definition,
+                        file_id,
+                        in_dependency,
+                    },
+                ) => {
+                    let err = ResolverError::PrivateItem {
+                        segment,
+                        definition,
+                        file_id,
+                        in_dependency,
+                    };
+                    (import.module_id, err.into_diagnostic(&context.def_interner))
+                }
+                ImportError::Unresolved(
+                    import,
+                    PathResolutionError::Ambiguous {
+                        segment,
+                        first_import,
+                        second_import,
+                        file_id,
+                    },
+                ) => {
+                    let err = ResolverError::AmbiguousItem {
+                        segment,
+                        first_import,
+                        second_import,
+                        file_id,
+                    };
+                    (import.module_id, err.into_diagnostic(&context.def_interner))
+                }
+                ImportError::Duplicate {
+                    module_id,
+                    first_def,
+                    second_def,
+                } => {
+                    let err = DefCollectorErrorKind::DuplicateImport {
+                        first_def,
+                        second_def,
+                    };
+                    (module_id, err.to_diagnostic())
+                }
+            };
+            // File that the import was declared in
+            let file_id = current_def_map.modules[module_id.0].origin.file_id();
+            errors.push(CollectedErrors {
+                file_id,
+                errors: vec![diagnostic],
+            });
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
 
-        // Populate module namespaces according to the imports used
-        let current_def_map = context.def_maps.get_mut(&crate_id).unwrap();
-        for resolved_import in resolved {
-            let name = resolved_import.name;
-            for ns in resolved_import.resolved_namespace.iter_defs() {
-                current_def_map.modules[resolved_import.module_scope.0]
-                    .scope
-                    .add_item_to_namespace(name.clone(), ns)
-                    .map_err(|(first_def, second_def)| {
-                        let err = DefCollectorErrorKind::DuplicateImport {
-                            first_def,
-                            second_def,
-                        };
+        // Evaluate the globals. This must be done before the structs and functions
+        // are resolved, since globals may be used as array lengths
+        resolve_globals(
+            &mut context.def_interner,
+            crate_id,
+            &context.def_maps,
+            def_collector.collected_globals,
+        )?;
 
-                        vec![CollectedErrors {
-                            file_id: root_file_id,
-                            errors: vec![err.to_diagnostic()],
-                        }]
-                    })?;
-            }
-        }
+        // Inherent impl blocks can only be declared for the structs of this crate
+        let local_structs: HashSet<StructId> = def_collector
+            .collected_types
+            .iter()
+            .map(|unresolved| unresolved.struct_id)
+            .collect();
+
+        // Resolve the fields of each struct. This must be done before the functions
+        // are resolved, since functions may use the struct's fields
+        resolve_structs(
+            &mut context.def_interner,
+            crate_id,
+            &context.def_maps,
+            def_collector.collected_types,
+        )?;
+
+        // Resolve the method signatures of each trait. This must be done before the
+        // functions are resolved, since functions may call the methods through trait bounds
+        resolve_traits(
+            &mut context.def_interner,
+            crate_id,
+            &context.def_maps,
+            def_collector.collected_traits,
+        )?;
+
+        // Attach the methods of each impl block to their struct. This must be done before
+        // the functions are resolved, since functions may call the methods
+        let trait_impls = resolve_impls(
+            &mut context.def_interner,
+            crate_id,
+            &context.def_maps,
+            &local_structs,
+            def_collector.collected_impls,
+        )?;
 
         // Lower each function in the crate. This is now possible since imports have been resolved
         let file_func_ids = resolve_functions(
             &mut context.def_interner,
             crate_id,
             &context.def_maps,
+            &mut context.generic_functions,
             def_collector.collected_functions,
         )?;
 
+        // The parameters of main are the inputs of the circuit, so main cannot be generic
+        if context.crate_graph.crate_type(crate_id) == CrateType::Binary {
+            check_main_is_not_generic(
+                &context.def_interner,
+                &context.def_maps[&crate_id],
+                &file_func_ids,
+            )?;
+        }
+
+        // Now that the methods have been resolved, check that each impl of a
+        // trait implements the methods of the trait with the same signatures
+        check_trait_impls(&context.def_interner, trait_impls)?;
+
         // Type check all of the functions in the crate
-        type_check_functions(&mut context.def_interner, file_func_ids)?;
+        type_check_functions(&mut context.def_interner, &context.def_maps, file_func_ids)?;
+
+        // Instantiate the generic functions which were called in this crate
+        monomorphise(
+            &mut context.def_interner,
+            &context.def_maps,
+            &context.generic_functions,
+        )?;
+
+        Ok(())
+    }
+}
+
+fn resolve_globals(
+    interner: &mut NodeInterner,
+    crate_id: CrateId,
+    def_maps: &HashMap<CrateId, CrateDefMap>,
+    collected_globals: Vec<UnresolvedGlobal>,
+) -> Result<(), Vec<CollectedErrors>> {
+    let mut errors = Vec::new();
+
+    // A global may refer to globals which are declared after it, so the globals are
+    // evaluated repeatedly until no more of them can be evaluated
+    let mut remaining = collected_globals;
+    loop {
+        let num_remaining = remaining.len();
+        let mut unevaluated = Vec::new();
+        let mut deferred_errors = Vec::new();
+
+        for unresolved in remaining {
+            let path_resolver = FunctionPathResolver::new(ModuleId {
+                local_id: unresolved.module_id,
+                krate: crate_id,
+            });
+            let resolver = Resolver::new(interner, &path_resolver, def_maps);
+
+            match resolver.resolve_global_value(unresolved.stmt.expression.clone()) {
+                Ok(value) => interner.push_global_value(unresolved.global_id, value),
+                Err(err @ ResolverError::UnevaluatedGlobal { .. }) => {
+                    deferred_errors.push(CollectedErrors {
+                        file_id: unresolved.file_id,
+                        errors: vec![err.into_diagnostic(&interner)],
+                    });
+                    unevaluated.push(unresolved);
+                }
+                Err(err) => errors.push(CollectedErrors {
+                    file_id: unresolved.file_id,
+                    errors: vec![err.into_diagnostic(&interner)],
+                }),
+            }
+        }
+
+        // If no global was evaluated in this pass, the remaining globals depend
+        // on each other or on globals which could not be evaluated
+        if unevaluated.is_empty() || unevaluated.len() == num_remaining {
+            errors.extend(deferred_errors);
+            break;
+        }
+        remaining = unevaluated;
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn resolve_structs(
+    interner: &mut NodeInterner,
+    crate_id: CrateId,
+    def_maps: &HashMap<CrateId, CrateDefMap>,
+    collected_types: Vec<UnresolvedStruct>,
+) -> Result<(), Vec<CollectedErrors>> {
+    let mut errors = Vec::new();
+    let mut struct_ids = Vec::with_capacity(collected_types.len());
+
+    for unresolved in collected_types {
+        let path_resolver = FunctionPathResolver::new(ModuleId {
+            local_id: unresolved.module_id,
+            krate: crate_id,
+        });
+        let resolver = Resolver::new(interner, &path_resolver, def_maps);
+        let (fields, errs) = resolver.resolve_struct_fields(unresolved.struct_def);
+
+        interner
+            .get_struct(unresolved.struct_id)
+            .borrow_mut()
+            .fields = fields;
+        struct_ids.push((unresolved.file_id, unresolved.struct_id));
+
+        if !errs.is_empty() {
+            errors.push(CollectedErrors {
+                file_id: unresolved.file_id,
+                errors: errs
+                    .into_iter()
+                    .map(|err| err.into_diagnostic(&interner))
+                    .collect(),
+            });
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    // A struct which contains itself would have an infinite size
+    for (file_id, struct_id) in struct_ids {
+        if let Some(err) = check_for_recursive_struct(interner, struct_id, &mut Vec::new()) {
+            return Err(vec![CollectedErrors {
+                file_id,
+                errors: vec![err.into_diagnostic(&interner)],
+            }]);
+        }
+    }
+
+    Ok(())
+}
 
+fn resolve_traits(
+    interner: &mut NodeInterner,
+    crate_id: CrateId,
+    def_maps: &HashMap<CrateId, CrateDefMap>,
+    collected_traits: Vec<UnresolvedTrait>,
+) -> Result<(), Vec<CollectedErrors>> {
+    let mut errors = Vec::new();
+
+    for unresolved in collected_traits {
+        let path_resolver = FunctionPathResolver::new(ModuleId {
+            local_id: unresolved.module_id,
+            krate: crate_id,
+        });
+        let resolver = Resolver::new(interner, &path_resolver, def_maps);
+        let (methods, errs) = resolver.resolve_trait_methods(unresolved.trait_def);
+
+        interner.get_trait(unresolved.trait_id).borrow_mut().methods = methods;
+
+        if !errs.is_empty() {
+            errors.push(CollectedErrors {
+                file_id: unresolved.file_id,
+                errors: errs
+                    .into_iter()
+                    .map(|err| err.into_diagnostic(&interner))
+                    .collect(),
+            });
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn resolve_impls(
+    interner: &mut NodeInterner,
+    crate_id: CrateId,
+    def_maps: &HashMap<CrateId, CrateDefMap>,
+    local_structs: &HashSet<StructId>,
+    collected_impls: Vec<UnresolvedImpl>,
+) -> Result<Vec<TraitImpl>, Vec<CollectedErrors>> {
+    let mut errors = Vec::new();
+    let mut trait_impls = Vec::new();
+
+    // The name of each method is kept, so that duplicate methods can be reported
+    let mut method_names: HashMap<FuncId, Ident> = HashMap::new();
+
+    for unresolved in collected_impls {
+        let path_resolver = FunctionPathResolver::new(ModuleId {
+            local_id: unresolved.module_id,
+            krate: crate_id,
+        });
+        let resolver = Resolver::new(interner, &path_resolver, def_maps);
+
+        let span = unresolved.type_path.span();
+        let struct_id = match resolver.resolve_impl_type(unresolved.type_path) {
+            Ok(struct_id) => struct_id,
+            Err(errs) => {
+                errors.push(CollectedErrors {
+                    file_id: unresolved.file_id,
+                    errors: errs
+                        .into_iter()
+                        .map(|err| err.into_diagnostic(&interner))
+                        .collect(),
+                });
+                continue;
+            }
+        };
+
+        // The methods of a struct from another crate could clash with the methods declared
+        // in that crate, so they can only be added by implementing a trait
+        if unresolved.trait_path.is_none() && !local_structs.contains(&struct_id) {
+            let err = ResolverError::ForeignInherentImpl {
+                struct_definition: interner.get_struct(struct_id).borrow().name.clone(),
+                span,
+            };
+            errors.push(CollectedErrors {
+                file_id: unresolved.file_id,
+                errors: vec![err.into_diagnostic(&interner)],
+            });
+            continue;
+        }
+
+        // The trait is resolved first, so that an impl block which implements a trait
+        // for the second time is reported, instead of each of its methods
+        if let Some(trait_path) = unresolved.trait_path {
+            let resolver = Resolver::new(interner, &path_resolver, def_maps);
+            let trait_id = match resolver.resolve_impl_trait(trait_path) {
+                Ok(trait_id) => trait_id,
+                Err(errs) => {
+                    errors.push(CollectedErrors {
+                        file_id: unresolved.file_id,
+                        errors: errs
+                            .into_iter()
+                            .map(|err| err.into_diagnostic(&interner))
+                            .collect(),
+                    });
+                    continue;
+                }
+            };
+
+            if !interner.add_trait_impl(struct_id, trait_id) {
+                let err = ResolverError::DuplicateTraitImpl {
+                    trait_name: interner.get_trait(trait_id).borrow().name.clone(),
+                    struct_definition: interner.get_struct(struct_id).borrow().name.clone(),
+                    span,
+                };
+                errors.push(CollectedErrors {
+                    file_id: unresolved.file_id,
+                    errors: vec![err.into_diagnostic(&interner)],
+                });
+                continue;
+            }
+
+            trait_impls.push(TraitImpl {
+                file_id: unresolved.file_id,
+                struct_id,
+                trait_id,
+                span,
+                methods: unresolved.methods.clone(),
+            });
+        }
+
+        for (name, func_id) in unresolved.methods {
+            if let Some(first_id) = interner.add_method(struct_id, name.0.contents.clone(), func_id)
+            {
+                // A method declared in another crate is not in this file,
+                // so the error points at the second definition instead
+                let first_def = method_names
+                    .get(&first_id)
+                    .cloned()
+                    .unwrap_or_else(|| name.clone());
+                let err = DefCollectorErrorKind::DuplicateMethod {
+                    first_def,
+                    second_def: name.clone(),
+                };
+                errors.push(CollectedErrors {
+                    file_id: unresolved.file_id,
+                    errors: vec![err.to_diagnostic()],
+                });
+            }
+            method_names.insert(func_id, name);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(trait_impls)
+    } else {
+        Err(errors)
+    }
+}
+
+fn check_trait_impls(
+    interner: &NodeInterner,
+    trait_impls: Vec<TraitImpl>,
+) -> Result<(), Vec<CollectedErrors>> {
+    let mut errors = Vec::new();
+
+    for trait_impl in trait_impls {
+        let errs = check_trait_impl(interner, &trait_impl);
+        if !errs.is_empty() {
+            errors.push(CollectedErrors {
+                file_id: trait_impl.file_id,
+                errors: errs
+                    .into_iter()
+                    .map(|err| err.into_diagnostic(&interner))
+                    .collect(),
+            });
+        }
+    }
+
+    if errors.is_empty() {
         Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Checks that an impl block implements each method of the trait, with the signature
+/// declared in the trait, and that it does not declare any other methods.
+fn check_trait_impl(interner: &NodeInterner, trait_impl: &TraitImpl) -> Vec<ResolverError> {
+    let trait_type = interner.get_trait(trait_impl.trait_id);
+    let trait_type = trait_type.borrow();
+    let self_type = Type::Struct(interner.get_struct(trait_impl.struct_id));
+
+    let mut errors = Vec::new();
+    for (name, func_id) in &trait_impl.methods {
+        let method = match trait_type.method(&name.0.contents) {
+            Some(method) => method,
+            None => {
+                errors.push(ResolverError::NotATraitMethod {
+                    name: name.clone(),
+                    trait_name: trait_type.name.clone(),
+                });
+                continue;
+            }
+        };
+
+        let (parameters, return_type) = method.signature(&self_type);
+        let func_meta = interner.function_meta(func_id);
+        let found_parameters: Vec<_> = func_meta
+            .parameters
+            .iter()
+            .map(|param| param.1.clone())
+            .collect();
+
+        if parameters != found_parameters || return_type != func_meta.return_type {
+            errors.push(ResolverError::TraitMethodMismatch {
+                name: name.clone(),
+                trait_name: trait_type.name.clone(),
+                expected: fn_signature(&parameters, &return_type),
+                found: fn_signature(&found_parameters, &func_meta.return_type),
+            });
+        }
+    }
+
+    let missing_methods: Vec<_> = trait_type
+        .methods
+        .iter()
+        .filter(|method| {
+            !trait_impl
+                .methods
+                .iter()
+                .any(|(name, _)| name == &method.name)
+        })
+        .map(|method| method.name.0.contents.clone())
+        .collect();
+    if !missing_methods.is_empty() {
+        errors.push(ResolverError::MissingTraitMethods {
+            span: trait_impl.span,
+            missing_methods,
+            trait_name: trait_type.name.clone(),
+        });
+    }
+
+    errors
+}
+
+fn fn_signature(parameters: &[Type], return_type: &Type) -> String {
+    let parameters: Vec<_> = parameters.iter().map(|typ| typ.to_string()).collect();
+    format!("fn({}) -> {}", parameters.join(", "), return_type)
+}
+
+/// Walks the fields of a struct, returning an error if the struct
+/// contains itself, either directly or through one of its fields.
+fn check_for_recursive_struct(
+    interner: &NodeInterner,
+    struct_id: StructId,
+    visited: &mut Vec<StructId>,
+) -> Option<ResolverError> {
+    let struct_type = interner.get_struct(struct_id);
+    let struct_type = struct_type.borrow();
+
+    if visited.contains(&struct_id) {
+        return Some(ResolverError::RecursiveStruct {
+            name: struct_type.name.clone(),
+        });
+    }
+    visited.push(struct_id);
+
+    let mut field_structs = Vec::new();
+    for (_, field_type) in &struct_type.fields {
+        collect_structs(field_type, &mut field_structs);
+    }
+
+    for field_struct_id in field_structs {
+        if let Some(err) = check_for_recursive_struct(interner, field_struct_id, visited) {
+            return Some(err);
+        }
+    }
+
+    visited.pop();
+    None
+}
+
+// Collects the structs which are contained in a type, including
+// those which are the elements of arrays or tuples
+fn collect_structs(typ: &Type, structs: &mut Vec<StructId>) {
+    match typ {
+        Type::Struct(struct_type) => structs.push(struct_type.borrow().id),
+        Type::Array(_, _, elem_type) => collect_structs(elem_type, structs),
+        Type::Tuple(elements) => {
+            for elem in elements {
+                collect_structs(elem, structs);
+            }
+        }
+        _ => {}
     }
 }
 
@@ -154,8 +703,9 @@ fn resolve_functions(
     interner: &mut NodeInterner,
     crate_id: CrateId,
     def_maps: &HashMap<CrateId, CrateDefMap>,
+    generic_functions: &mut HashMap<FuncId, GenericFunction>,
     collected_functions: Vec<UnresolvedFunctions>,
-) -> Result<Vec<(FileId, FuncId)>, Vec<CollectedErrors>> {
+) -> Result<Vec<(FileId, ModuleId, FuncId)>, Vec<CollectedErrors>> {
     let mut file_func_ids = Vec::new();
     let mut errors = Vec::new();
 
@@ -168,16 +718,32 @@ fn resolve_functions(
         };
 
         for (mod_id, func_id, func) in unresolved_functions.functions {
-            file_func_ids.push((file_id, func_id));
-
-            let func_resolver = FunctionPathResolver::new(ModuleId {
+            let module_id = ModuleId {
                 local_id: mod_id,
                 krate: crate_id,
-            });
+            };
+            file_func_ids.push((file_id, module_id, func_id));
+
+            let func_resolver = FunctionPathResolver::new(module_id);
             let resolver = Resolver::new(interner, &func_resolver, def_maps);
 
+            // A generic function is resolved again for each instance
+            let generic_function = if func.generics().is_empty() {
+                None
+            } else {
+                Some(func.clone())
+            };
+
             match resolver.resolve_function(func) {
                 Ok((hir_func, func_meta)) => {
+                    if !func_meta.generics.is_empty() || !func_meta.type_generics.is_empty() {
+                        let generic_function = GenericFunction {
+                            file_id,
+                            module_id,
+                            function: generic_function.unwrap(),
+                        };
+                        generic_functions.insert(func_id, generic_function);
+                    }
                     interner.push_fn_meta(func_meta, func_id);
                     interner.update_fn(func_id, hir_func);
                 }
@@ -199,13 +765,50 @@ fn resolve_functions(
     return Err(errors);
 }
 
+fn check_main_is_not_generic(
+    interner: &NodeInterner,
+    def_map: &CrateDefMap,
+    file_func_ids: &[(FileId, ModuleId, FuncId)],
+) -> Result<(), Vec<CollectedErrors>> {
+    let main_id = match def_map.main_function() {
+        Some(main_id) => main_id,
+        None => return Ok(()),
+    };
+    let file_id = match file_func_ids
+        .iter()
+        .find(|(_, _, func_id)| *func_id == main_id)
+    {
+        Some((file_id, _, _)) => *file_id,
+        None => return Ok(()),
+    };
+
+    let func_meta = interner.function_meta(&main_id);
+    let generics = func_meta.generics.iter().map(|generic| {
+        let span = interner.ident_span(generic);
+        Ident::from(Spanned::from(span, interner.ident_name(generic)))
+    });
+    let errors: Vec<_> = generics
+        .chain(func_meta.type_generics.iter().cloned())
+        .map(|generic| ResolverError::GenericMain { generic }.into_diagnostic(interner))
+        .collect();
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+    Err(vec![CollectedErrors { file_id, errors }])
+}
+
 use crate::hir::type_check::type_check_func;
 fn type_check_functions(
     interner: &mut NodeInterner,
-    file_func_ids: Vec<(FileId, FuncId)>,
+    def_maps: &HashMap<CrateId, CrateDefMap>,
+    file_func_ids: Vec<(FileId, ModuleId, FuncId)>,
 ) -> Result<(), Vec<CollectedErrors>> {
-    for (file_id, func_id) in file_func_ids {
-        if let Err(type_err) = type_check_func(interner, func_id) {
+    for (file_id, module_id, func_id) in file_func_ids {
+        // Generic functions are type checked here against their generics,
+        // then again for each of their instances during monomorphisation
+        let visible_modules = visible_modules(def_maps, module_id);
+        if let Err(type_err) = type_check_func(interner, func_id, visible_modules) {
             let diag = type_err.into_diagnostics(interner);
             let errs = vec![CollectedErrors {
                 file_id,
@@ -1,10 +1,13 @@
 use fm::FileId;
 use noirc_errors::{CollectedErrors, DiagnosableError};
 
-use crate::{Ident, NoirFunction, ParsedModule};
+use crate::{Ident, NoirFunction, ParsedModule, Visibility};
 
 use super::{
-    dc_crate::{DefCollector, UnresolvedFunctions},
+    dc_crate::{
+        DefCollector, UnresolvedFunctions, UnresolvedGlobal, UnresolvedImpl, UnresolvedStruct,
+        UnresolvedTrait,
+    },
     errors::DefCollectorErrorKind,
 };
 use crate::hir::def_map::{parse_file, LocalModuleId, ModuleData, ModuleId, ModuleOrigin};
@@ -25,8 +28,8 @@ impl<'a> ModCollector<'a> {
         // First resolve the module declarations
         // XXX: to avoid clone, possibly destructure the AST and pass in `self` for mod collector instead of `&mut self`
         // Alternatively, pass in the AST as a reference
-        for decl in self.ast.module_decls.clone() {
-            self.parse_module_declaration(context, &decl)?
+        for (decl, visibility) in self.ast.module_decls.clone() {
+            self.parse_module_declaration(context, &decl, visibility)?
         }
 
         // Then add the imports to defCollector to resolve once all modules in the hierarchy have been resolved
@@ -35,6 +38,148 @@ impl<'a> ModCollector<'a> {
                 module_id: self.module_id,
                 path: import.path,
                 alias: import.alias,
+                is_glob: import.is_glob,
+                visibility: import.visibility,
+            });
+        }
+
+        // Then add the structs. Their fields are resolved once all of the
+        // imports have been resolved, as they may refer to other structs
+        for struct_def in self.ast.types.clone() {
+            let name = struct_def.name.clone();
+            let visibility = struct_def.visibility;
+
+            // Create an empty struct in the DefInterner, so that we can get a StructId
+            let struct_id = context
+                .def_interner
+                .push_empty_struct(name.clone(), struct_def.span);
+
+            // Add the struct to the scope of the module
+            self.def_collector.def_map.modules[self.module_id.0]
+                .scope
+                .define_struct_def(name, struct_id, visibility)
+                .map_err(|(first_def, second_def)| {
+                    let err = DefCollectorErrorKind::DuplicateStruct {
+                        first_def,
+                        second_def,
+                    };
+
+                    vec![CollectedErrors {
+                        file_id: self.file_id,
+                        errors: vec![err.to_diagnostic()],
+                    }]
+                })?;
+
+            self.def_collector.collected_types.push(UnresolvedStruct {
+                file_id: self.file_id,
+                module_id: self.module_id,
+                struct_id,
+                struct_def,
+            });
+        }
+
+        // Then add the enums. Their variants do not refer to any other
+        // items, so the enum is complete once it has been interned
+        for enum_def in self.ast.enums.clone() {
+            let name = enum_def.name.clone();
+
+            let mut variants: Vec<Ident> = Vec::with_capacity(enum_def.variants.len());
+            for variant in enum_def.variants {
+                if let Some(first_def) = variants.iter().find(|first_def| **first_def == variant) {
+                    let err = DefCollectorErrorKind::DuplicateVariant {
+                        first_def: first_def.clone(),
+                        second_def: variant,
+                    };
+                    return Err(vec![CollectedErrors {
+                        file_id: self.file_id,
+                        errors: vec![err.to_diagnostic()],
+                    }]);
+                }
+                variants.push(variant);
+            }
+
+            let enum_id = context
+                .def_interner
+                .push_enum(name.clone(), variants, enum_def.span);
+
+            // Enums share the type namespace with structs
+            self.def_collector.def_map.modules[self.module_id.0]
+                .scope
+                .define_enum_def(name, enum_id, enum_def.visibility)
+                .map_err(|(first_def, second_def)| {
+                    let err = DefCollectorErrorKind::DuplicateEnum {
+                        first_def,
+                        second_def,
+                    };
+
+                    vec![CollectedErrors {
+                        file_id: self.file_id,
+                        errors: vec![err.to_diagnostic()],
+                    }]
+                })?;
+        }
+
+        // Then add the traits. Like structs, the signatures of their methods
+        // are resolved once all of the imports have been resolved
+        for trait_def in self.ast.traits.clone() {
+            let name = trait_def.name.clone();
+
+            let trait_id = context
+                .def_interner
+                .push_empty_trait(name.clone(), trait_def.span);
+
+            // Traits share the type namespace with structs
+            self.def_collector.def_map.modules[self.module_id.0]
+                .scope
+                .define_trait_def(name, trait_id, trait_def.visibility)
+                .map_err(|(first_def, second_def)| {
+                    let err = DefCollectorErrorKind::DuplicateTrait {
+                        first_def,
+                        second_def,
+                    };
+
+                    vec![CollectedErrors {
+                        file_id: self.file_id,
+                        errors: vec![err.to_diagnostic()],
+                    }]
+                })?;
+
+            self.def_collector.collected_traits.push(UnresolvedTrait {
+                file_id: self.file_id,
+                module_id: self.module_id,
+                trait_id,
+                trait_def,
+            });
+        }
+
+        // Then add the globals. Like structs, they are added to the scope of the module
+        // first, and their values are evaluated once all of the imports have been resolved
+        for (global, visibility) in self.ast.globals.clone() {
+            let name = global.identifier.clone();
+
+            let global_id = context.def_interner.push_empty_global(name.clone());
+
+            // Globals share the value namespace with functions
+            self.def_collector.def_map.modules[self.module_id.0]
+                .scope
+                .define_global_def(name, global_id, visibility)
+                .map_err(|(first_def, second_def)| {
+                    let err = DefCollectorErrorKind::DuplicateGlobal {
+                        first_def,
+                        second_def,
+                    };
+
+                    vec![CollectedErrors {
+                        file_id: self.file_id,
+                        errors: vec![err.to_diagnostic()],
+                    }]
+                })?;
+
+            self.def_collector.collected_globals.push(UnresolvedGlobal {
+                file_id: self.file_id,
+                module_id: self.module_id,
+                global_id,
+                stmt: global,
             });
         }
 
@@ -45,6 +190,7 @@ impl<'a> ModCollector<'a> {
         };
         for function in self.ast.functions.clone() {
             let name = function.name_ident().clone();
+            let visibility = function.def().visibility;
             let nf: NoirFunction = function.into();
 
             // First create dummy function in the DefInterner
@@ -62,7 +208,7 @@ impl<'a> ModCollector<'a> {
             // Add function to scope/ns of the module
             self.def_collector.def_map.modules[self.module_id.0]
                 .scope
-                .define_func_def(name, func_id)
+                .define_func_def(name, func_id, visibility)
                 .map_err(|(first_def, second_def)| {
                     let err = DefCollectorErrorKind::DuplicateFunction {
                         first_def,
@@ -75,6 +221,39 @@ impl<'a> ModCollector<'a> {
                     }]
                 })?;
         }
+
+        // Then add the methods of each impl block. They are not added to the scope of the
+        // module, instead they are attached to their type once the structs have been resolved
+        for r#impl in self.ast.impls.clone() {
+            let mut methods = Vec::with_capacity(r#impl.methods.len());
+            for method in r#impl.methods {
+                let func_id = context.def_interner.push_empty_fn();
+                let name = method.name_ident().clone();
+                if method.def().visibility == Visibility::Private && r#impl.trait_path.is_none() {
+                    let module = ModuleId {
+                        krate: self.def_collector.def_map.krate,
+                        local_id: self.module_id,
+                    };
+                    context.def_interner.add_private_method(
+                        func_id,
+                        module,
+                        self.file_id,
+                        name.clone(),
+                    );
+                }
+                methods.push((name, func_id));
+                unresolved_functions.push_fn(self.module_id, func_id, method);
+            }
+
+            self.def_collector.collected_impls.push(UnresolvedImpl {
+                file_id: self.file_id,
+                module_id: self.module_id,
+                trait_path: r#impl.trait_path,
+                type_path: r#impl.type_path,
+                methods,
+            });
+        }
+
         self.def_collector
             .collected_functions
             .push(unresolved_functions);
@@ -88,6 +267,7 @@ impl<'a> ModCollector<'a> {
         &mut self,
         context: &mut Context,
         mod_name: &Ident,
+        visibility: Visibility,
     ) -> Result<(), Vec<CollectedErrors>> {
         let child_file_id = context
             .file_manager
@@ -107,7 +287,7 @@ impl<'a> ModCollector<'a> {
         let ast = parse_file(&mut context.file_manager, child_file_id)?;
 
         // Add module into def collector and get a ModuleId
-        let child_mod_id = self.push_child_module(mod_name, child_file_id)?;
+        let child_mod_id = self.push_child_module(mod_name, child_file_id, visibility)?;
 
         ModCollector {
             def_collector: self.def_collector,
@@ -122,6 +302,7 @@ impl<'a> ModCollector<'a> {
         &mut self,
         mod_name: &Ident,
         file_id: FileId,
+        visibility: Visibility,
     ) -> Result<LocalModuleId, Vec<CollectedErrors>> {
         // Create a new default module
         let module_id = self
@@ -156,7 +337,7 @@ impl<'a> ModCollector<'a> {
         .into();
         modules[self.module_id.0]
             .scope
-            .define_module_def(mod_name.to_owned(), mod_id)
+            .define_module_def(mod_name.to_owned(), mod_id, visibility)
             .map_err(|(first_def, second_def)| {
                 let err = DefCollectorErrorKind::DuplicateModuleDecl {
                     first_def,
@@ -8,6 +8,18 @@ use thiserror::Error;
 pub enum DefCollectorErrorKind {
     #[error("duplicate function found in namespace")]
     DuplicateFunction { first_def: Ident, second_def: Ident },
+    #[error("duplicate struct found in namespace")]
+    DuplicateStruct { first_def: Ident, second_def: Ident },
+    #[error("duplicate enum found in namespace")]
+    DuplicateEnum { first_def: Ident, second_def: Ident },
+    #[error("duplicate enum variant")]
+    DuplicateVariant { first_def: Ident, second_def: Ident },
+    #[error("duplicate trait found in namespace")]
+    DuplicateTrait { first_def: Ident, second_def: Ident },
+    #[error("duplicate method found in impl")]
+    DuplicateMethod { first_def: Ident, second_def: Ident },
+    #[error("duplicate global found in namespace")]
+    DuplicateGlobal { first_def: Ident, second_def: Ident },
     #[error("duplicate function found in namespace")]
     DuplicateModuleDecl { first_def: Ident, second_def: Ident },
     #[error("duplicate import")]
@@ -37,6 +49,102 @@ impl DiagnosableError for DefCollectorErrorKind {
                 diag.add_secondary(format!("second definition found here"), second_span);
                 diag
             }
+            DefCollectorErrorKind::DuplicateStruct {
+                first_def,
+                second_def,
+            } => {
+                let first_span = first_def.0.span();
+                let second_span = second_def.0.span();
+                let struct_name = &first_def.0.contents;
+
+                let mut diag = Diagnostic::simple_error(
+                    format!("duplicate definitions of {} struct found", struct_name),
+                    format!("first definition found here"),
+                    first_span,
+                );
+                diag.add_secondary(format!("second definition found here"), second_span);
+                diag
+            }
+            DefCollectorErrorKind::DuplicateEnum {
+                first_def,
+                second_def,
+            } => {
+                let first_span = first_def.0.span();
+                let second_span = second_def.0.span();
+                let enum_name = &first_def.0.contents;
+
+                let mut diag = Diagnostic::simple_error(
+                    format!("duplicate definitions of {} enum found", enum_name),
+                    format!("first definition found here"),
+                    first_span,
+                );
+                diag.add_secondary(format!("second definition found here"), second_span);
+                diag
+            }
+            DefCollectorErrorKind::DuplicateVariant {
+                first_def,
+                second_def,
+            } => {
+                let first_span = first_def.0.span();
+                let second_span = second_def.0.span();
+                let variant_name = &first_def.0.contents;
+
+                let mut diag = Diagnostic::simple_error(
+                    format!("duplicate definitions of {} variant found", variant_name),
+                    format!("first definition found here"),
+                    first_span,
+                );
+                diag.add_secondary(format!("second definition found here"), second_span);
+                diag
+            }
+            DefCollectorErrorKind::DuplicateTrait {
+                first_def,
+                second_def,
+            } => {
+                let first_span = first_def.0.span();
+                let second_span = second_def.0.span();
+                let trait_name = &first_def.0.contents;
+
+                let mut diag = Diagnostic::simple_error(
+                    format!("duplicate definitions of {} trait found", trait_name),
+                    format!("first definition found here"),
+                    first_span,
+                );
+                diag.add_secondary(format!("second definition found here"), second_span);
+                diag
+            }
+            DefCollectorErrorKind::DuplicateMethod {
+                first_def,
+                second_def,
+            } => {
+                let first_span = first_def.0.span();
+                let second_span = second_def.0.span();
+                let method_name = &first_def.0.contents;
+
+                let mut diag = Diagnostic::simple_error(
+                    format!("duplicate definitions of {} method found", method_name),
+                    format!("first definition found here"),
+                    first_span,
+                );
+                diag.add_secondary(format!("second definition found here"), second_span);
+                diag
+            }
+            DefCollectorErrorKind::DuplicateGlobal {
+                first_def,
+                second_def,
+            } => {
+                let first_span = first_def.0.span();
+                let second_span = second_def.0.span();
+                let global_name = &first_def.0.contents;
+
+                let mut diag = Diagnostic::simple_error(
+                    format!("the name `{}` is defined multiple times", global_name),
+                    format!("first definition found here"),
+                    first_span,
+                );
+                diag.add_secondary(format!("second definition found here"), second_span);
+                diag
+            }
             DefCollectorErrorKind::DuplicateModuleDecl {
                 first_def,
                 second_def,
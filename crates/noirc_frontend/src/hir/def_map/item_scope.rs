@@ -1,17 +1,25 @@
 use super::{namespace::PerNs, ModuleDefId, ModuleId};
-use crate::{node_interner::FuncId, Ident};
-use std::collections::{hash_map::Entry, HashMap};
-
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
-pub enum Visibility {
-    Public,
-}
+use crate::{
+    node_interner::{EnumId, FuncId, GlobalId, StructId, TraitId},
+    Ident, Visibility,
+};
+use std::collections::{HashMap, HashSet};
 
 #[derive(Default, Debug, PartialEq, Eq)]
 pub struct ItemScope {
     types: HashMap<Ident, (ModuleDefId, Visibility)>,
     values: HashMap<Ident, (ModuleDefId, Visibility)>,
 
+    // The names in each namespace which were added by glob imports.
+    // Any other item with the same name shadows them.
+    glob_types: HashSet<Ident>,
+    glob_values: HashSet<Ident>,
+
+    // The names in each namespace which two glob imports bring different items for,
+    // along with the second of these imports. Using such a name is an error
+    ambiguous_types: HashMap<Ident, Ident>,
+    ambiguous_values: HashMap<Ident, Ident>,
+
     defs: Vec<ModuleDefId>,
 }
 
@@ -20,8 +28,9 @@ impl ItemScope {
         &mut self,
         name: Ident,
         mod_def: ModuleDefId,
+        visibility: Visibility,
     ) -> Result<(), (Ident, Ident)> {
-        self.add_item_to_namespace(name, mod_def)?;
+        self.add_item_to_namespace(name, mod_def, visibility)?;
         self.defs.push(mod_def);
         Ok(())
     }
@@ -32,39 +41,127 @@ impl ItemScope {
         &mut self,
         name: Ident,
         mod_def: ModuleDefId,
+        visibility: Visibility,
     ) -> Result<(), (Ident, Ident)> {
-        match &mod_def {
-            ModuleDefId::ModuleId(_) => {
-                if let Entry::Occupied(o) = self.types.entry(name.clone()) {
-                    let old_ident = o.key();
-                    return Err((old_ident.clone(), name.clone()));
-                }
-
-                self.types
-                    .insert(name.clone(), (mod_def, Visibility::Public))
-            }
-            ModuleDefId::FunctionId(_) => {
-                if let Entry::Occupied(o) = self.values.entry(name.clone()) {
-                    let old_ident = o.key();
-                    return Err((old_ident.clone(), name.clone()));
-                }
-
-                self.values
-                    .insert(name.clone(), (mod_def, Visibility::Public))
+        let (namespace, glob_imports, ambiguous) = self.namespace_mut(&mod_def);
+
+        if let Some((old_ident, _)) = namespace.get_key_value(&name) {
+            if !glob_imports.remove(&name) {
+                return Err((old_ident.clone(), name));
             }
-        };
+            // The key is replaced as well, so that it points at the new item
+            namespace.remove(&name);
+            ambiguous.remove(&name);
+        }
+
+        namespace.insert(name, (mod_def, visibility));
         Ok(())
     }
 
+    /// Adds an item imported by a glob import. If there is already an item in
+    /// the namespace with that name, it takes precedence and this is not an error.
+    /// Unless that item was also imported by a glob import, and is a different item,
+    /// in which case the name becomes ambiguous.
+    /// Returns true if the item was added or the name became ambiguous.
+    pub fn add_glob_import(
+        &mut self,
+        name: Ident,
+        mod_def: ModuleDefId,
+        visibility: Visibility,
+    ) -> bool {
+        let (namespace, glob_imports, ambiguous) = self.namespace_mut(&mod_def);
+        if let Some((old_def, _)) = namespace.get(&name) {
+            let is_ambiguous = glob_imports.contains(&name) && *old_def != mod_def;
+            if !is_ambiguous || ambiguous.contains_key(&name) {
+                return false;
+            }
+            ambiguous.insert(name.clone(), name);
+            return true;
+        }
+
+        glob_imports.insert(name.clone());
+        namespace.insert(name, (mod_def, visibility));
+        true
+    }
+
+    // Returns the namespace that an item is added to, along with the names in
+    // that namespace which were added by glob imports, and those which are ambiguous
+    fn namespace_mut(
+        &mut self,
+        mod_def: &ModuleDefId,
+    ) -> (
+        &mut HashMap<Ident, (ModuleDefId, Visibility)>,
+        &mut HashSet<Ident>,
+        &mut HashMap<Ident, Ident>,
+    ) {
+        match mod_def {
+            ModuleDefId::ModuleId(_)
+            | ModuleDefId::TypeId(_)
+            | ModuleDefId::EnumId(_)
+            | ModuleDefId::TraitId(_) => (
+                &mut self.types,
+                &mut self.glob_types,
+                &mut self.ambiguous_types,
+            ),
+            ModuleDefId::FunctionId(_) | ModuleDefId::GlobalId(_) => (
+                &mut self.values,
+                &mut self.glob_values,
+                &mut self.ambiguous_values,
+            ),
+        }
+    }
+
     pub fn define_module_def(
         &mut self,
         name: Ident,
         mod_id: ModuleId,
+        visibility: Visibility,
+    ) -> Result<(), (Ident, Ident)> {
+        self.add_definition(name, mod_id.into(), visibility)
+    }
+    pub fn define_func_def(
+        &mut self,
+        name: Ident,
+        local_id: FuncId,
+        visibility: Visibility,
+    ) -> Result<(), (Ident, Ident)> {
+        self.add_definition(name, local_id.into(), visibility)
+    }
+
+    pub fn define_struct_def(
+        &mut self,
+        name: Ident,
+        local_id: StructId,
+        visibility: Visibility,
     ) -> Result<(), (Ident, Ident)> {
-        self.add_definition(name, mod_id.into())
+        self.add_definition(name, local_id.into(), visibility)
     }
-    pub fn define_func_def(&mut self, name: Ident, local_id: FuncId) -> Result<(), (Ident, Ident)> {
-        self.add_definition(name, local_id.into())
+
+    pub fn define_enum_def(
+        &mut self,
+        name: Ident,
+        local_id: EnumId,
+        visibility: Visibility,
+    ) -> Result<(), (Ident, Ident)> {
+        self.add_definition(name, local_id.into(), visibility)
+    }
+
+    pub fn define_trait_def(
+        &mut self,
+        name: Ident,
+        local_id: TraitId,
+        visibility: Visibility,
+    ) -> Result<(), (Ident, Ident)> {
+        self.add_definition(name, local_id.into(), visibility)
+    }
+
+    pub fn define_global_def(
+        &mut self,
+        name: Ident,
+        local_id: GlobalId,
+        visibility: Visibility,
+    ) -> Result<(), (Ident, Ident)> {
+        self.add_definition(name, local_id.into(), visibility)
     }
 
     pub fn find_module_with_name(&self, mod_name: &Ident) -> Option<&ModuleId> {
@@ -88,6 +185,17 @@ impl ItemScope {
         }
     }
 
+    /// Returns the two glob imports which bring different types with this name into scope
+    pub fn ambiguous_type(&self, name: &Ident) -> Option<(&Ident, &Ident)> {
+        let (first_import, _) = self.types.get_key_value(name)?;
+        Some((first_import, self.ambiguous_types.get(name)?))
+    }
+    /// Returns the two glob imports which bring different values with this name into scope
+    pub fn ambiguous_value(&self, name: &Ident) -> Option<(&Ident, &Ident)> {
+        let (first_import, _) = self.values.get_key_value(name)?;
+        Some((first_import, self.ambiguous_values.get(name)?))
+    }
+
     pub fn definitions(&self) -> Vec<ModuleDefId> {
         self.defs.clone()
     }
@@ -1,4 +1,4 @@
-use crate::node_interner::FuncId;
+use crate::node_interner::{EnumId, FuncId, GlobalId, StructId, TraitId};
 
 use super::ModuleId;
 
@@ -6,6 +6,10 @@ use super::ModuleId;
 pub enum ModuleDefId {
     ModuleId(ModuleId),
     FunctionId(FuncId),
+    TypeId(StructId),
+    EnumId(EnumId),
+    GlobalId(GlobalId),
+    TraitId(TraitId),
 }
 
 impl ModuleDefId {
@@ -15,12 +19,40 @@ impl ModuleDefId {
         }
         return None;
     }
+    pub fn as_type(&self) -> Option<StructId> {
+        if let ModuleDefId::TypeId(struct_id) = self {
+            return Some(*struct_id);
+        }
+        return None;
+    }
+    pub fn as_enum(&self) -> Option<EnumId> {
+        if let ModuleDefId::EnumId(enum_id) = self {
+            return Some(*enum_id);
+        }
+        return None;
+    }
+    pub fn as_trait(&self) -> Option<TraitId> {
+        if let ModuleDefId::TraitId(trait_id) = self {
+            return Some(*trait_id);
+        }
+        return None;
+    }
+    pub fn as_global(&self) -> Option<GlobalId> {
+        if let ModuleDefId::GlobalId(global_id) = self {
+            return Some(*global_id);
+        }
+        return None;
+    }
     // XXX: We are still allocating fro error reporting even though strings are stored in binary
     // It is a minor performance issue, which can be addressed by having the error reporting, not allocate
     pub fn as_str(&self) -> &'static str {
         match self {
             ModuleDefId::FunctionId(_) => "function",
             ModuleDefId::ModuleId(_) => "module",
+            ModuleDefId::TypeId(_) => "struct",
+            ModuleDefId::EnumId(_) => "enum",
+            ModuleDefId::GlobalId(_) => "global",
+            ModuleDefId::TraitId(_) => "trait",
         }
     }
 }
@@ -35,3 +67,23 @@ impl Into<ModuleDefId> for FuncId {
         ModuleDefId::FunctionId(self)
     }
 }
+impl Into<ModuleDefId> for StructId {
+    fn into(self) -> ModuleDefId {
+        ModuleDefId::TypeId(self)
+    }
+}
+impl Into<ModuleDefId> for EnumId {
+    fn into(self) -> ModuleDefId {
+        ModuleDefId::EnumId(self)
+    }
+}
+impl Into<ModuleDefId> for TraitId {
+    fn into(self) -> ModuleDefId {
+        ModuleDefId::TraitId(self)
+    }
+}
+impl Into<ModuleDefId> for GlobalId {
+    fn into(self) -> ModuleDefId {
+        ModuleDefId::GlobalId(self)
+    }
+}
@@ -1,4 +1,5 @@
-use super::{item_scope::Visibility, ModuleDefId};
+use super::ModuleDefId;
+use crate::Visibility;
 
 // This works exactly the same as in r-a, just simplified
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -15,6 +16,13 @@ impl PerNs {
         }
     }
 
+    pub fn values(t: ModuleDefId) -> PerNs {
+        PerNs {
+            types: None,
+            values: Some((t, Visibility::Public)),
+        }
+    }
+
     pub fn take_types(self) -> Option<ModuleDefId> {
         self.types.map(|it| it.0)
     }
@@ -1,13 +1,15 @@
 pub mod def_collector;
 pub mod def_map;
+pub mod monomorphisation;
 pub mod resolution;
 pub mod scope;
 pub mod type_check;
 
 use crate::graph::{CrateGraph, CrateId};
-use crate::node_interner::NodeInterner;
+use crate::node_interner::{FuncId, NodeInterner};
 use def_map::CrateDefMap;
 use fm::FileManager;
+use monomorphisation::GenericFunction;
 use std::collections::HashMap;
 
 /// Global context that is accessible during each stage
@@ -19,6 +21,9 @@ pub struct Context {
 
     pub(crate) def_maps: HashMap<CrateId, CrateDefMap>,
 
+    // Functions which are generic over types, which are instantiated during monomorphisation
+    pub(crate) generic_functions: HashMap<FuncId, GenericFunction>,
+
     pub file_manager: FileManager,
 }
 
@@ -29,6 +34,7 @@ impl Default for Context {
             crate_graph: CrateGraph::default(),
             file_manager: FileManager::new(),
             def_maps: HashMap::new(),
+            generic_functions: HashMap::new(),
         }
     }
 }
@@ -38,6 +44,7 @@ impl Context {
         Context {
             def_interner: NodeInterner::default(),
             def_maps: HashMap::new(),
+            generic_functions: HashMap::new(),
             crate_graph,
             file_manager,
         }
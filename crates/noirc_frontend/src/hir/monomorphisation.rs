@@ -0,0 +1,171 @@
+// Monomorphisation creates an instance of a generic function, for each set of
+// array lengths and types that the function is called with.
+//
+// Calls to the generic function are then replaced with calls to the instance,
+// so generic functions never reach the evaluator.
+use std::collections::HashMap;
+
+use fm::FileId;
+use noirc_errors::CollectedErrors;
+
+use crate::graph::CrateId;
+use crate::hir::def_map::{CrateDefMap, ModuleId};
+use crate::hir::resolution::{
+    import::visible_modules, path_resolver::FunctionPathResolver, resolver::Resolver,
+};
+use crate::hir::type_check::{type_check_func, TypeCheckError};
+use crate::hir_def::expr::HirExpression;
+use crate::hir_def::function::GenericArgs;
+use crate::node_interner::{FuncId, NodeInterner};
+use crate::{Ident, NoirFunction};
+
+/// A function which is generic over array lengths or types.
+///
+/// The function is resolved again for each instance, so we keep its AST
+/// along with the module it was declared in.
+#[derive(Debug)]
+pub struct GenericFunction {
+    pub file_id: FileId,
+    pub module_id: ModuleId,
+    pub function: NoirFunction,
+}
+
+// The maximum number of nested instantiations. Without a limit, a function which
+// calls itself with a larger type each time would be instantiated forever
+const MAX_INSTANTIATION_DEPTH: usize = 64;
+
+/// Replaces each call to a generic function with a call to the instance of the function
+/// for the types it was called with. Instances are created and type checked as needed.
+pub(crate) fn monomorphise(
+    interner: &mut NodeInterner,
+    def_maps: &HashMap<CrateId, CrateDefMap>,
+    generic_functions: &HashMap<FuncId, GenericFunction>,
+) -> Result<(), Vec<CollectedErrors>> {
+    // Type checking an instance can find more calls to generic functions, so each call
+    // is paired with the number of instantiations that led to it, and the file it is in
+    let mut generic_calls: Vec<_> = interner
+        .take_generic_calls()
+        .into_iter()
+        .map(|call| (call, 0, None))
+        .collect();
+
+    while let Some(((call_expr_id, func_id, args), depth, file_id)) = generic_calls.pop() {
+        let instance = match interner.instance(&func_id, &args) {
+            Some(instance) => instance,
+            None => {
+                let generic = &generic_functions[&func_id];
+                if depth >= MAX_INSTANTIATION_DEPTH {
+                    let err = TypeCheckError::InstantiationDepth {
+                        instantiation: instantiation_name(interner, func_id, &args),
+                        limit: MAX_INSTANTIATION_DEPTH,
+                        span: interner.expr_span(&call_expr_id),
+                    };
+                    return Err(vec![CollectedErrors {
+                        file_id: file_id.unwrap_or(generic.file_id),
+                        errors: err.into_diagnostics(interner),
+                    }]);
+                }
+
+                let instance = instantiate(interner, def_maps, generic_functions, func_id, args)?;
+                let calls = interner.take_generic_calls().into_iter();
+                generic_calls.extend(calls.map(|call| (call, depth + 1, Some(generic.file_id))));
+                instance
+            }
+        };
+
+        let mut call_expr = match interner.expression(&call_expr_id) {
+            HirExpression::Call(call_expr) => call_expr,
+            _ => unreachable!("ice: generic calls should always be call expressions"),
+        };
+        call_expr.func_id = instance;
+        interner.update_expr(call_expr_id, HirExpression::Call(call_expr));
+    }
+
+    Ok(())
+}
+
+/// Creates the instance of a generic function for a set of lengths and types, by resolving
+/// the function with each generic bound to its value and then type checking it.
+fn instantiate(
+    interner: &mut NodeInterner,
+    def_maps: &HashMap<CrateId, CrateDefMap>,
+    generic_functions: &HashMap<FuncId, GenericFunction>,
+    func_id: FuncId,
+    args: GenericArgs,
+) -> Result<FuncId, Vec<CollectedErrors>> {
+    let generic = generic_functions
+        .get(&func_id)
+        .expect("ice: the AST of every generic function should be kept");
+
+    let func_meta = interner.function_meta(&func_id);
+    let length_bindings: HashMap<_, _> = func_meta
+        .generics
+        .iter()
+        .map(|generic| Ident::from(interner.ident_name(generic)))
+        .zip(args.lengths.iter().copied())
+        .collect();
+    let type_bindings = func_meta
+        .type_generics
+        .into_iter()
+        .zip(args.types.iter().cloned())
+        .collect();
+
+    let instantiation = instantiation_name(interner, func_id, &args);
+
+    // The instance is recorded before it is type checked, so that
+    // recursive calls are redirected to the instance itself
+    let instance = interner.push_empty_fn();
+    interner.push_instance(func_id, args, instance);
+
+    let path_resolver = FunctionPathResolver::new(generic.module_id);
+    let resolver = Resolver::new(interner, &path_resolver, def_maps);
+
+    match resolver.resolve_function_instance(
+        generic.function.clone(),
+        type_bindings,
+        length_bindings,
+    ) {
+        Ok((hir_func, func_meta)) => {
+            interner.push_fn_meta(func_meta, instance);
+            interner.update_fn(instance, hir_func);
+        }
+        Err(errs) => {
+            return Err(vec![CollectedErrors {
+                file_id: generic.file_id,
+                errors: errs
+                    .into_iter()
+                    .map(|err| err.into_diagnostic(&interner))
+                    .collect(),
+            }]);
+        }
+    }
+
+    let visible_modules = visible_modules(def_maps, generic.module_id);
+    if let Err(err) = type_check_func(interner, instance, visible_modules) {
+        let err = TypeCheckError::Instantiation {
+            err: Box::new(err),
+            instantiation,
+        };
+        return Err(vec![CollectedErrors {
+            file_id: generic.file_id,
+            errors: err.into_diagnostics(interner),
+        }]);
+    }
+
+    Ok(instance)
+}
+
+// Returns the name of an instance, such as `sum<3>` or `max<u8>`
+fn instantiation_name(interner: &NodeInterner, func_id: FuncId, args: &GenericArgs) -> String {
+    let arg_names: Vec<_> = args
+        .lengths
+        .iter()
+        .map(|length| length.to_string())
+        .chain(args.types.iter().map(|typ| typ.to_string()))
+        .collect();
+    format!(
+        "{}<{}>",
+        interner.function_meta(&func_id).name,
+        arg_names.join(", ")
+    )
+}
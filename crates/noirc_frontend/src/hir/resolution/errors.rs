@@ -1,3 +1,4 @@
+use fm::FileId;
 use noirc_errors::CustomDiagnostic as Diagnostic;
 pub use noirc_errors::Span;
 use thiserror::Error;
@@ -26,12 +27,98 @@ pub enum ResolverError {
         name: String,
         segment: Ident,
     },
+    #[error("Item is private")]
+    PrivateItem {
+        segment: Ident,
+        definition: Ident,
+        file_id: FileId,
+        in_dependency: bool,
+    },
+    #[error("Item is ambiguous")]
+    AmbiguousItem {
+        segment: Ident,
+        first_import: Ident,
+        second_import: Ident,
+        file_id: FileId,
+    },
     #[error("could not resolve path")]
     Expected {
         span: Span,
         expected: String,
         got: String,
     },
+    #[error("Duplicate field in struct")]
+    DuplicateField { field: Ident },
+    #[error("No such field in struct")]
+    NoSuchField {
+        field: Ident,
+        struct_definition: Ident,
+    },
+    #[error("Recursive struct")]
+    RecursiveStruct { name: Ident },
+    #[error("Cannot assign to an immutable variable")]
+    AssignToImmutable { name: String, span: Span },
+    #[error("Generic could not be inferred")]
+    UninferredGeneric { generic: Ident },
+    #[error("Expression is not a constant")]
+    NotConstant { span: Span },
+    #[error("Division by zero in a constant expression")]
+    DivisionByZero { span: Span },
+    #[error("Invalid array length")]
+    InvalidArrayLength { span: Span },
+    #[error("Invalid slice range")]
+    InvalidSliceRange { span: Span },
+    #[error("Global could not be evaluated")]
+    UnevaluatedGlobal { name: String, span: Span },
+    #[error("No such method in impl")]
+    NoSuchMethod {
+        name: Ident,
+        struct_definition: Ident,
+    },
+    #[error("No such variant in enum")]
+    NoSuchVariant { name: Ident, enum_definition: Ident },
+    #[error("Self type used outside of an impl")]
+    SelfTypeOutsideImpl { span: Span },
+    #[error("Duplicate method in trait")]
+    DuplicateTraitMethod { name: Ident },
+    #[error("Trait bound on a generic which is not a type")]
+    InvalidTraitBound { generic: Ident },
+    #[error("No such method in the trait bounds of a generic")]
+    NoSuchTraitMethod { name: Ident, generic: Ident },
+    #[error("Trait is implemented more than once")]
+    DuplicateTraitImpl {
+        trait_name: Ident,
+        struct_definition: Ident,
+        span: Span,
+    },
+    #[error("Method is not a member of the trait")]
+    NotATraitMethod { name: Ident, trait_name: Ident },
+    #[error("Method signature does not match the trait")]
+    TraitMethodMismatch {
+        name: Ident,
+        trait_name: Ident,
+        expected: String,
+        found: String,
+    },
+    #[error("Missing methods from trait impl")]
+    MissingTraitMethods {
+        span: Span,
+        missing_methods: Vec<String>,
+        trait_name: Ident,
+    },
+    #[error("Missing fields from struct")]
+    MissingFields {
+        span: Span,
+        missing_fields: Vec<String>,
+        struct_definition: Ident,
+    },
+    #[error("Inherent impl on a struct from another crate")]
+    ForeignInherentImpl {
+        struct_definition: Ident,
+        span: Span,
+    },
+    #[error("Main function is generic")]
+    GenericMain { generic: Ident },
 }
 
 impl ResolverError {
@@ -100,6 +187,68 @@ impl ResolverError {
 
                 diag
             }
+            ResolverError::PrivateItem {
+                segment,
+                definition,
+                file_id,
+                in_dependency,
+            } => {
+                let name = &segment.0.contents;
+
+                let mut diag = Diagnostic::simple_error(
+                    format!("`{}` is private", name),
+                    format!("private item"),
+                    segment.0.span(),
+                );
+                // The item may be defined in another file than the one it is used in
+                diag.add_secondary_in_file(
+                    format!("`{}` is defined here", name),
+                    definition.0.span(),
+                    file_id,
+                );
+                if in_dependency {
+                    // Crates written before items were private by default do not declare any item with `pub`
+                    diag.add_note(format!(
+                        "items are private by default, so the crate which declares `{}` must declare it with `pub` for it to be used from another crate",
+                        name
+                    ));
+                } else {
+                    diag.add_note(format!(
+                        "items can only be used outside of their module if they are declared with `pub`"
+                    ));
+                }
+                diag
+            }
+            ResolverError::AmbiguousItem {
+                segment,
+                first_import,
+                second_import,
+                file_id,
+            } => {
+                let name = &segment.0.contents;
+
+                let mut diag = Diagnostic::simple_error(
+                    format!("`{}` is ambiguous", name),
+                    format!("ambiguous name"),
+                    segment.0.span(),
+                );
+                // The imports may be declared in another file than the one the name is used in
+                diag.add_secondary_in_file(
+                    format!("`{}` could refer to the item imported here", name),
+                    first_import.0.span(),
+                    file_id,
+                );
+                diag.add_secondary_in_file(
+                    format!("`{}` could also refer to the item imported here", name),
+                    second_import.0.span(),
+                    file_id,
+                );
+                diag.add_note(format!(
+                    "import `{}` explicitly to choose between the two items",
+                    name
+                ));
+                diag
+            }
             ResolverError::Expected {
                 span,
                 expected,
@@ -109,6 +258,254 @@ impl ResolverError {
                 String::new(),
                 span,
             ),
+            ResolverError::DuplicateField { field } => Diagnostic::simple_error(
+                format!("duplicate field {}", field.0.contents),
+                String::new(),
+                field.0.span(),
+            ),
+            ResolverError::NoSuchField {
+                field,
+                struct_definition,
+            } => Diagnostic::simple_error(
+                format!(
+                    "no such field {} defined in struct {}",
+                    field.0.contents, struct_definition.0.contents
+                ),
+                String::new(),
+                field.0.span(),
+            ),
+            ResolverError::RecursiveStruct { name } => Diagnostic::simple_error(
+                format!("struct {} contains itself", name.0.contents),
+                format!("recursive structs have an infinite size"),
+                name.0.span(),
+            ),
+            ResolverError::AssignToImmutable { name, span } => {
+                let mut diag = Diagnostic::simple_error(
+                    format!("cannot assign twice to immutable variable `{}`", name),
+                    format!("cannot assign twice to immutable variable"),
+                    span,
+                );
+                diag.add_note(format!("consider declaring it with `let mut {}`", name));
+                diag
+            }
+            ResolverError::UninferredGeneric { generic } => {
+                let mut diag = Diagnostic::simple_error(
+                    format!("cannot infer the value of generic `{}`", generic.0.contents),
+                    format!("not used by any parameter"),
+                    generic.0.span(),
+                );
+                diag.add_note(format!(
+                    "generics are inferred from arguments, so `{}` must be the length of an array parameter or be used in the type of a parameter",
+                    generic.0.contents
+                ));
+                diag
+            }
+            ResolverError::NotConstant { span } => {
+                let mut diag = Diagnostic::simple_error(
+                    format!("expression cannot be evaluated at compile time"),
+                    format!("not a constant expression"),
+                    span,
+                );
+                diag.add_note(format!(
+                    "only integer literals, constants and the operators `+`, `-`, `*` and `/` can be evaluated at compile time"
+                ));
+                diag
+            }
+            ResolverError::DivisionByZero { span } => Diagnostic::simple_error(
+                format!("attempt to divide by zero in a constant expression"),
+                format!("this evaluates to zero"),
+                span,
+            ),
+            ResolverError::InvalidArrayLength { span } => {
+                let mut diag = Diagnostic::simple_error(
+                    format!("array lengths must fit within a u128"),
+                    format!("invalid array length"),
+                    span,
+                );
+                diag.add_note(format!(
+                    "constants are field elements, so subtracting a larger value or dividing by a value which is not a factor does not give an integer"
+                ));
+                diag
+            }
+            ResolverError::InvalidSliceRange { span } => {
+                let mut diag = Diagnostic::simple_error(
+                    format!("the start of a slice must not be after its end"),
+                    format!("invalid slice range"),
+                    span,
+                );
+                diag.add_note(format!(
+                    "slice bounds are field elements, so a negative bound is a very large value"
+                ));
+                diag
+            }
+            ResolverError::UnevaluatedGlobal { name, span } => {
+                let mut diag = Diagnostic::simple_error(
+                    format!("could not evaluate the value of global `{}`", name),
+                    format!("used here"),
+                    span,
+                );
+                diag.add_note(format!(
+                    "globals cannot depend on themselves or on globals which could not be evaluated"
+                ));
+                diag
+            }
+            ResolverError::NoSuchMethod {
+                name,
+                struct_definition,
+            } => Diagnostic::simple_error(
+                format!(
+                    "no function named {} found in the impl blocks of struct {}",
+                    name.0.contents, struct_definition.0.contents
+                ),
+                String::new(),
+                name.0.span(),
+            ),
+            ResolverError::NoSuchVariant {
+                name,
+                enum_definition,
+            } => Diagnostic::simple_error(
+                format!(
+                    "no variant named {} found in enum {}",
+                    name.0.contents, enum_definition.0.contents
+                ),
+                String::new(),
+                name.0.span(),
+            ),
+            ResolverError::SelfTypeOutsideImpl { span } => Diagnostic::simple_error(
+                format!("`Self` can only be used in the signature of a method"),
+                format!("`Self` is not available here"),
+                span,
+            ),
+            ResolverError::DuplicateTraitMethod { name } => Diagnostic::simple_error(
+                format!(
+                    "duplicate definitions of {} method found in trait",
+                    name.0.contents
+                ),
+                format!("second definition found here"),
+                name.0.span(),
+            ),
+            ResolverError::InvalidTraitBound { generic } => {
+                let mut diag = Diagnostic::simple_error(
+                    format!("`{}` cannot have a trait bound", generic.0.contents),
+                    format!("not a type generic"),
+                    generic.0.span(),
+                );
+                diag.add_note(format!(
+                    "trait bounds can only be placed on generics which are used as the type of a parameter"
+                ));
+                diag
+            }
+            ResolverError::NoSuchTraitMethod { name, generic } => {
+                let mut diag = Diagnostic::simple_error(
+                    format!(
+                        "no method named {} found in the traits that {} implements",
+                        name.0.contents, generic.0.contents
+                    ),
+                    String::new(),
+                    name.0.span(),
+                );
+                diag.add_note(format!(
+                    "methods can only be called on a generic if it is bounded by a trait which declares them, such as `{}: Trait`",
+                    generic.0.contents
+                ));
+                diag
+            }
+            ResolverError::DuplicateTraitImpl {
+                trait_name,
+                struct_definition,
+                span,
+            } => Diagnostic::simple_error(
+                format!(
+                    "trait {} is implemented more than once for struct {}",
+                    trait_name.0.contents, struct_definition.0.contents
+                ),
+                format!("second implementation found here"),
+                span,
+            ),
+            ResolverError::NotATraitMethod { name, trait_name } => Diagnostic::simple_error(
+                format!(
+                    "method {} is not a member of trait {}",
+                    name.0.contents, trait_name.0.contents
+                ),
+                format!("not declared in the trait"),
+                name.0.span(),
+            ),
+            ResolverError::TraitMethodMismatch {
+                name,
+                trait_name,
+                expected,
+                found,
+            } => Diagnostic::simple_error(
+                format!(
+                    "method {} does not match its declaration in trait {}",
+                    name.0.contents, trait_name.0.contents
+                ),
+                format!("expected `{}`, found `{}`", expected, found),
+                name.0.span(),
+            ),
+            ResolverError::MissingTraitMethods {
+                span,
+                missing_methods,
+                trait_name,
+            } => {
+                let plural = if missing_methods.len() != 1 { "s" } else { "" };
+                let missing_methods = missing_methods.join(", ");
+
+                Diagnostic::simple_error(
+                    format!(
+                        "missing method{} {} in the impl of trait {}",
+                        plural, missing_methods, trait_name.0.contents
+                    ),
+                    format!("missing method{}", plural),
+                    span,
+                )
+            }
+            ResolverError::MissingFields {
+                span,
+                missing_fields,
+                struct_definition,
+            } => {
+                let plural = if missing_fields.len() != 1 { "s" } else { "" };
+                let missing_fields = missing_fields.join(", ");
+
+                Diagnostic::simple_error(
+                    format!(
+                        "missing field{} {} in struct {}",
+                        plural, missing_fields, struct_definition.0.contents
+                    ),
+                    String::new(),
+                    span,
+                )
+            }
+            ResolverError::ForeignInherentImpl {
+                struct_definition,
+                span,
+            } => {
+                let mut diag = Diagnostic::simple_error(
+                    format!(
+                        "cannot declare an impl block for struct {}, which is defined in another crate",
+                        struct_definition.0.contents
+                    ),
+                    format!("impl for a struct from another crate"),
+                    span,
+                );
+                diag.add_note(
+                    "methods can be added to it by implementing a trait declared in this crate"
+                        .to_owned(),
+                );
+                diag
+            }
+            ResolverError::GenericMain { generic } => {
+                let mut diag = Diagnostic::simple_error(
+                    format!("main cannot be generic over `{}`", generic.0.contents),
+                    format!("generic declared here"),
+                    generic.0.span(),
+                );
+                diag.add_note(
+                    "the parameters of main are the inputs of the circuit, so their types and lengths must be known".to_owned(),
+                );
+                diag
+            }
         }
     }
 }
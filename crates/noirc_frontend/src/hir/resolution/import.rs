@@ -1,65 +1,183 @@
 use crate::graph::CrateId;
+use fm::FileId;
+use noirc_errors::Spanned;
 use std::collections::HashMap;
 
 use crate::hir::def_map::{CrateDefMap, LocalModuleId, ModuleDefId, ModuleId, PerNs};
-use crate::{Ident, Path, PathKind};
+use crate::{Ident, Path, Visibility};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ImportDirective {
     pub module_id: LocalModuleId,
     pub path: Path,
     pub alias: Option<Ident>,
+    pub is_glob: bool,
+    pub visibility: Visibility,
 }
 
-#[derive(Debug)]
-pub enum PathResolution {
-    Resolved(PerNs),
+pub type PathResolution = Result<PerNs, PathResolutionError>;
+
+#[derive(Debug, Clone)]
+pub enum PathResolutionError {
     Unresolved(Ident),
+    // The segment refers to an item which is private to a module
+    // that the path is not resolved from
+    Private {
+        segment: Ident,
+        // The name of the item in the scope of its module, along with the file
+        // of that module. Its span points at the definition of the item
+        definition: Ident,
+        file_id: FileId,
+        // The item is declared in another crate than the one the path is resolved from
+        in_dependency: bool,
+    },
+    // The segment is brought into scope by two glob imports, which refer
+    // to different items. The spans of the imports point at their paths
+    Ambiguous {
+        segment: Ident,
+        first_import: Ident,
+        second_import: Ident,
+        // The file of the module which declares the imports
+        file_id: FileId,
+    },
 }
+
 #[derive(Debug)]
-pub struct ResolvedImport {
-    // name of the namespace, either last path segment or an alias
-    pub name: Ident,
-    // The symbol which we have resolved to
-    pub resolved_namespace: PerNs,
-    // The module which we must add the resolved namespace to
-    pub module_scope: LocalModuleId,
+pub enum ImportError {
+    Unresolved(ImportDirective, PathResolutionError),
+    // The import adds a name which is already used in the scope of its module
+    Duplicate {
+        module_id: LocalModuleId,
+        first_def: Ident,
+        second_def: Ident,
+    },
 }
 
+/// Resolves the imports of a crate and adds the items that they refer to
+/// to the scope of the module which declares them.
+///
+/// An import may refer to an item which is itself imported, such as a re-export.
+/// So the imports are resolved repeatedly, until an iteration does not add any items.
+/// The imports which are still unresolved at that point are returned as errors.
 pub fn resolve_imports(
     crate_id: CrateId,
     imports_to_resolve: Vec<ImportDirective>,
-    def_maps: &HashMap<CrateId, CrateDefMap>,
-) -> (Vec<ImportDirective>, Vec<ResolvedImport>) {
-    let num_imports = imports_to_resolve.len();
-    let def_map = &def_maps[&crate_id];
-
-    let mut unresolved: Vec<ImportDirective> = Vec::new();
-    let mut resolved: Vec<ResolvedImport> = Vec::new();
-    for import_directive in imports_to_resolve {
-        let defs = resolve_path_to_ns(&import_directive, def_map, def_maps);
-
-        // Once we have the Option<defs>
-        // resolve name and push into appropriate vector
-        match defs {
-            PathResolution::Unresolved(_) => {
-                unresolved.push(import_directive);
+    def_maps: &mut HashMap<CrateId, CrateDefMap>,
+) -> Vec<ImportError> {
+    let (glob_imports, mut pending): (Vec<_>, Vec<_>) = imports_to_resolve
+        .into_iter()
+        .partition(|import_directive| import_directive.is_glob);
+
+    let mut errors = Vec::new();
+    loop {
+        let def_map = &def_maps[&crate_id];
+
+        let mut unresolved = Vec::new();
+        let mut resolved = Vec::new();
+        for import_directive in pending {
+            match resolve_path_to_ns(&import_directive, def_map, def_maps) {
+                Ok(resolved_namespace) => resolved.push((import_directive, resolved_namespace)),
+                Err(err) => unresolved.push((import_directive, err)),
+            }
+        }
+
+        // Glob imports are resolved on every iteration, since the
+        // module that they refer to may have gained new items
+        let mut glob_items = Vec::new();
+        let mut glob_errors = Vec::new();
+        for import_directive in &glob_imports {
+            match resolve_glob_import(import_directive, def_map, def_maps) {
+                Ok(items) => glob_items.push((import_directive, items)),
+                Err(err) => glob_errors.push((import_directive.clone(), err)),
             }
-            PathResolution::Resolved(resolved_namespace) => {
-                let name = resolve_path_name(&import_directive);
-                let res = ResolvedImport {
-                    name,
-                    resolved_namespace,
-                    module_scope: import_directive.module_id,
-                };
-                resolved.push(res);
+        }
+
+        let def_map = def_maps.get_mut(&crate_id).unwrap();
+        let mut changed = !resolved.is_empty();
+
+        for (import_directive, resolved_namespace) in resolved {
+            let name = resolve_path_name(&import_directive);
+            let scope = &mut def_map.modules[import_directive.module_id.0].scope;
+            for ns in resolved_namespace.iter_defs() {
+                let visibility = import_directive.visibility;
+                if let Err((first_def, second_def)) =
+                    scope.add_item_to_namespace(name.clone(), ns, visibility)
+                {
+                    errors.push(ImportError::Duplicate {
+                        module_id: import_directive.module_id,
+                        first_def,
+                        second_def,
+                    });
+                }
             }
-        };
+        }
+
+        for (import_directive, items) in glob_items {
+            let scope = &mut def_map.modules[import_directive.module_id.0].scope;
+            for (name, mod_def) in items {
+                // The span of the name points at the import, as the item
+                // is defined in the file of another module
+                let name = Ident(Spanned::from(import_directive.path.span(), name.0.contents));
+                changed |= scope.add_glob_import(name, mod_def, import_directive.visibility);
+            }
+        }
+
+        if !changed {
+            errors.extend(
+                unresolved
+                    .into_iter()
+                    .chain(glob_errors)
+                    .map(|(import_directive, err)| ImportError::Unresolved(import_directive, err)),
+            );
+            return errors;
+        }
+        pending = unresolved
+            .into_iter()
+            .map(|(import_directive, _)| import_directive)
+            .collect();
     }
+}
+
+// Returns the items of the module that a glob import refers to,
+// which are visible from the module that declares the import
+fn resolve_glob_import(
+    import_directive: &ImportDirective,
+    def_map: &CrateDefMap,
+    def_maps: &HashMap<CrateId, CrateDefMap>,
+) -> Result<Vec<(Ident, ModuleDefId)>, PathResolutionError> {
+    let resolved_namespace = resolve_path_to_ns(import_directive, def_map, def_maps)?;
+
+    // Only modules contain items, the variants of an enum cannot be imported
+    let module = match resolved_namespace.take_types() {
+        Some(ModuleDefId::ModuleId(module)) => module,
+        _ => {
+            let segment = import_directive.path.last_segment();
+            return Err(PathResolutionError::Unresolved(segment));
+        }
+    };
+
+    let from = ModuleId {
+        krate: def_map.krate,
+        local_id: import_directive.module_id,
+    };
+    let scope = &def_maps[&module.krate].modules[module.local_id.0].scope;
 
-    assert!(unresolved.len() + resolved.len() == num_imports);
+    // Ambiguous names are not imported, as they do not refer to a single item
+    let types = scope
+        .types()
+        .iter()
+        .filter(|(name, _)| scope.ambiguous_type(name).is_none());
+    let values = scope
+        .values()
+        .iter()
+        .filter(|(name, _)| scope.ambiguous_value(name).is_none());
 
-    (unresolved, resolved)
+    let items = types
+        .chain(values)
+        .filter(|(_, (_, visibility))| is_visible(def_maps, *visibility, module, from))
+        .map(|(name, (mod_def, _))| (name.clone(), *mod_def))
+        .collect();
+    Ok(items)
 }
 
 pub fn resolve_path_to_ns(
@@ -69,16 +187,31 @@ pub fn resolve_path_to_ns(
 ) -> PathResolution {
     let import_path = &import_directive.path.segments;
 
+    // The module which the path is resolved from. Private items
+    // are only visible from their own module and its descendants
+    let from = ModuleId {
+        krate: def_map.krate,
+        local_id: import_directive.module_id,
+    };
+
     match import_directive.path.kind {
         crate::ast::PathKind::Crate => {
             // Resolve from the root of the crate
-            resolve_path_from_crate_root(def_map, &import_path, def_maps)
+            resolve_path_from_crate_root(def_map, &import_path, from, def_maps)
+        }
+        crate::ast::PathKind::Dep => {
+            resolve_external_dep(def_map, &import_directive, from, def_maps)
         }
-        crate::ast::PathKind::Dep => resolve_external_dep(def_map, &import_directive, def_maps),
         crate::ast::PathKind::Plain => {
             // Plain paths are only used to import children modules. It's possible to allow import of external deps, but maybe this distinction is better?
             // In Rust they can also point to external Dependencies, if no children can be found with the specified name
-            resolve_name_in_module(def_map, import_path, import_directive.module_id, def_maps)
+            resolve_name_in_module(
+                def_map,
+                import_path,
+                import_directive.module_id,
+                from,
+                def_maps,
+            )
         }
     }
 }
@@ -86,58 +219,188 @@ pub fn resolve_path_to_ns(
 fn resolve_path_from_crate_root(
     def_map: &CrateDefMap,
     import_path: &[Ident],
+    from: ModuleId,
     def_maps: &HashMap<CrateId, CrateDefMap>,
 ) -> PathResolution {
-    resolve_name_in_module(def_map, import_path, def_map.root, def_maps)
+    resolve_name_in_module(def_map, import_path, def_map.root, from, def_maps)
 }
 
 fn resolve_name_in_module(
     def_map: &CrateDefMap,
     import_path: &[Ident],
     starting_mod: LocalModuleId,
+    from: ModuleId,
     def_maps: &HashMap<CrateId, CrateDefMap>,
 ) -> PathResolution {
-    let mut current_mod = &def_map.modules[starting_mod.0];
+    let mut current_mod_id = ModuleId {
+        krate: def_map.krate,
+        local_id: starting_mod,
+    };
 
     // There is a possibility that the import path is empty
     // In that case, early return
     if import_path.is_empty() {
-        let mod_id = ModuleId {
-            krate: def_map.krate,
-            local_id: starting_mod,
-        };
-        return PathResolution::Resolved(PerNs::types(mod_id.into()));
+        return Ok(PerNs::types(current_mod_id.into()));
     }
 
     let mut import_path = import_path.into_iter();
     let first_segment = import_path.next().expect("ice: could not fetch first segment");
-    let mut current_ns = current_mod.scope.find_name(&first_segment);
-    if current_ns.is_none() {
-        return PathResolution::Unresolved(first_segment.clone());
-    }
-
+    let mut current_ns = find_visible_name(def_maps, current_mod_id, first_segment, from)?;
 
     for segment in import_path {
         let typ = match current_ns.take_types() {
-            None => return PathResolution::Unresolved(segment.clone()),
+            None => return Err(PathResolutionError::Unresolved(segment.clone())),
             Some(typ) => typ,
         };
 
-        // In the type namespace, only Mod can be used in a path. Moreover only Mod is in this namespace for now
+        // In the type namespace, only Mod can be used in a path.
         let new_module_id = match typ {
             ModuleDefId::ModuleId(id) => id,
             ModuleDefId::FunctionId(_) => panic!("functions cannot be in the type namespace"),
+            ModuleDefId::GlobalId(_) => panic!("globals cannot be in the type namespace"),
+            // A struct does not contain any items, so it cannot be a path segment
+            ModuleDefId::TypeId(_) => return Err(PathResolutionError::Unresolved(segment.clone())),
+            // Variants of an enum are not items, they are only found when resolving expressions
+            ModuleDefId::EnumId(_) => return Err(PathResolutionError::Unresolved(segment.clone())),
+            // Nor does a trait, its methods are only found through the types implementing it
+            ModuleDefId::TraitId(_) => {
+                return Err(PathResolutionError::Unresolved(segment.clone()))
+            }
         };
-        current_mod = &def_maps[&new_module_id.krate].modules[new_module_id.local_id.0];
-        // Check if namespace
-        let found_ns = current_mod.scope.find_name(&segment);
-        if found_ns.is_none() {
-            return PathResolution::Unresolved(segment.clone());
-        }
-        current_ns = found_ns
+        current_mod_id = new_module_id;
+        current_ns = find_visible_name(def_maps, current_mod_id, segment, from)?;
+    }
+
+    Ok(current_ns)
+}
+
+// Looks up `name` in the scope of `module`, keeping only the items which are visible from `from`.
+// If there are items with this name, but none of them are visible, the error points at one of them.
+// If one of the visible items is ambiguous, the error points at the glob imports which bring it into scope.
+fn find_visible_name(
+    def_maps: &HashMap<CrateId, CrateDefMap>,
+    module: ModuleId,
+    name: &Ident,
+    from: ModuleId,
+) -> PathResolution {
+    let module_data = &def_maps[&module.krate].modules[module.local_id.0];
+    let scope = &module_data.scope;
+
+    let mut found_ns = scope.find_name(name);
+    if found_ns.is_none() {
+        return Err(PathResolutionError::Unresolved(name.clone()));
+    }
+
+    let visible = |item: &(ModuleDefId, Visibility)| is_visible(def_maps, item.1, module, from);
+    let private_item = scope
+        .types()
+        .get_key_value(name)
+        .filter(|(_, item)| !visible(item))
+        .or_else(|| {
+            scope
+                .values()
+                .get_key_value(name)
+                .filter(|(_, item)| !visible(item))
+        });
+
+    found_ns.types = found_ns.types.filter(visible);
+    found_ns.values = found_ns.values.filter(visible);
+
+    let ambiguity = found_ns
+        .types
+        .and_then(|_| scope.ambiguous_type(name))
+        .or_else(|| found_ns.values.and_then(|_| scope.ambiguous_value(name)));
+    if let Some((first_import, second_import)) = ambiguity {
+        return Err(PathResolutionError::Ambiguous {
+            segment: name.clone(),
+            first_import: first_import.clone(),
+            second_import: second_import.clone(),
+            file_id: module_data.origin.file_id(),
+        });
     }
 
-    PathResolution::Resolved(current_ns)
+    match private_item {
+        Some((definition, _)) if found_ns.is_none() => Err(PathResolutionError::Private {
+            segment: name.clone(),
+            definition: definition.clone(),
+            file_id: module_data.origin.file_id(),
+            in_dependency: module.krate != from.krate,
+        }),
+        _ => Ok(found_ns),
+    }
+}
+
+/// Checks that a method declared without `pub`, in an impl block in `module`, can be used from `from`.
+///
+/// `method` is where the method is used and `definition` is where it is declared.
+pub fn check_private_method(
+    def_maps: &HashMap<CrateId, CrateDefMap>,
+    method: &Ident,
+    definition: &Ident,
+    module: ModuleId,
+    from: ModuleId,
+) -> Result<(), PathResolutionError> {
+    if is_descendant_of(def_maps, from, module) {
+        return Ok(());
+    }
+    Err(PathResolutionError::Private {
+        segment: method.clone(),
+        definition: definition.clone(),
+        file_id: def_maps[&module.krate].modules[module.local_id.0]
+            .origin
+            .file_id(),
+        in_dependency: module.krate != from.krate,
+    })
+}
+
+/// Returns `module` and its ancestors, which are the modules whose private items can be used from `module`
+pub fn visible_modules(
+    def_maps: &HashMap<CrateId, CrateDefMap>,
+    module: ModuleId,
+) -> Vec<ModuleId> {
+    let modules = &def_maps[&module.krate].modules;
+    let mut visible = Vec::new();
+    let mut current = Some(module.local_id);
+    while let Some(local_id) = current {
+        visible.push(ModuleId {
+            krate: module.krate,
+            local_id,
+        });
+        current = modules[local_id.0].parent;
+    }
+    visible
+}
+
+// Returns true if an item with the given visibility, in the scope of `module`, can be used from `from`.
+// Private items can only be used from their own module and its descendants
+fn is_visible(
+    def_maps: &HashMap<CrateId, CrateDefMap>,
+    visibility: Visibility,
+    module: ModuleId,
+    from: ModuleId,
+) -> bool {
+    visibility == Visibility::Public || is_descendant_of(def_maps, from, module)
+}
+
+// Returns true if `module` is `ancestor` itself, or one of its descendants in the same crate
+fn is_descendant_of(
+    def_maps: &HashMap<CrateId, CrateDefMap>,
+    module: ModuleId,
+    ancestor: ModuleId,
+) -> bool {
+    if module.krate != ancestor.krate {
+        return false;
+    }
+
+    let modules = &def_maps[&module.krate].modules;
+    let mut current = Some(module.local_id);
+    while let Some(local_id) = current {
+        if local_id == ancestor.local_id {
+            return true;
+        }
+        current = modules[local_id.0].parent;
+    }
+    false
 }
 
 fn resolve_path_name(import_directive: &ImportDirective) -> Ident {
@@ -150,6 +413,7 @@ fn resolve_path_name(import_directive: &ImportDirective) -> Ident {
 fn resolve_external_dep(
     current_def_map: &CrateDefMap,
     directive: &ImportDirective,
+    from: ModuleId,
     def_maps: &HashMap<CrateId, CrateDefMap>,
 ) -> PathResolution {
     // Use extern_prelude to get the dep
@@ -157,26 +421,24 @@ fn resolve_external_dep(
     let path = &directive.path.segments;
     //
     // Fetch the root module from the prelude
-    let crate_name = path.first().unwrap().0.contents.clone();
-    let dep_module = current_def_map
-        .extern_prelude
-        .get(&crate_name)
-        .expect("error reporter: could not find crate");
-
-    // Create an import directive for the dependency crate
-    let path_without_crate_name = &path[1..]; // XXX: This will panic if the path is of the form `use dep::std` Ideal algorithm will not distinguish between crate and module
-
-    let path = Path {
-        segments: path_without_crate_name.to_vec(),
-        kind: PathKind::Plain,
-    };
-    let dep_directive = ImportDirective {
-        module_id: dep_module.local_id,
-        path,
-        alias: directive.alias.clone(),
+    let crate_name = path.first().unwrap();
+    let dep_module = match current_def_map.extern_prelude.get(&crate_name.0.contents) {
+        Some(dep_module) => dep_module,
+        None => return Err(PathResolutionError::Unresolved(crate_name.clone())),
     };
 
+    // Resolve the rest of the path from the root module of the dependency crate.
+    // The visibility of each item is still checked from the module of the directive.
+    // A path of the form `dep::std` has no other segments, so it refers to the root module
+    let path_without_crate_name = &path[1..];
+
     let dep_def_map = def_maps.get(&dep_module.krate).unwrap();
 
-    resolve_path_to_ns(&dep_directive, dep_def_map, def_maps)
+    resolve_name_in_module(
+        dep_def_map,
+        path_without_crate_name,
+        dep_module.local_id,
+        from,
+        def_maps,
+    )
 }
@@ -1,16 +1,24 @@
-use super::import::{resolve_path_to_ns, ImportDirective, PathResolution};
-use crate::{Ident, Path};
+use super::import::{
+    check_private_method, resolve_path_to_ns, ImportDirective, PathResolution, PathResolutionError,
+};
+use crate::{Ident, Path, Visibility};
 use std::collections::HashMap;
 
 use crate::graph::CrateId;
-use crate::hir::def_map::{CrateDefMap, ModuleDefId, ModuleId};
+use crate::hir::def_map::{CrateDefMap, ModuleId};
 
 pub trait PathResolver {
-    fn resolve(
+    fn resolve(&self, def_maps: &HashMap<CrateId, CrateDefMap>, path: Path) -> PathResolution;
+
+    /// Checks that a private method, declared in an impl block in `module`,
+    /// can be used from the module that paths are resolved in
+    fn check_private_method(
         &self,
         def_maps: &HashMap<CrateId, CrateDefMap>,
-        path: Path,
-    ) -> Result<Option<ModuleDefId>, Ident>;
+        method: &Ident,
+        definition: &Ident,
+        module: ModuleId,
+    ) -> Result<(), PathResolutionError>;
 }
 
 pub struct FunctionPathResolver {
@@ -25,38 +33,38 @@ impl FunctionPathResolver {
 }
 
 impl PathResolver for FunctionPathResolver {
-    fn resolve(
+    fn resolve(&self, def_maps: &HashMap<CrateId, CrateDefMap>, path: Path) -> PathResolution {
+        resolve_path(def_maps, self.module_id, path)
+    }
+
+    fn check_private_method(
         &self,
         def_maps: &HashMap<CrateId, CrateDefMap>,
-        path: Path,
-    ) -> Result<Option<ModuleDefId>, Ident> {
-        resolve_function_call_path(def_maps, self.module_id, path)
+        method: &Ident,
+        definition: &Ident,
+        module: ModuleId,
+    ) -> Result<(), PathResolutionError> {
+        check_private_method(def_maps, method, definition, module, self.module_id)
     }
 }
 
 // Resolve `foo::bar` in foo::bar::call() to the module with the function
-pub fn resolve_function_call_path(
+// or `foo::Bar` in `x : foo::Bar` to the module with the struct
+pub fn resolve_path(
     def_maps: &HashMap<CrateId, CrateDefMap>,
     module_id: ModuleId,
     path: Path,
-) -> Result<Option<ModuleDefId>, Ident> {
+) -> PathResolution {
     // lets package up the path into an ImportDirective and resolve it using that
     let import = ImportDirective {
         module_id: module_id.local_id,
         path,
         alias: None,
+        is_glob: false,
+        visibility: Visibility::Private,
     };
 
+    // The Resolver decides which namespace it needs
     let def_map = &def_maps[&module_id.krate];
-    let path_res = resolve_path_to_ns(&import, def_map, def_maps);
-    let ns = match path_res {
-        PathResolution::Unresolved(seg) => return Err(seg),
-        PathResolution::Resolved(ns) => ns,
-    };
-
-    // XXX: Note that we are returning the value and not a type.
-    // In the future we can generalise and return a PerNs
-    // Which the Resolver will then deal with
-    // For now, since this is used only for function call paths, it is fine
-    Ok(ns.take_values())
+    resolve_path_to_ns(&import, def_map, def_maps)
 }
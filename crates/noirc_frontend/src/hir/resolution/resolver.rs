@@ -15,16 +15,26 @@
 struct ResolverMeta {
     num_times_used: usize,
     id: IdentId,
+    mutable: bool,
+    // The value of a local constant, if it could be evaluated at compile time
+    value: Option<FieldElement>,
 }
 use std::collections::HashMap;
 
 use crate::graph::CrateId;
-use crate::node_interner::{ExprId, FuncId, IdentId, NodeInterner, StmtId};
+use crate::node_interner::{
+    EnumId, ExprId, FuncId, GlobalId, IdentId, NodeInterner, StmtId, StructId, TraitId,
+};
 use crate::{
-    hir::{def_map::CrateDefMap, resolution::path_resolver::PathResolver},
-    BlockExpression, Expression, ExpressionKind, FunctionKind, Ident, Literal, NoirFunction,
-    Statement,
+    hir::{
+        def_map::{CrateDefMap, PerNs},
+        resolution::{import::PathResolutionError, path_resolver::PathResolver},
+    },
+    ArraySize, BinaryOpKind, BlockExpression, Expression, ExpressionKind, FunctionKind, Ident,
+    InfixExpression, LValue, Literal, MatchPattern, NoirFunction, NoirStruct, NoirTrait, Path,
+    PathKind, Pattern, Statement, Type,
 };
+use noir_field::FieldElement;
 use noirc_errors::Spanned;
 
 use crate::hir::scope::{
@@ -33,14 +43,17 @@ use crate::hir::scope::{
 use crate::hir_def::{
     expr::{
         HirArrayLiteral, HirBinaryOp, HirBlockExpression, HirCallExpression, HirCastExpression,
-        HirExpression, HirForExpression, HirIndexExpression, HirInfixExpression, HirLiteral,
-        HirPrefixExpression, HirUnaryOp,
+        HirConstructorExpression, HirEnumVariant, HirExpression, HirForExpression, HirIfExpression,
+        HirIndexExpression, HirInfixExpression, HirLiteral, HirMatchExpression, HirMatchPattern,
+        HirMemberAccess, HirMethodCallExpression, HirPrefixExpression, HirRepeatedArrayLiteral,
+        HirSliceExpression, HirUnaryOp, HirWhileExpression,
     },
     function::{FuncMeta, HirFunction, Param},
     stmt::{
-        HirConstStatement, HirConstrainStatement, HirLetStatement, HirPrivateStatement,
-        HirStatement,
+        HirAssignStatement, HirConstStatement, HirConstrainStatement, HirLValue, HirLetStatement,
+        HirPattern, HirPrivateStatement, HirStatement,
     },
+    types::TraitMethod,
 };
 
 use super::errors::ResolverError;
@@ -57,6 +70,21 @@ pub struct Resolver<'a> {
 
     interner: &'a mut NodeInterner,
 
+    // The generics of the function being resolved
+    generics: Vec<Ident>,
+
+    // The concrete types of the type generics, when resolving an instance of a generic function
+    type_bindings: HashMap<Ident, Type>,
+
+    // The lengths that the generics are bound to, when resolving an instance of a generic function
+    length_bindings: HashMap<Ident, u128>,
+
+    // The traits that each type generic of the function being resolved must implement
+    trait_bounds: Vec<(Ident, TraitId)>,
+
+    // The values of the return statements in the function being resolved
+    return_values: Vec<ExprId>,
+
     errors: Vec<ResolverError>,
 }
 
@@ -71,6 +99,11 @@ impl<'a> Resolver<'a> {
             def_maps,
             scopes: ScopeForest::new(),
             interner,
+            generics: Vec::new(),
+            type_bindings: HashMap::new(),
+            length_bindings: HashMap::new(),
+            trait_bounds: Vec::new(),
+            return_values: Vec::new(),
             errors: Vec::new(),
         }
     }
@@ -101,6 +134,98 @@ impl<'a> Resolver<'a> {
             return Err(self.errors);
         }
     }
+
+    /// Resolves an instance of a generic function.
+    ///
+    /// Each type generic is replaced with the type it is bound to and each length generic
+    /// with its value, so the resulting function can be type checked and evaluated like any other.
+    pub fn resolve_function_instance(
+        mut self,
+        func: NoirFunction,
+        type_bindings: HashMap<Ident, Type>,
+        length_bindings: HashMap<Ident, u128>,
+    ) -> Result<(HirFunction, FuncMeta), Vec<ResolverError>> {
+        self.type_bindings = type_bindings;
+        self.length_bindings = length_bindings;
+        self.resolve_function(func)
+    }
+    /// Evaluates the value of a global constant.
+    ///
+    /// Globals are evaluated before any struct or function is resolved,
+    /// so that they can be used as array lengths.
+    pub fn resolve_global_value(mut self, expr: Expression) -> Result<FieldElement, ResolverError> {
+        self.evaluate_const_expression(&expr)
+    }
+    /// Resolves the type that an impl block is declared for.
+    ///
+    /// Currently, methods can only be declared on structs.
+    pub fn resolve_impl_type(mut self, path: Path) -> Result<StructId, Vec<ResolverError>> {
+        self.lookup_struct(path).ok_or(self.errors)
+    }
+    /// Resolves the trait that an impl block implements.
+    pub fn resolve_impl_trait(mut self, path: Path) -> Result<TraitId, Vec<ResolverError>> {
+        self.lookup_trait(path).ok_or(self.errors)
+    }
+    /// Resolves the signatures of the methods declared in a trait.
+    ///
+    /// `Self` is resolved to a type generic, which is bound to the type
+    /// implementing the trait when an impl block is checked against the trait.
+    pub fn resolve_trait_methods(
+        mut self,
+        unresolved: NoirTrait,
+    ) -> (Vec<TraitMethod>, Vec<ResolverError>) {
+        let mut methods: Vec<TraitMethod> = Vec::with_capacity(unresolved.methods.len());
+
+        for method in unresolved.methods {
+            let name = method.name_ident().clone();
+            if methods.iter().any(|other| other.name == name) {
+                self.push_err(ResolverError::DuplicateTraitMethod { name });
+                continue;
+            }
+
+            let self_type: Ident = Spanned::from(name.0.span(), "Self".to_owned()).into();
+            self.generics = vec![self_type];
+            self.generics.extend(method.generics().iter().cloned());
+
+            let parameters = method
+                .parameters()
+                .iter()
+                .map(|(param, typ)| (param.clone(), self.resolve_type(typ.clone())))
+                .collect();
+            let return_type = self.resolve_type(method.return_type());
+
+            methods.push(TraitMethod {
+                name,
+                parameters,
+                return_type,
+            });
+        }
+
+        (methods, self.errors)
+    }
+    /// Resolves the types of a struct's fields.
+    ///
+    /// This is done separately from the functions, as a function
+    /// may refer to a struct which is declared after it.
+    pub fn resolve_struct_fields(
+        mut self,
+        unresolved: NoirStruct,
+    ) -> (Vec<(Ident, Type)>, Vec<ResolverError>) {
+        let mut fields: Vec<(Ident, Type)> = Vec::with_capacity(unresolved.fields.len());
+
+        for (name, typ) in unresolved.fields {
+            if fields.iter().any(|(field_name, _)| field_name == &name) {
+                self.push_err(ResolverError::DuplicateField { field: name });
+                continue;
+            }
+
+            let typ = self.resolve_type(typ);
+            fields.push((name, typ));
+        }
+
+        (fields, self.errors)
+    }
+
     fn resolve_expression(&mut self, expr: Expression) -> ExprId {
         self.intern_expr(expr)
     }
@@ -134,7 +259,394 @@ impl<'a> Resolver<'a> {
 }
 
 impl<'a> Resolver<'a> {
-    fn add_variable_decl(&mut self, name: Ident) -> IdentId {
+    /// Translates an (unresolved) AST type into a resolved type.
+    /// Named types are looked up and replaced with the struct they refer to.
+    pub fn resolve_type(&mut self, typ: Type) -> Type {
+        match typ {
+            Type::Unresolved(path) => {
+                // A named type may refer to a generic of the function being resolved
+                if let Some(name) = path.as_ident() {
+                    if let Some(typ) = self.type_bindings.get(name) {
+                        return typ.clone();
+                    }
+                    if self.generics.contains(name) {
+                        return Type::Generic(name.clone());
+                    }
+                    // `Self` has already been replaced in the signatures of methods
+                    if name.0.contents == "Self" {
+                        self.push_err(ResolverError::SelfTypeOutsideImpl {
+                            span: name.0.span(),
+                        });
+                        return Type::Error;
+                    }
+                }
+                if let Some(enum_id) = self.lookup_enum(&path) {
+                    return Type::Enum(self.interner.get_enum(enum_id));
+                }
+                match self.lookup_struct(path) {
+                    Some(struct_id) => Type::Struct(self.interner.get_struct(struct_id)),
+                    None => Type::Error,
+                }
+            }
+            Type::Array(fe_type, size, elem_type) => {
+                let size = match size {
+                    // A named length which is not a generic must be a constant
+                    ArraySize::Generic(name) if !self.generics.contains(&name) => {
+                        let span = name.0.span();
+                        let length = Expression {
+                            kind: ExpressionKind::Ident(name.0.contents),
+                            span,
+                        };
+                        self.evaluate_array_length(&length).map(ArraySize::Fixed)
+                    }
+                    ArraySize::Unevaluated(length) => {
+                        self.evaluate_array_length(&length).map(ArraySize::Fixed)
+                    }
+                    size => Some(size),
+                };
+                match size {
+                    Some(size) => {
+                        Type::Array(fe_type, size, Box::new(self.resolve_type(*elem_type)))
+                    }
+                    None => Type::Error,
+                }
+            }
+            Type::Tuple(elements) => Type::Tuple(
+                elements
+                    .into_iter()
+                    .map(|elem| self.resolve_type(elem))
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+
+    /// Resolves a path to a struct definition.
+    /// Errors are logged if the path cannot be resolved or does not refer to a struct
+    fn lookup_struct(&mut self, path: Path) -> Option<StructId> {
+        let span = path.span();
+        let ns = self.resolve_path(path)?;
+
+        match ns.take_types() {
+            Some(def_id) => match def_id.as_type() {
+                Some(struct_id) => Some(struct_id),
+                None => {
+                    self.push_err(ResolverError::Expected {
+                        expected: "struct".to_owned(),
+                        got: def_id.as_str().to_owned(),
+                        span,
+                    });
+                    None
+                }
+            },
+            None => {
+                let got = ns
+                    .iter_defs()
+                    .next()
+                    .map_or("nothing", |def_id| def_id.as_str());
+                self.push_err(ResolverError::Expected {
+                    expected: "struct".to_owned(),
+                    got: got.to_owned(),
+                    span,
+                });
+                None
+            }
+        }
+    }
+
+    /// Resolves a path to a trait definition.
+    /// Errors are logged if the path cannot be resolved or does not refer to a trait
+    fn lookup_trait(&mut self, path: Path) -> Option<TraitId> {
+        let span = path.span();
+        let ns = self.resolve_path(path)?;
+
+        match ns.take_types().and_then(|def_id| def_id.as_trait()) {
+            Some(trait_id) => Some(trait_id),
+            None => {
+                let got = ns
+                    .iter_defs()
+                    .next()
+                    .map_or("nothing", |def_id| def_id.as_str());
+                self.push_err(ResolverError::Expected {
+                    expected: "trait".to_owned(),
+                    got: got.to_owned(),
+                    span,
+                });
+                None
+            }
+        }
+    }
+
+    /// Resolves a path to a function.
+    /// Errors are logged if the path cannot be resolved or does not refer to a function
+    fn lookup_function(&mut self, path: Path) -> Option<FuncId> {
+        // A path such as `H::hash` refers to a method of the traits that the generic `H` implements
+        if let Some(func_id) = self.lookup_generic_method(&path) {
+            return func_id;
+        }
+
+        // A path such as `Point::new` refers to a function declared in an impl block
+        if let Some(struct_id) = self.lookup_impl_type(&path) {
+            let name = path.last_segment();
+            let func_id = self.interner.lookup_method(struct_id, &name.0.contents);
+            match func_id {
+                Some(func_id) => self.check_private_method(&name, func_id),
+                None => {
+                    let struct_definition =
+                        self.interner.get_struct(struct_id).borrow().name.clone();
+                    self.push_err(ResolverError::NoSuchMethod {
+                        name,
+                        struct_definition,
+                    });
+                }
+            }
+            return func_id;
+        }
+
+        let span = path.span();
+        let ns = self.resolve_path(path)?;
+
+        match ns.take_values().and_then(|def_id| def_id.as_function()) {
+            Some(func_id) => Some(func_id),
+            None => {
+                let got = ns
+                    .iter_defs()
+                    .next()
+                    .map_or("nothing", |def_id| def_id.as_str());
+                self.push_err(ResolverError::Expected {
+                    expected: "function".to_owned(),
+                    got: got.to_owned(),
+                    span,
+                });
+                None
+            }
+        }
+    }
+
+    // Resolves a path of the form `T::name`, where `T` is a type generic of the function.
+    // Returns None if the path is not of this form.
+    fn lookup_generic_method(&mut self, path: &Path) -> Option<Option<FuncId>> {
+        let (generic, name) = match path.segments.as_slice() {
+            [generic, name] if path.kind == PathKind::Plain => (generic, name),
+            _ => return None,
+        };
+
+        let is_trait_method = |resolver: &Self| {
+            resolver.trait_bounds.iter().any(|(bounded, trait_id)| {
+                bounded == generic
+                    && resolver
+                        .interner
+                        .get_trait(*trait_id)
+                        .borrow()
+                        .method(&name.0.contents)
+                        .is_some()
+            })
+        };
+
+        // When resolving an instance, the generic is bound to the type implementing the
+        // traits, so the method is looked up in the impl blocks of that type.
+        // Only the methods of the traits are visible, not the other methods of the type.
+        if let Some(typ) = self.type_bindings.get(generic) {
+            let func_id = match typ {
+                Type::Struct(struct_type) if is_trait_method(self) => self
+                    .interner
+                    .lookup_method(struct_type.borrow().id, &name.0.contents),
+                _ => None,
+            };
+            if func_id.is_none() {
+                self.push_err(ResolverError::NoSuchTraitMethod {
+                    name: name.clone(),
+                    generic: generic.clone(),
+                });
+            }
+            return Some(func_id);
+        }
+
+        if !self.generics.contains(generic) {
+            return None;
+        }
+
+        let method = self.trait_bounds.iter().find_map(|(bounded, trait_id)| {
+            if bounded != generic {
+                return None;
+            }
+            let trait_type = self.interner.get_trait(*trait_id);
+            let method = trait_type.borrow().method(&name.0.contents).cloned();
+            method
+        });
+        match method {
+            Some(method) => Some(Some(self.interner.push_trait_method(generic, method))),
+            None => {
+                self.push_err(ResolverError::NoSuchTraitMethod {
+                    name: name.clone(),
+                    generic: generic.clone(),
+                });
+                Some(None)
+            }
+        }
+    }
+
+    // Returns the struct which a path of the form `Type::name` is prefixed with.
+    // No error is logged if the prefix is not a struct, since the path
+    // may refer to a function in a module instead.
+    fn lookup_impl_type(&self, path: &Path) -> Option<StructId> {
+        let (_, type_segments) = path.segments.split_last()?;
+        if type_segments.is_empty() {
+            return None;
+        }
+        let type_path = Path {
+            segments: type_segments.to_vec(),
+            kind: path.kind.clone(),
+        };
+
+        let ns = self.path_resolver.resolve(self.def_maps, type_path).ok()?;
+        ns.take_types()?.as_type()
+    }
+
+    // Returns the enum that the path refers to. No error is logged if the path
+    // does not refer to an enum, since it may refer to a struct instead.
+    fn lookup_enum(&self, path: &Path) -> Option<EnumId> {
+        let ns = self
+            .path_resolver
+            .resolve(self.def_maps, path.clone())
+            .ok()?;
+        ns.take_types()?.as_enum()
+    }
+
+    // Resolves a path of the form `Enum::Variant`.
+    // Returns None if the path is not prefixed with an enum.
+    fn lookup_enum_variant(&mut self, path: &Path) -> Option<Option<HirEnumVariant>> {
+        let (name, enum_segments) = path.segments.split_last()?;
+        if enum_segments.is_empty() {
+            return None;
+        }
+        let enum_path = Path {
+            segments: enum_segments.to_vec(),
+            kind: path.kind.clone(),
+        };
+
+        let enum_type = self.interner.get_enum(self.lookup_enum(&enum_path)?);
+        match enum_type.variant(&name.0.contents) {
+            Some(index) => Some(Some(HirEnumVariant {
+                r#type: enum_type,
+                index,
+            })),
+            None => {
+                self.push_err(ResolverError::NoSuchVariant {
+                    name: name.clone(),
+                    enum_definition: enum_type.name.clone(),
+                });
+                Some(None)
+            }
+        }
+    }
+
+    fn resolve_match_pattern(&mut self, pattern: MatchPattern) -> HirMatchPattern {
+        let path = match pattern {
+            MatchPattern::Wildcard(span) => return HirMatchPattern::Wildcard(span),
+            MatchPattern::Variant(path) => path,
+        };
+
+        let span = path.span();
+        match self.lookup_enum_variant(&path) {
+            Some(Some(variant)) => HirMatchPattern::Variant(variant, span),
+            // An error has already been logged. The pattern is replaced with a wildcard,
+            // so that the match is not also reported as being non-exhaustive
+            Some(None) => HirMatchPattern::Wildcard(span),
+            None => {
+                let got = self
+                    .path_resolver
+                    .resolve(self.def_maps, path)
+                    .ok()
+                    .and_then(|ns| ns.iter_defs().next())
+                    .map_or("nothing", |def_id| def_id.as_str());
+                self.push_err(ResolverError::Expected {
+                    expected: "enum variant".to_owned(),
+                    got: got.to_owned(),
+                    span,
+                });
+                HirMatchPattern::Wildcard(span)
+            }
+        }
+    }
+
+    /// Resolves a path, logging an error if the path could not be resolved.
+    /// The caller decides which namespace the definition should be in.
+    fn resolve_path(&mut self, path: Path) -> Option<PerNs> {
+        // Get the span and name of path for error reporting
+        let span = path.span();
+        let name = path.as_string();
+
+        match self.path_resolver.resolve(self.def_maps, path) {
+            Ok(ns) => Some(ns),
+            Err(PathResolutionError::Unresolved(segment)) => {
+                let err = ResolverError::PathUnresolved {
+                    span,
+                    name,
+                    segment,
+                };
+                self.push_err(err);
+                None
+            }
+            Err(PathResolutionError::Private {
+                segment,
+                definition,
+                file_id,
+                in_dependency,
+            }) => {
+                let err = ResolverError::PrivateItem {
+                    segment,
+                    definition,
+                    file_id,
+                    in_dependency,
+                };
+                self.push_err(err);
+                None
+            }
+            Err(PathResolutionError::Ambiguous {
+                segment,
+                first_import,
+                second_import,
+                file_id,
+            }) => {
+                let err = ResolverError::AmbiguousItem {
+                    segment,
+                    first_import,
+                    second_import,
+                    file_id,
+                };
+                self.push_err(err);
+                None
+            }
+        }
+    }
+
+    // Methods declared without `pub` can only be used from the module of their impl block
+    fn check_private_method(&mut self, method: &Ident, func_id: FuncId) {
+        let (module, _, definition) = match self.interner.private_method(&func_id) {
+            Some(private_method) => private_method.clone(),
+            None => return,
+        };
+        let result =
+            self.path_resolver
+                .check_private_method(self.def_maps, method, &definition, module);
+        if let Err(PathResolutionError::Private {
+            segment,
+            definition,
+            file_id,
+            in_dependency,
+        }) = result
+        {
+            self.push_err(ResolverError::PrivateItem {
+                segment,
+                definition,
+                file_id,
+                in_dependency,
+            });
+        }
+    }
+
+    fn add_variable_decl(&mut self, name: Ident, mutable: bool) -> IdentId {
         let id = self.interner.push_ident(name.clone());
         // Variable was defined here, so it's definition links to itself
         self.interner.linked_ident_to_def(id, id);
@@ -143,6 +655,8 @@ impl<'a> Resolver<'a> {
         let resolver_meta = ResolverMeta {
             num_times_used: 0,
             id,
+            mutable,
+            value: None,
         };
         let old_value = scope.add_key_value(name.0.contents.clone(), resolver_meta);
 
@@ -190,8 +704,230 @@ impl<'a> Resolver<'a> {
         return IdentId::dummy_id();
     }
 
+    // Resolves a name which is used as an expression. The name either refers to a
+    // variable or to a global constant, which is replaced with its value.
+    // Variables shadow globals with the same name.
+    fn resolve_name(&mut self, path: Path) -> ExprId {
+        if let Some(variant) = self.lookup_enum_variant(&path) {
+            let expr = match variant {
+                Some(variant) => HirExpression::EnumVariant(variant),
+                // An error has already been logged
+                None => HirExpression::Ident(IdentId::dummy_id()),
+            };
+            return self.interner.push_expr(expr);
+        }
+
+        let is_variable = match path.as_ident() {
+            Some(name) => self
+                .scopes
+                .current_scope_tree()
+                .find(&name.0.contents)
+                .is_some(),
+            None => false,
+        };
+        if !is_variable {
+            // A generic which is bound is replaced with its value, like a global
+            if let Some(length) = path
+                .as_ident()
+                .and_then(|name| self.length_bindings.get(name))
+            {
+                let literal = HirLiteral::Integer(FieldElement::from(*length as i128));
+                return self.interner.push_expr(HirExpression::Literal(literal));
+            }
+            if let Some(value) = self.find_global(&path) {
+                let literal = HirLiteral::Integer(value);
+                return self.interner.push_expr(HirExpression::Literal(literal));
+            }
+        }
+
+        // If the Path is not a global, then it is referring to an Identifier
+        let ident_id = match path.as_ident() {
+            None => {
+                self.push_err(ResolverError::PathIsNotIdent { span: path.span() });
+
+                IdentId::dummy_id()
+            }
+            Some(identifier) => self.find_variable(identifier),
+        };
+
+        self.interner.push_expr(HirExpression::Ident(ident_id))
+    }
+
+    // Returns the value of the global constant that the path refers to.
+    // No error is logged if it does not refer to a global, so that the
+    // caller can look for something else with the same name.
+    fn find_global(&mut self, path: &Path) -> Option<FieldElement> {
+        let global_id = self.lookup_global(path)?;
+        self.interner.global_value(&global_id)
+    }
+
+    fn lookup_global(&self, path: &Path) -> Option<GlobalId> {
+        let ns = self
+            .path_resolver
+            .resolve(self.def_maps, path.clone())
+            .ok()?;
+        ns.take_values()?.as_global()
+    }
+
+    /// Evaluates an expression whose value must be known at compile time,
+    /// such as an array length or the value of a global.
+    ///
+    /// Errors are not logged, so that the caller can decide how to report them.
+    fn evaluate_const_expression(
+        &mut self,
+        expr: &Expression,
+    ) -> Result<FieldElement, ResolverError> {
+        match &expr.kind {
+            ExpressionKind::Literal(Literal::Integer(value)) => Ok(*value),
+            ExpressionKind::Ident(name) => {
+                let ident: Ident = Spanned::from(expr.span, name.clone()).into();
+                self.evaluate_const_name(&Path {
+                    segments: vec![ident],
+                    kind: PathKind::Plain,
+                })
+            }
+            ExpressionKind::Path(path) => self.evaluate_const_name(path),
+            ExpressionKind::Infix(infix) => {
+                let lhs = self.evaluate_const_expression(&infix.lhs)?;
+                let rhs = self.evaluate_const_expression(&infix.rhs)?;
+
+                // Like the rest of the language, constants are field elements,
+                // so division is field division
+                match infix.operator.contents {
+                    BinaryOpKind::Add => Ok(lhs + rhs),
+                    BinaryOpKind::Subtract => Ok(lhs - rhs),
+                    BinaryOpKind::Multiply => Ok(lhs * rhs),
+                    BinaryOpKind::Divide if rhs.is_zero() => Err(ResolverError::DivisionByZero {
+                        span: infix.rhs.span,
+                    }),
+                    BinaryOpKind::Divide => Ok(lhs / rhs),
+                    _ => Err(ResolverError::NotConstant { span: expr.span }),
+                }
+            }
+            _ => Err(ResolverError::NotConstant { span: expr.span }),
+        }
+    }
+
+    // Evaluates a name in a constant expression.
+    // Local constants shadow globals with the same name.
+    fn evaluate_const_name(&mut self, path: &Path) -> Result<FieldElement, ResolverError> {
+        if let Some(name) = path.as_ident() {
+            if let Some(meta) = self.scopes.current_scope_tree().find(&name.0.contents) {
+                meta.num_times_used += 1;
+                return meta.value.ok_or(ResolverError::NotConstant {
+                    span: name.0.span(),
+                });
+            }
+            if let Some(length) = self.length_bindings.get(name) {
+                return Ok(FieldElement::from(*length as i128));
+            }
+        }
+
+        match self.lookup_global(path) {
+            // A global without a value is either being evaluated or could not be evaluated
+            Some(global_id) => self.interner.global_value(&global_id).ok_or_else(|| {
+                ResolverError::UnevaluatedGlobal {
+                    name: path.as_string(),
+                    span: path.span(),
+                }
+            }),
+            None => Err(ResolverError::VariableNotDeclared {
+                name: path.as_string(),
+                span: path.span(),
+            }),
+        }
+    }
+
+    // Evaluates the length of an array, logging an error if it is not a valid length
+    fn evaluate_array_length(&mut self, length: &Expression) -> Option<u128> {
+        match self.evaluate_const_expression(length) {
+            Ok(value) if value.fits_in_u128() => Some(value.to_u128()),
+            Ok(_) => {
+                self.push_err(ResolverError::InvalidArrayLength { span: length.span });
+                None
+            }
+            Err(err) => {
+                self.push_err(err);
+                None
+            }
+        }
+    }
+
+    // Evaluates the bounds of a slice, logging an error if they do not form a valid range.
+    // An invalid range is replaced with an empty one, so that resolution can continue
+    fn evaluate_slice_range(&mut self, start: &Expression, end: &Expression) -> (u128, u128) {
+        let start_value = self.evaluate_const_expression(start);
+        let end_value = self.evaluate_const_expression(end);
+
+        match (start_value, end_value) {
+            (Ok(start_value), Ok(end_value))
+                if start_value.fits_in_u128()
+                    && end_value.fits_in_u128()
+                    && start_value.to_u128() <= end_value.to_u128() =>
+            {
+                (start_value.to_u128(), end_value.to_u128())
+            }
+            (Ok(_), Ok(_)) => {
+                let span = start.span.merge(end.span);
+                self.push_err(ResolverError::InvalidSliceRange { span });
+                (0, 0)
+            }
+            (start_value, end_value) => {
+                for err in start_value.err().into_iter().chain(end_value.err()) {
+                    self.push_err(err);
+                }
+                (0, 0)
+            }
+        }
+    }
+
+    // Range bounds which are constant expressions are folded into a literal.
+    // Other bounds, such as generics, are resolved as usual and are evaluated
+    // when the function is evaluated.
+    fn resolve_range_bound(&mut self, bound: Expression) -> ExprId {
+        match self.evaluate_const_expression(&bound) {
+            Ok(value) => {
+                let literal = HirExpression::Literal(HirLiteral::Integer(value));
+                let expr_id = self.interner.push_expr(literal);
+                self.interner.push_expr_span(expr_id, bound.span);
+                expr_id
+            }
+            Err(err @ ResolverError::DivisionByZero { .. }) => {
+                self.push_err(err);
+                self.resolve_expression(bound)
+            }
+            Err(_) => self.resolve_expression(bound),
+        }
+    }
+
+    // Finds the variable being assigned to, logging an error
+    // if it was not declared as mutable
+    fn find_mutable_variable(&mut self, name: &Ident) -> IdentId {
+        let scope_tree = self.scopes.current_scope_tree();
+        let mutable = scope_tree.find(&name.0.contents).map(|meta| meta.mutable);
+
+        if mutable == Some(false) {
+            self.push_err(ResolverError::AssignToImmutable {
+                name: name.0.contents.clone(),
+                span: name.0.span(),
+            });
+        }
+
+        self.find_variable(name)
+    }
+
+    fn resolve_lvalue(&mut self, lvalue: LValue) -> HirLValue {
+        match lvalue {
+            LValue::Ident(name) => HirLValue::Ident(self.find_mutable_variable(&name)),
+            LValue::Index { array, index } => HirLValue::Index {
+                array: Box::new(self.resolve_lvalue(*array)),
+                index: self.resolve_expression(index),
+            },
+        }
+    }
+
     pub fn intern_function(&mut self, func: NoirFunction) -> (HirFunction, FuncMeta) {
-        let func_meta = self.extract_meta(&func);
+        let mut func_meta = self.extract_meta(&func);
 
         let hir_func = match func.kind {
             FunctionKind::Builtin | FunctionKind::LowLevel => HirFunction::empty(),
@@ -203,6 +939,7 @@ impl<'a> Resolver<'a> {
                 HirFunction::unsafe_from_expr(expr_id)
             }
         };
+        func_meta.return_values = std::mem::take(&mut self.return_values);
 
         (hir_func, func_meta)
     }
@@ -213,45 +950,136 @@ impl<'a> Resolver<'a> {
         let name = func.name().to_owned();
         let attributes = func.attribute().cloned();
 
+        // Generics which are bound are replaced with their type or length
+        self.generics = func
+            .generics()
+            .iter()
+            .filter(|generic| {
+                !self.type_bindings.contains_key(generic)
+                    && !self.length_bindings.contains_key(generic)
+            })
+            .cloned()
+            .collect();
+
         let mut parameters = Vec::new();
         for (ident, typ) in func.parameters().to_owned() {
-            let ident_id = self.add_variable_decl(ident.clone());
+            let ident_id = self.add_variable_decl(ident.clone(), false);
 
-            parameters.push(Param(ident_id, typ));
+            parameters.push(Param(ident_id, self.resolve_type(typ)));
         }
 
-        let return_type = func.return_type();
+        // Each generic must be the length of an array parameter or be used in the type of a
+        // parameter, so that it can be inferred.
+        let mut generics = Vec::new();
+        let mut type_generics = Vec::new();
+        for generic in self.generics.clone() {
+            if parameters
+                .iter()
+                .any(|param| uses_type_generic(&param.1, &generic))
+            {
+                type_generics.push(generic);
+                continue;
+            }
+
+            // Generics are declared as variables, so that their value can be used in the function body
+            let ident_id = self.add_variable_decl(generic.clone(), false);
+
+            // A generic is used by the parameters it is inferred from, so it is never unused
+            let scope = self.scopes.get_mut_scope();
+            if let Some(generic_meta) = scope.find(&generic.0.contents) {
+                generic_meta.num_times_used += 1;
+            }
+
+            let is_inferred = parameters.iter().any(|param| match &param.1 {
+                Type::Array(_, ArraySize::Generic(name), _) => name == &generic,
+                _ => false,
+            });
+            if !is_inferred {
+                self.push_err(ResolverError::UninferredGeneric { generic });
+            }
+
+            generics.push(ident_id);
+        }
+
+        // Trait bounds on generics which are bound have already been checked at the call site,
+        // but they are kept to restrict which methods can be called on the generic
+        self.trait_bounds = Vec::new();
+        for (generic, trait_path) in func.def.trait_bounds.iter().cloned() {
+            if !self.type_bindings.contains_key(&generic) && !type_generics.contains(&generic) {
+                self.push_err(ResolverError::InvalidTraitBound { generic });
+                continue;
+            }
+            if let Some(trait_id) = self.lookup_trait(trait_path) {
+                self.trait_bounds.push((generic, trait_id));
+            }
+        }
+
+        let trait_bounds = self
+            .trait_bounds
+            .iter()
+            .filter(|(generic, _)| !self.type_bindings.contains_key(generic))
+            .cloned()
+            .collect();
+
+        let return_type = self.resolve_type(func.return_type());
 
         let func_meta = FuncMeta {
             name,
             kind: func.kind,
             attributes,
+            generics,
+            type_generics,
+            trait_bounds,
             parameters: parameters.into(),
             return_type,
+            return_values: Vec::new(),
             has_body: func.def.body.len() > 0,
         };
         func_meta
     }
 
+    // Declares each identifier in the pattern
+    fn resolve_pattern(&mut self, pattern: Pattern, mutable: bool) -> HirPattern {
+        match pattern {
+            Pattern::Identifier(name) => {
+                HirPattern::Identifier(self.add_variable_decl(name, mutable))
+            }
+            Pattern::Tuple(patterns, span) => {
+                let patterns = patterns
+                    .into_iter()
+                    .map(|pattern| self.resolve_pattern(pattern, mutable))
+                    .collect();
+                HirPattern::Tuple(patterns, span)
+            }
+        }
+    }
+
     pub fn intern_stmt(&mut self, stmt: Statement) -> StmtId {
         match stmt {
             Statement::Let(let_stmt) => {
-                let id = self.add_variable_decl(let_stmt.identifier);
+                let pattern = self.resolve_pattern(let_stmt.pattern, let_stmt.mutable);
 
                 let let_stmt = HirLetStatement {
-                    identifier: id,
-                    r#type: let_stmt.r#type,
+                    pattern,
+                    r#type: self.resolve_type(let_stmt.r#type),
                     expression: self.intern_expr(let_stmt.expression),
+                    mutable: let_stmt.mutable,
                 };
 
                 self.interner.push_stmt(HirStatement::Let(let_stmt))
             }
             Statement::Const(const_stmt) => {
-                let id = self.add_variable_decl(const_stmt.identifier);
+                // The value is recorded, so that the constant can be used in array lengths
+                let value = self.evaluate_const_expression(&const_stmt.expression).ok();
+                let name = const_stmt.identifier.0.contents.clone();
+                let id = self.add_variable_decl(const_stmt.identifier, false);
+                if let Some(meta) = self.scopes.get_mut_scope().find(&name) {
+                    meta.value = value;
+                }
 
                 let const_stmt = HirConstStatement {
                     identifier: id,
-                    r#type: const_stmt.r#type,
+                    r#type: self.resolve_type(const_stmt.r#type),
                     expression: self.intern_expr(const_stmt.expression),
                 };
 
@@ -267,15 +1095,21 @@ impl<'a> Resolver<'a> {
                 self.interner.push_stmt(HirStatement::Constrain(stmt))
             }
             Statement::Private(priv_stmt) => {
-                let identifier = self.add_variable_decl(priv_stmt.identifier);
+                let identifier = self.add_variable_decl(priv_stmt.identifier, false);
                 let expression = self.resolve_expression(priv_stmt.expression);
                 let stmt = HirPrivateStatement {
                     identifier,
                     expression,
-                    r#type: priv_stmt.r#type,
+                    r#type: self.resolve_type(priv_stmt.r#type),
                 };
                 self.interner.push_stmt(HirStatement::Private(stmt))
             }
+            Statement::Assign(assign_stmt) => {
+                let expression = self.resolve_expression(assign_stmt.expression);
+                let lvalue = self.resolve_lvalue(assign_stmt.lvalue);
+                let stmt = HirAssignStatement { lvalue, expression };
+                self.interner.push_stmt(HirStatement::Assign(stmt))
+            }
             Statement::Expression(expr) => {
                 let stmt = HirStatement::Expression(self.resolve_expression(expr));
                 self.interner.push_stmt(stmt)
@@ -284,6 +1118,11 @@ impl<'a> Resolver<'a> {
                 let stmt = HirStatement::Semi(self.resolve_expression(expr));
                 self.interner.push_stmt(stmt)
             }
+            Statement::Return(expr) => {
+                let expr_id = self.resolve_expression(expr);
+                self.return_values.push(expr_id);
+                self.interner.push_stmt(HirStatement::Return(expr_id))
+            }
         }
     }
 
@@ -292,8 +1131,10 @@ impl<'a> Resolver<'a> {
             ExpressionKind::Ident(string) => {
                 let span = expr.span;
                 let ident: Ident = Spanned::from(span, string).into();
-                let ident_id = self.find_variable(&ident);
-                self.interner.push_expr(HirExpression::Ident(ident_id))
+                self.resolve_name(Path {
+                    segments: vec![ident],
+                    kind: PathKind::Plain,
+                })
             }
             ExpressionKind::Literal(literal) => {
                 let literal = match literal {
@@ -305,10 +1146,16 @@ impl<'a> Resolver<'a> {
                         }
                         HirLiteral::Array(HirArrayLiteral {
                             contents: interned_contents,
-                            r#type: arr.r#type,
+                            r#type: self.resolve_type(arr.r#type),
                             length: arr.length,
                         })
                     }
+                    Literal::RepeatedArray(arr) => {
+                        let element = self.resolve_expression(*arr.element);
+                        // An invalid length has already been reported
+                        let length = self.evaluate_array_length(&arr.length).unwrap_or(0);
+                        HirLiteral::RepeatedArray(HirRepeatedArrayLiteral { element, length })
+                    }
                     Literal::Integer(integer) => HirLiteral::Integer(integer),
                     Literal::Str(str) => HirLiteral::Str(str),
                 };
@@ -321,55 +1168,19 @@ impl<'a> Resolver<'a> {
                 let expr = HirPrefixExpression { rhs, operator };
                 self.interner.push_expr(HirExpression::Prefix(expr))
             }
-            ExpressionKind::Infix(infix) | ExpressionKind::Predicate(infix) => {
-                let lhs = self.intern_expr(infix.lhs);
-                let rhs = self.intern_expr(infix.rhs);
-                let expr = HirInfixExpression {
-                    lhs,
-                    operator: infix.operator.into(),
-                    rhs,
-                };
+            ExpressionKind::Infix(infix) => {
+                let expr = self.resolve_infix(*infix);
                 self.interner.push_expr(HirExpression::Infix(expr))
             }
+            ExpressionKind::Predicate(infix) => {
+                let expr = self.resolve_infix(*infix);
+                self.interner.push_expr(HirExpression::Predicate(expr))
+            }
             ExpressionKind::Call(call_expr) => {
-                // Get the span and name of path for error reporting
-                let span = call_expr.func_name.span();
-                let func_name = call_expr.func_name.as_string();
-
-                let mod_def_id = match self
-                    .path_resolver
-                    .resolve(self.def_maps, call_expr.func_name)
-                {
-                    Ok(mod_def_id) => mod_def_id,
-                    Err(segment) => {
-                        let err = ResolverError::PathUnresolved {
-                            span,
-                            name: func_name,
-                            segment,
-                        };
-                        self.push_err(err);
-                        None
-                    }
-                };
-
-                let func_id = match mod_def_id {
-                    // Could not resolve this symbol, the error is already logged, return a dummy function id
-                    None => FuncId::dummy_id(),
-                    Some(def_id) => {
-                        // A symbol was found. Check if it is a function
-                        if let Some(func_id) = def_id.as_function() {
-                            func_id
-                        } else {
-                            let err = ResolverError::Expected {
-                                expected: "function".to_owned(),
-                                got: def_id.as_str().to_owned(),
-                                span: span,
-                            };
-                            self.push_err(err);
-                            FuncId::dummy_id()
-                        }
-                    }
-                };
+                // Could not resolve this symbol, the error is already logged, return a dummy function id
+                let func_id = self
+                    .lookup_function(call_expr.func_name)
+                    .unwrap_or_else(FuncId::dummy_id);
 
                 let mut arguments = Vec::with_capacity(call_expr.arguments.len());
                 for arg in call_expr.arguments {
@@ -383,18 +1194,18 @@ impl<'a> Resolver<'a> {
                 let lhs = self.resolve_expression(cast_expr.lhs);
                 let expr = HirCastExpression {
                     lhs,
-                    r#type: cast_expr.r#type,
+                    r#type: self.resolve_type(cast_expr.r#type),
                 };
 
                 self.interner.push_expr(HirExpression::Cast(expr))
             }
             ExpressionKind::For(for_expr) => {
-                let start_range = self.resolve_expression(for_expr.start_range);
-                let end_range = self.resolve_expression(for_expr.end_range);
+                let start_range = self.resolve_range_bound(for_expr.start_range);
+                let end_range = self.resolve_range_bound(for_expr.end_range);
 
                 self.scopes.start_for_loop();
 
-                let identifier = self.add_variable_decl(for_expr.identifier);
+                let identifier = self.add_variable_decl(for_expr.identifier, false);
 
                 let block_id = self.resolve_block(for_expr.block);
                 let for_scope = self.scopes.end_for_loop();
@@ -409,31 +1220,127 @@ impl<'a> Resolver<'a> {
                 };
                 self.interner.push_expr(HirExpression::For(expr))
             }
-            ExpressionKind::If(_) => todo!("If statements are currently not supported"),
+            ExpressionKind::While(while_expr) => {
+                let condition = self.resolve_expression(while_expr.condition);
+
+                // The body of a while loop is scoped in the same way as a for loop
+                self.scopes.start_for_loop();
+                let block = self.resolve_block(while_expr.block);
+                let while_scope = self.scopes.end_for_loop();
+
+                self.check_for_unused_variables_in_scope_tree(while_scope.into());
+
+                let expr = HirWhileExpression { condition, block };
+                self.interner.push_expr(HirExpression::While(expr))
+            }
+            ExpressionKind::If(if_expr) => {
+                let condition = self.resolve_expression(if_expr.condition);
+                let consequence = self.resolve_if_branch(if_expr.consequence);
+                let alternative = if_expr
+                    .alternative
+                    .map(|alternative| self.resolve_if_branch(alternative));
+
+                let expr = HirIfExpression {
+                    condition,
+                    consequence,
+                    alternative,
+                };
+                self.interner.push_expr(HirExpression::If(expr))
+            }
+            ExpressionKind::Match(match_expr) => {
+                let expression = self.resolve_expression(match_expr.expression);
+                let arms = match_expr
+                    .arms
+                    .into_iter()
+                    .map(|(pattern, arm)| {
+                        let pattern = self.resolve_match_pattern(pattern);
+
+                        // Like the branches of an if expression, each arm has its own scope
+                        self.scopes.start_if_branch();
+                        let arm = self.resolve_expression(arm);
+                        let arm_scope = self.scopes.end_if_branch();
+                        self.check_for_unused_variables_in_scope_tree(arm_scope.into());
+
+                        (pattern, arm)
+                    })
+                    .collect();
+
+                let expr = HirMatchExpression { expression, arms };
+                self.interner.push_expr(HirExpression::Match(expr))
+            }
             ExpressionKind::Index(indexed_expr) => {
-                let collection_name = self.find_variable(&indexed_expr.collection_name);
+                let collection = self.resolve_expression(indexed_expr.collection);
                 let index = self.resolve_expression(indexed_expr.index);
-                let expr = HirIndexExpression {
-                    collection_name,
-                    index,
-                };
+                let expr = HirIndexExpression { collection, index };
                 self.interner.push_expr(HirExpression::Index(expr))
             }
-            ExpressionKind::Path(path) => {
-                // If the Path is being used as an Expression, then it is referring to an Identifier
-                //
-                // This is currently not supported : const x = foo::bar::SOME_CONST + 10;
-                let ident_id = match path.as_ident() {
+            ExpressionKind::Slice(slice_expr) => {
+                let collection = self.resolve_expression(slice_expr.collection);
+                let (start, end) = self.evaluate_slice_range(&slice_expr.start, &slice_expr.end);
+                let expr = HirSliceExpression {
+                    collection,
+                    start,
+                    end,
+                };
+                self.interner.push_expr(HirExpression::Slice(expr))
+            }
+            ExpressionKind::Constructor(constructor) => {
+                let span = constructor.type_name.span();
+
+                match self.lookup_struct(constructor.type_name) {
+                    Some(struct_id) => {
+                        let r#type = self.interner.get_struct(struct_id);
+                        let fields = self.resolve_constructor_fields(
+                            constructor.fields,
+                            &r#type.borrow(),
+                            span,
+                        );
+                        let expr = HirConstructorExpression { r#type, fields };
+                        self.interner.push_expr(HirExpression::Constructor(expr))
+                    }
                     None => {
-                        self.push_err(ResolverError::PathIsNotIdent { span: path.span() });
-
-                        IdentId::dummy_id()
+                        // The error is already logged. We still resolve the fields
+                        // so that errors inside of them are reported
+                        for (_, field) in constructor.fields {
+                            self.resolve_expression(field);
+                        }
+                        self.interner.push_expr(HirExpression::empty_block())
                     }
-                    Some(identifier) => self.find_variable(identifier),
+                }
+            }
+            ExpressionKind::Tuple(elements) => {
+                let elements = elements
+                    .into_iter()
+                    .map(|elem| self.resolve_expression(elem))
+                    .collect();
+                self.interner.push_expr(HirExpression::Tuple(elements))
+            }
+            ExpressionKind::MemberAccess(access) => {
+                // Validating whether the lhs actually has the rhs as a field
+                // needs to wait until type checking when we know the type of the lhs
+                let expr = HirMemberAccess {
+                    lhs: self.resolve_expression(access.lhs),
+                    rhs: access.rhs,
                 };
-
-                self.interner.push_expr(HirExpression::Ident(ident_id))
+                self.interner.push_expr(HirExpression::MemberAccess(expr))
+            }
+            ExpressionKind::MethodCall(method_call) => {
+                // The method can only be found once the type of the object is known,
+                // so the type checker replaces this with a call to the method
+                let object = self.resolve_expression(method_call.object);
+                let arguments = method_call
+                    .arguments
+                    .into_iter()
+                    .map(|arg| self.resolve_expression(arg))
+                    .collect();
+                let expr = HirMethodCallExpression {
+                    object,
+                    method: method_call.method_name,
+                    arguments,
+                };
+                self.interner.push_expr(HirExpression::MethodCall(expr))
             }
+            ExpressionKind::Path(path) => self.resolve_name(path),
             ExpressionKind::Block(block_expr) => self.resolve_block(block_expr),
         };
 
@@ -441,6 +1348,72 @@ impl<'a> Resolver<'a> {
         expr_id
     }
 
+    /// Resolves the fields of a constructor, checking that each field
+    /// of the struct is given exactly once.
+    ///
+    /// The fields are kept in the order they were written, so that
+    /// they are evaluated in that order.
+    fn resolve_constructor_fields(
+        &mut self,
+        fields: Vec<(Ident, Expression)>,
+        struct_type: &crate::hir_def::types::StructType,
+        span: noirc_errors::Span,
+    ) -> Vec<(Ident, ExprId)> {
+        let mut resolved_fields: Vec<(Ident, ExprId)> = Vec::with_capacity(fields.len());
+
+        for (field_name, field) in fields {
+            let expr_id = self.resolve_expression(field);
+
+            if struct_type.field(&field_name).is_none() {
+                self.push_err(ResolverError::NoSuchField {
+                    field: field_name,
+                    struct_definition: struct_type.name.clone(),
+                });
+            } else if resolved_fields.iter().any(|(name, _)| name == &field_name) {
+                self.push_err(ResolverError::DuplicateField { field: field_name });
+            } else {
+                resolved_fields.push((field_name, expr_id));
+            }
+        }
+
+        let missing_fields: Vec<String> = struct_type
+            .fields
+            .iter()
+            .filter(|(name, _)| !resolved_fields.iter().any(|(field, _)| field == name))
+            .map(|(name, _)| name.0.contents.clone())
+            .collect();
+
+        if !missing_fields.is_empty() {
+            self.push_err(ResolverError::MissingFields {
+                span,
+                missing_fields,
+                struct_definition: struct_type.name.clone(),
+            });
+        }
+
+        resolved_fields
+    }
+
+    fn resolve_infix(&mut self, infix: InfixExpression) -> HirInfixExpression {
+        let lhs = self.intern_expr(infix.lhs);
+        let rhs = self.intern_expr(infix.rhs);
+        HirInfixExpression {
+            lhs,
+            operator: infix.operator.into(),
+            rhs,
+        }
+    }
+
+    /// Resolves a branch of an if expression in it's own scope
+    fn resolve_if_branch(&mut self, block_expr: BlockExpression) -> ExprId {
+        self.scopes.start_if_branch();
+        let block_id = self.resolve_block(block_expr);
+        let branch_scope = self.scopes.end_if_branch();
+
+        self.check_for_unused_variables_in_scope_tree(branch_scope.into());
+        block_id
+    }
+
     fn resolve_block(&mut self, block_expr: BlockExpression) -> ExprId {
         let stmts: Vec<_> = block_expr
             .0
@@ -454,6 +1427,16 @@ impl<'a> Resolver<'a> {
     }
 }
 
+/// Returns true if the type generic `name` is used in `typ`
+fn uses_type_generic(typ: &Type, name: &Ident) -> bool {
+    match typ {
+        Type::Generic(generic) => generic == name,
+        Type::Array(_, _, elem_type) => uses_type_generic(elem_type, name),
+        Type::Tuple(elements) => elements.iter().any(|elem| uses_type_generic(elem, name)),
+        _ => false,
+    }
+}
+
 // XXX: These tests repeat a lot of code
 // what we should do is have test cases which are passed to a test harness
 // A test harness will allow for more expressive and readable tests
@@ -462,14 +1445,15 @@ mod test {
 
     use std::collections::HashMap;
 
-    use crate::{hir::resolution::errors::ResolverError, Ident};
+    use crate::hir::resolution::errors::ResolverError;
 
     use crate::graph::CrateId;
     use crate::hir_def::function::HirFunction;
     use crate::node_interner::{FuncId, NodeInterner};
     use crate::{
-        hir::def_map::{CrateDefMap, ModuleDefId},
-        Parser, Path,
+        hir::def_map::{CrateDefMap, ModuleDefId, ModuleId, PerNs},
+        hir::resolution::import::{PathResolution, PathResolutionError},
+        Ident, Parser, Path,
     };
 
     use super::{PathResolver, Resolver};
@@ -554,6 +1538,29 @@ mod test {
         }
     }
     #[test]
+    fn resolve_assign_to_immutable() {
+        let src = r#"
+            fn main(x : Field) {
+                let mut y = x;
+                y = y + x;
+                let z = x;
+                z = y;
+                constrain z == x;
+            }
+        "#;
+
+        let (_, mut errors) = resolve_src_code(src, vec![String::from("main")]);
+
+        // There should only be one error
+        assert!(errors.len() == 1);
+        let err = errors.pop().unwrap();
+        // It should be regarding the assignment to `z`
+        match err {
+            ResolverError::AssignToImmutable { name, .. } => assert_eq!(name, "z"),
+            _ => unimplemented!("we should only have an assign to immutable error"),
+        }
+    }
+    #[test]
     fn resolve_unresolved_var() {
         let src = r#"
             fn main(x : Field) {
@@ -659,6 +1666,36 @@ mod test {
         assert!(errors.is_empty());
     }
     #[test]
+    fn resolve_if_expr() {
+        let src = r#"
+            fn main(x : Field, y : Field) {
+                if x {
+                    priv _z = x + y;
+                } else {
+                    priv _z = x - y;
+                };
+            }
+        "#;
+
+        let (_, errors) = resolve_src_code(src, vec![String::from("main")]);
+        assert!(errors.is_empty());
+    }
+    #[test]
+    fn resolve_if_branch_scope() {
+        let src = r#"
+            fn main(x : Field) {
+                if x {
+                    priv z = x;
+                    constrain z == x;
+                };
+                constrain z == x;
+            }
+        "#;
+
+        let (_, errors) = resolve_src_code(src, vec![String::from("main")]);
+        assert!(errors.len() == 1);
+    }
+    #[test]
     fn resolve_call_expr() {
         let src = r#"
             fn main(x : Field) {
@@ -690,19 +1727,26 @@ mod test {
     struct TestPathResolver(HashMap<String, ModuleDefId>);
 
     impl PathResolver for TestPathResolver {
-        fn resolve(
-            &self,
-            _def_maps: &HashMap<CrateId, CrateDefMap>,
-            path: Path,
-        ) -> Result<Option<ModuleDefId>, Ident> {
+        fn resolve(&self, _def_maps: &HashMap<CrateId, CrateDefMap>, path: Path) -> PathResolution {
             // Not here that foo::bar and hello::foo::bar would fetch the same thing
             let name = path.segments.last().unwrap();
             let mod_def = self.0.get(&name.0.contents).cloned();
             match mod_def {
-                None => Err(name.clone()),
-                Some(_) => Ok(mod_def),
+                None => Err(PathResolutionError::Unresolved(name.clone())),
+                Some(def) => Ok(PerNs::values(def)),
             }
         }
+
+        // Every item is declared in the same module
+        fn check_private_method(
+            &self,
+            _def_maps: &HashMap<CrateId, CrateDefMap>,
+            _method: &Ident,
+            _definition: &Ident,
+            _module: ModuleId,
+        ) -> Result<(), PathResolutionError> {
+            Ok(())
+        }
     }
 
     impl TestPathResolver {
@@ -155,6 +155,16 @@ impl<K: std::hash::Hash + Eq + Clone, V> ScopeForest<K, V> {
     pub fn end_for_loop(&mut self) -> Scope<K, V> {
         self.remove_scope_tree_extension()
     }
+
+    /// Each branch of an if expression has access to the outside scope, like a for loop.
+    /// Variables declared inside of a branch are not visible once the branch has ended
+    pub fn start_if_branch(&mut self) {
+        self.extend_current_scope_tree()
+    }
+    /// Ending an if branch requires removal of it's scope from the current scope tree
+    pub fn end_if_branch(&mut self) -> Scope<K, V> {
+        self.remove_scope_tree_extension()
+    }
 }
 
 // ScopeForest is another layer of abstraction which will handle scoping for functions
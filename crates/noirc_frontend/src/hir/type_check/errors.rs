@@ -1,9 +1,10 @@
+use fm::FileId;
 use noirc_errors::CustomDiagnostic as Diagnostic;
 pub use noirc_errors::Span;
 use thiserror::Error;
 
 use crate::node_interner::NodeInterner;
-use crate::{hir_def::expr::HirBinaryOp, Type};
+use crate::{hir_def::expr::HirBinaryOp, Ident, Type};
 
 #[derive(Error, Debug, Clone)]
 pub enum TypeCheckError {
@@ -31,8 +32,32 @@ pub enum TypeCheckError {
         found: u16,
         span: Span,
     },
+    #[error("type {typ:?} does not implement the trait {trait_name:?}")]
+    UnsatisfiedTraitBound {
+        typ: Type,
+        trait_name: String,
+        generic: String,
+        span: Span,
+    },
+    #[error("match on enum {enum_name:?} does not cover the variants {missing_variants:?}")]
+    NonExhaustiveMatch {
+        enum_name: String,
+        missing_variants: Vec<String>,
+        span: Span,
+    },
+    #[error("unreachable match arm")]
+    UnreachableMatchArm { span: Span },
+    #[error("slice end {end:?} is out of bounds for an array of length {length:?}")]
+    SliceOutOfBounds { end: u128, length: u128, span: Span },
     #[error("return type in a function cannot be public")]
     PublicReturnType { typ: Type, span: Span },
+    // The definition of the method may be in another file than the call
+    #[error("method {method:?} is private")]
+    PrivateMethod {
+        method: Ident,
+        definition: Ident,
+        file_id: FileId,
+    },
     // XXX: unstructured errors are not ideal for testing.
     // They will be removed in a later iteration
     #[error("unstructured msg: {msg:?}")]
@@ -47,6 +72,18 @@ pub enum TypeCheckError {
         err: Box<TypeCheckError>,
         ctx: &'static str,
     },
+    // Generic functions are type checked once for each set of types they are instantiated with
+    #[error("error in an instantiation of a generic function")]
+    Instantiation {
+        err: Box<TypeCheckError>,
+        instantiation: String,
+    },
+    #[error("instantiation depth limit reached")]
+    InstantiationDepth {
+        instantiation: String,
+        limit: usize,
+        span: Span,
+    },
     #[error("Array is not homogenous")]
     NonHomogenousArray {
         first_span: Span,
@@ -83,6 +120,28 @@ impl TypeCheckError {
                 diag.add_note(ctx.to_owned());
                 vec![diag]
             }
+            TypeCheckError::Instantiation { err, instantiation } => {
+                let mut diags = err.into_diagnostics(interner);
+                for diag in diags.iter_mut() {
+                    diag.add_note(format!("in the instantiation `{}`", instantiation));
+                }
+                diags
+            }
+            TypeCheckError::InstantiationDepth {
+                instantiation,
+                limit,
+                span,
+            } => {
+                let mut diag = Diagnostic::simple_error(
+                    format!("reached the limit of {} nested instantiations", limit),
+                    format!("while instantiating `{}`", instantiation),
+                    span,
+                );
+                diag.add_note(format!(
+                    "a generic function which calls itself with a different type each time is instantiated forever"
+                ));
+                vec![diag]
+            }
             TypeCheckError::OpCannotBeUsed { op, place, span } => {
                 vec![Diagnostic::simple_error(
                     format!("the operator {:?} cannot be used in a {}", op, place),
@@ -120,6 +179,16 @@ impl TypeCheckError {
                 diag.add_secondary(format!("but then found type {}", second_type), second_span);
                 vec![diag]
             }
+            TypeCheckError::SliceOutOfBounds { end, length, span } => {
+                vec![Diagnostic::simple_error(
+                    format!("slice out of bounds"),
+                    format!(
+                        "the slice ends at {} but the array has length {}",
+                        end, length
+                    ),
+                    span,
+                )]
+            }
             TypeCheckError::ArityMisMatch {
                 expected,
                 found,
@@ -131,6 +200,55 @@ impl TypeCheckError {
                     span,
                 )]
             }
+            TypeCheckError::UnsatisfiedTraitBound {
+                typ,
+                trait_name,
+                generic,
+                span,
+            } => {
+                vec![Diagnostic::simple_error(
+                    format!(
+                        "the trait {} is not implemented for type {}",
+                        trait_name, typ
+                    ),
+                    format!("required by the bound `{}: {}`", generic, trait_name),
+                    span,
+                )]
+            }
+            TypeCheckError::NonExhaustiveMatch {
+                enum_name,
+                missing_variants,
+                span,
+            } => {
+                let plural = if missing_variants.len() != 1 { "s" } else { "" };
+                let missing_variants: Vec<_> = missing_variants
+                    .iter()
+                    .map(|variant| format!("`{}::{}`", enum_name, variant))
+                    .collect();
+
+                let mut diag = Diagnostic::simple_error(
+                    format!("non-exhaustive match on enum {}", enum_name),
+                    format!(
+                        "variant{} {} not covered",
+                        plural,
+                        missing_variants.join(", ")
+                    ),
+                    span,
+                );
+                diag.add_note(format!(
+                    "add an arm for each missing variant, or a wildcard arm `_ => ..`"
+                ));
+                vec![diag]
+            }
+            TypeCheckError::UnreachableMatchArm { span } => {
+                vec![Diagnostic::simple_error(
+                    format!("unreachable match arm"),
+                    format!(
+                        "the variants matched by this arm are already matched by a previous arm"
+                    ),
+                    span,
+                )]
+            }
             TypeCheckError::Unstructured { msg, span } => {
                 vec![Diagnostic::simple_error(msg, format!(""), span)]
             }
@@ -141,6 +259,28 @@ impl TypeCheckError {
                     span,
                 )]
             }
+            TypeCheckError::PrivateMethod {
+                method,
+                definition,
+                file_id,
+            } => {
+                let name = &method.0.contents;
+
+                let mut diag = Diagnostic::simple_error(
+                    format!("`{}` is private", name),
+                    format!("private method"),
+                    method.0.span(),
+                );
+                diag.add_secondary_in_file(
+                    format!("`{}` is defined here", name),
+                    definition.0.span(),
+                    file_id,
+                );
+                diag.add_note(format!(
+                    "methods can only be called outside of the module of their impl block if they are declared with `pub`"
+                ));
+                vec![diag]
+            }
         }
     }
 
@@ -151,7 +291,13 @@ impl TypeCheckError {
             | TypeCheckError::TypeMismatch { .. }
             | TypeCheckError::NonHomogenousArray { .. }
             | TypeCheckError::PublicReturnType { .. }
+            | TypeCheckError::PrivateMethod { .. }
             | TypeCheckError::ArityMisMatch { .. }
+            | TypeCheckError::UnsatisfiedTraitBound { .. }
+            | TypeCheckError::NonExhaustiveMatch { .. }
+            | TypeCheckError::UnreachableMatchArm { .. }
+            | TypeCheckError::SliceOutOfBounds { .. }
+            | TypeCheckError::InstantiationDepth { .. }
             | TypeCheckError::TypeCannotBeUsed { .. } => Some(TypeCheckError::Context {
                 err: Box::new(self),
                 ctx,
@@ -159,7 +305,7 @@ impl TypeCheckError {
             // Cannot apply a context to multiple diagnostics
             TypeCheckError::MultipleErrors(_) => None,
             // Cannot append or overwrite a context
-            TypeCheckError::Context { .. } => None,
+            TypeCheckError::Context { .. } | TypeCheckError::Instantiation { .. } => None,
         }
     }
 }
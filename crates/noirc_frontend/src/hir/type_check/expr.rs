@@ -1,17 +1,21 @@
 use crate::{
     hir_def::{
-        expr::{HirBinaryOp, HirExpression, HirLiteral},
+        expr::{
+            HirBinaryOp, HirBinaryOpKind, HirCallExpression, HirExpression, HirLiteral,
+            HirMatchExpression, HirMatchPattern, HirUnaryOp,
+        },
         function::Param,
         stmt::HirStatement,
     },
-    ArraySize, Type,
+    ArraySize, Ident, Signedness, Type,
 };
 use crate::{
-    node_interner::{ExprId, NodeInterner, StmtId},
+    node_interner::{ExprId, FuncId, NodeInterner, StmtId},
     FieldElementType,
 };
 
 use super::errors::TypeCheckError;
+use super::unify::{unify_param, GenericBindings};
 
 pub(crate) fn type_check_expression(
     interner: &mut NodeInterner,
@@ -92,20 +96,34 @@ pub(crate) fn type_check_expression(
 
                     interner.push_expr_type(expr_id, arr_type)
                 }
+                HirLiteral::RepeatedArray(arr) => {
+                    type_check_expression(interner, &arr.element)?;
+                    let element_type = interner.id_type(&arr.element);
+
+                    let arr_type = Type::Array(
+                        FieldElementType::Private,
+                        ArraySize::Fixed(arr.length),
+                        Box::new(element_type),
+                    );
+                    interner.push_expr_type(expr_id, arr_type)
+                }
                 HirLiteral::Bool(_) => {
-                    unimplemented!("currently native boolean types have not been implemented")
+                    interner.push_expr_type(expr_id, Type::Bool);
                 }
                 HirLiteral::Integer(_) => {
                     // Literal integers will always be a constant, since the lexer was able to parse the integer
                     interner.push_expr_type(expr_id, Type::FieldElement(FieldElementType::Constant));
                 }
-                HirLiteral::Str(_) => unimplemented!(
-                    "[Coming Soon] : Currently string literal types have not been implemented"
-                ),
+                HirLiteral::Str(string) => {
+                    // The length of a string is its number of bytes
+                    let length = ArraySize::Fixed(string.len() as u128);
+                    let string_type = Type::string(FieldElementType::Private, length);
+                    interner.push_expr_type(expr_id, string_type);
+                }
             }
         }
 
-        HirExpression::Infix(infix_expr) => {
+        HirExpression::Infix(infix_expr) | HirExpression::Predicate(infix_expr) => {
             // The type of the infix expression must be looked up from a type table
 
             type_check_expression(interner, &infix_expr.lhs)?;
@@ -128,16 +146,29 @@ pub(crate) fn type_check_expression(
             }
         }
         HirExpression::Index(index_expr) => {
-            let ident_def = interner
-                .ident_def(&index_expr.collection_name)
-                .expect("ice : all identifiers should have a def");
-            let collection_type = interner.id_type(&ident_def);
+            type_check_expression(interner, &index_expr.index)?;
+            let index_type = interner.id_type(&index_expr.index);
+            // Booleans are used as the index 0 or 1
+            if !index_type.is_base_type() && index_type != Type::Bool && index_type != Type::Error {
+                let span = interner.expr_span(&index_expr.index);
+                return Err(TypeCheckError::TypeCannotBeUsed {
+                    typ: index_type,
+                    place: "array index",
+                    span,
+                });
+            }
+
+            type_check_expression(interner, &index_expr.collection)?;
+            let collection_type = interner.id_type(&index_expr.collection);
             match collection_type {
                 // XXX: We can check the array bounds here also, but it may be better to constant fold first
                 // and have ConstId instead of ExprId for constants
+                //
+                // The base type of a multi-dimensional array is the type of its rows
                 Type::Array(_, _, base_type) => interner.push_expr_type(expr_id, *base_type),
+                Type::Error => interner.push_expr_type(expr_id, Type::Error),
                 typ => {
-                    let span = interner.id_span(&index_expr.collection_name);
+                    let span = interner.expr_span(&index_expr.collection);
                     return Err(TypeCheckError::TypeMismatch {
                         expected_typ: "Array".to_owned(),
                         expr_typ: typ.to_string(),
@@ -146,21 +177,119 @@ pub(crate) fn type_check_expression(
                 }
             };
         }
-        HirExpression::Call(call_expr) => {
-            let func_meta = interner.function_meta(&call_expr.func_id);
-
-            // Check function call arity is correct
-            let param_len = func_meta.parameters.len();
-            let arg_len = call_expr.arguments.len();
-            if param_len != arg_len {
-                let span = interner.expr_span(expr_id);
-                return Err(TypeCheckError::ArityMisMatch {
-                    expected: param_len as u16,
-                    found: arg_len as u16,
-                    span,
-                });
+        HirExpression::Slice(slice_expr) => {
+            type_check_expression(interner, &slice_expr.collection)?;
+            let collection_type = interner.id_type(&slice_expr.collection);
+            match collection_type {
+                Type::Array(fe_type, size, base_type) => {
+                    // The bounds of arrays whose length is not fixed are checked by the evaluator
+                    if let ArraySize::Fixed(length) = size {
+                        if slice_expr.end > length {
+                            return Err(TypeCheckError::SliceOutOfBounds {
+                                end: slice_expr.end,
+                                length,
+                                span: interner.expr_span(expr_id),
+                            });
+                        }
+                    }
+
+                    let length = ArraySize::Fixed(slice_expr.end - slice_expr.start);
+                    interner.push_expr_type(expr_id, Type::Array(fe_type, length, base_type))
+                }
+                Type::Error => interner.push_expr_type(expr_id, Type::Error),
+                typ => {
+                    let span = interner.expr_span(&slice_expr.collection);
+                    return Err(TypeCheckError::TypeMismatch {
+                        expected_typ: "Array".to_owned(),
+                        expr_typ: typ.to_string(),
+                        expr_span: span,
+                    });
+                }
+            };
+        }
+        HirExpression::Constructor(constructor) => {
+            // The resolver has already checked that each field is given exactly once
+            for (field_name, field_expr) in constructor.fields.iter() {
+                type_check_expression(interner, field_expr)?;
+                let arg_type = interner.id_type(field_expr);
+
+                let struct_type = constructor.r#type.borrow();
+                let (_, field_type) = struct_type
+                    .field(field_name)
+                    .expect("ice: constructor fields should have been checked in the resolver");
+
+                if !field_type.is_super_type_of(&arg_type) {
+                    let span = interner.expr_span(field_expr);
+                    return Err(TypeCheckError::TypeMismatch {
+                        expected_typ: field_type.to_string(),
+                        expr_typ: arg_type.to_string(),
+                        expr_span: span,
+                    });
+                }
             }
 
+            interner.push_expr_type(expr_id, Type::Struct(constructor.r#type));
+        }
+        HirExpression::Tuple(elements) => {
+            let mut element_types = Vec::with_capacity(elements.len());
+            for element in elements.iter() {
+                type_check_expression(interner, element)?;
+                element_types.push(interner.id_type(element));
+            }
+
+            interner.push_expr_type(expr_id, Type::Tuple(element_types));
+        }
+        HirExpression::MemberAccess(access) => {
+            type_check_expression(interner, &access.lhs)?;
+            let lhs_type = interner.id_type(&access.lhs);
+
+            let field_type = match &lhs_type {
+                Type::Tuple(elements) => {
+                    let index = access.rhs.0.contents.parse::<usize>().ok();
+                    match index.and_then(|index| elements.get(index)) {
+                        Some(element_type) => element_type.clone(),
+                        None => {
+                            return Err(TypeCheckError::Unstructured {
+                                msg: format!(
+                                    "no element {} in tuple {}, it has {} elements",
+                                    access.rhs.0.contents,
+                                    lhs_type,
+                                    elements.len()
+                                ),
+                                span: access.rhs.0.span(),
+                            });
+                        }
+                    }
+                }
+                Type::Struct(struct_type) => {
+                    let struct_type = struct_type.borrow();
+                    match struct_type.field(&access.rhs) {
+                        Some((_, field_type)) => field_type.clone(),
+                        None => {
+                            return Err(TypeCheckError::Unstructured {
+                                msg: format!(
+                                    "no field named {} in struct {}",
+                                    access.rhs.0.contents, struct_type.name.0.contents
+                                ),
+                                span: access.rhs.0.span(),
+                            });
+                        }
+                    }
+                }
+                Type::Error => Type::Error,
+                typ => {
+                    let span = interner.expr_span(&access.lhs);
+                    return Err(TypeCheckError::TypeMismatch {
+                        expected_typ: "struct or tuple".to_owned(),
+                        expr_typ: typ.to_string(),
+                        expr_span: span,
+                    });
+                }
+            };
+
+            interner.push_expr_type(expr_id, field_type);
+        }
+        HirExpression::Call(call_expr) => {
             // Type check arguments
             let mut arg_types = Vec::with_capacity(call_expr.arguments.len());
             for arg_expr in call_expr.arguments.iter() {
@@ -168,13 +297,35 @@ pub(crate) fn type_check_expression(
                 arg_types.push(interner.id_type(arg_expr))
             }
 
-            // Check for argument param equality
-            for (param, arg) in func_meta.parameters.iter().zip(arg_types) {
-                check_param_argument(interner, param, &arg)?
+            type_check_call(interner, expr_id, &call_expr, arg_types)?;
+        }
+        HirExpression::MethodCall(method_call) => {
+            type_check_expression(interner, &method_call.object)?;
+            let object_type = interner.id_type(&method_call.object);
+            if object_type == Type::Error {
+                interner.push_expr_type(expr_id, Type::Error);
+                return Ok(());
             }
 
-            // The type of the call expression is the return type of the function being called
-            interner.push_expr_type(expr_id, func_meta.return_type);
+            let func_id = lookup_method(interner, &object_type, &method_call.method)?;
+
+            // The object is passed to the method as its first argument
+            let mut arg_types = Vec::with_capacity(method_call.arguments.len() + 1);
+            arg_types.push(object_type);
+            for arg_expr in method_call.arguments.iter() {
+                type_check_expression(interner, arg_expr)?;
+                arg_types.push(interner.id_type(arg_expr))
+            }
+
+            let mut arguments = vec![method_call.object];
+            arguments.extend(method_call.arguments);
+            let call_expr = HirCallExpression { func_id, arguments };
+
+            // Now that the method is known, the method call is replaced with a call to it
+            // so that the evaluator and monomorphisation only need to handle calls
+            interner.update_expr(*expr_id, HirExpression::Call(call_expr.clone()));
+
+            type_check_call(interner, expr_id, &call_expr, arg_types)?;
         }
         HirExpression::Cast(cast_expr) => {
             // Evaluate the Lhs
@@ -243,6 +394,28 @@ pub(crate) fn type_check_expression(
                 ),
             );
         }
+        HirExpression::While(while_expr) => {
+            type_check_expression(interner, &while_expr.condition)?;
+            let condition_type = interner.id_type(&while_expr.condition);
+
+            // Whether the condition is known at compile time is only checked
+            // when the loop is unrolled, as mutable variables are given a witness type
+            match &condition_type {
+                Type::FieldElement(_) | Type::Integer(_, _, _) | Type::Bool | Type::Error => {}
+                _ => {
+                    let span = interner.expr_span(&while_expr.condition);
+                    return Err(TypeCheckError::TypeCannotBeUsed {
+                        typ: condition_type,
+                        place: "while condition",
+                        span,
+                    });
+                }
+            }
+
+            // The value of the loop body is discarded
+            type_check_expression(interner, &while_expr.block)?;
+            interner.push_expr_type(expr_id, Type::Unit);
+        }
         HirExpression::Block(block_expr) => {
             for stmt in block_expr.statements() {
                 super::stmt::type_check(interner, stmt)?
@@ -254,14 +427,95 @@ pub(crate) fn type_check_expression(
 
             interner.push_expr_type(expr_id, last_stmt_type)
         }
-        HirExpression::Prefix(_) => {
+        HirExpression::Prefix(prefix_expr) => {
+            type_check_expression(interner, &prefix_expr.rhs)?;
+            let rhs_type = interner.id_type(&prefix_expr.rhs);
+
+            let is_valid = match (prefix_expr.operator, &rhs_type) {
+                (_, Type::Error) => true,
+                // Unsigned integers cannot be negated
+                (HirUnaryOp::Minus, Type::FieldElement(_)) => true,
+                (HirUnaryOp::Minus, Type::Integer(_, Signedness::Signed, _)) => true,
+                // `!` is the bitwise not for integers and the logical not for booleans
+                (HirUnaryOp::Not, Type::Integer(_, _, _)) => true,
+                (HirUnaryOp::Not, Type::Bool) => true,
+                _ => false,
+            };
+            if !is_valid {
+                return Err(TypeCheckError::TypeCannotBeUsed {
+                    typ: rhs_type,
+                    place: "prefix expression",
+                    span: interner.expr_span(expr_id),
+                });
+            }
+
             // type_of(prefix_expr) == type_of(rhs_expression)
-            todo!("prefix expressions have not been implemented yet")
+            interner.push_expr_type(expr_id, rhs_type)
         }
-        HirExpression::Predicate(_) => {
-            todo!("predicate statements have not been implemented yet")
+        HirExpression::If(if_expr) => {
+            type_check_expression(interner, &if_expr.condition)?;
+            let condition_type = interner.id_type(&if_expr.condition);
+
+            match &condition_type {
+                Type::FieldElement(_) | Type::Integer(_, _, _) | Type::Bool | Type::Error => {}
+                _ => {
+                    let span = interner.expr_span(&if_expr.condition);
+                    return Err(TypeCheckError::TypeCannotBeUsed {
+                        typ: condition_type,
+                        place: "if condition",
+                        span,
+                    });
+                }
+            }
+
+            type_check_expression(interner, &if_expr.consequence)?;
+            let consequence_type = interner.id_type(&if_expr.consequence);
+
+            let if_type = match if_expr.alternative {
+                // Without an else branch, the value of the consequence is discarded.
+                // This allows, for example, a for loop to be the last expression in the branch
+                None => Type::Unit,
+                Some(alternative) => {
+                    type_check_expression(interner, &alternative)?;
+                    let alternative_type = interner.id_type(&alternative);
+
+                    // A branch which always returns from the function does not produce a value,
+                    // so the if expression has the type of the other branch
+                    let if_type = if always_returns(interner, &if_expr.consequence) {
+                        alternative_type
+                    } else if always_returns(interner, &alternative) {
+                        consequence_type
+                    } else if consequence_type.is_super_type_of(&alternative_type) {
+                        consequence_type
+                    } else if alternative_type.is_super_type_of(&consequence_type) {
+                        alternative_type
+                    } else {
+                        let span = interner.expr_span(expr_id);
+                        let err = TypeCheckError::TypeMismatch {
+                            expected_typ: consequence_type.to_string(),
+                            expr_typ: alternative_type.to_string(),
+                            expr_span: span,
+                        };
+                        let ctx = "both branches of an if expression must have the same type";
+                        return Err(err.add_context(ctx).unwrap());
+                    };
+
+                    // If the condition is a witness, both branches are evaluated and the
+                    // result is selected in the circuit, so it cannot be a constant
+                    if condition_type == Type::CONSTANT {
+                        if_type
+                    } else {
+                        if_type.as_witness()
+                    }
+                }
+            };
+
+            interner.push_expr_type(expr_id, if_type);
         }
-        HirExpression::If(_) => todo!("If statements have not been implemented yet!"),
+        HirExpression::EnumVariant(variant) => {
+            interner.push_expr_type(expr_id, Type::Enum(variant.r#type))
+        }
+        HirExpression::Match(match_expr) => type_check_match(interner, expr_id, match_expr)?,
     };
     Ok(())
 }
@@ -278,6 +532,33 @@ pub fn infix_operand_type_rules(
         return Ok(Type::Bool);
     }
 
+    if op.kind == HirBinaryOpKind::Concat {
+        return concat_type_rules(lhs_type, other);
+    }
+
+    // Remainders and shifts are only defined for integers
+    // and bitwise operations are only defined for integers and booleans
+    match op.kind {
+        HirBinaryOpKind::Modulo | HirBinaryOpKind::ShiftLeft | HirBinaryOpKind::ShiftRight => {
+            if let (Type::FieldElement(_), Type::FieldElement(_)) = (lhs_type, other) {
+                return Err(format!("The {:?} operation can only be used on integers, found {} and {}", op.kind, lhs_type, other));
+            }
+        }
+        HirBinaryOpKind::And | HirBinaryOpKind::Or | HirBinaryOpKind::Xor => {
+            if let (Type::FieldElement(_), Type::FieldElement(_)) = (lhs_type, other) {
+                return Err(format!("The {:?} operation can only be used on integers and booleans, found {} and {}", op.kind, lhs_type, other));
+            }
+        }
+        _ => {}
+    }
+
+    // XXX: Division, remainders and right shifts are only lowered for unsigned integers in the evaluator
+    if let HirBinaryOpKind::Divide | HirBinaryOpKind::Modulo | HirBinaryOpKind::ShiftRight = op.kind {
+        if let (Type::Integer(_, Signedness::Signed, _), _) | (_, Type::Integer(_, Signedness::Signed, _)) = (lhs_type, other) {
+            return Err(format!("The {:?} operation is currently only supported for unsigned integers, found {} and {}", op.kind, lhs_type, other));
+        }
+    }
+
     match (lhs_type, other)  {
 
             (Type::Integer(lhs_field_type,sign_x, bit_width_x), Type::Integer(rhs_field_type,sign_y, bit_width_y)) => {
@@ -304,26 +585,77 @@ pub fn infix_operand_type_rules(
             (Type::Integer(_,_, _), typ) | (typ,Type::Integer(_,_, _)) => {
                 Err(format!("Integer cannot be used with type {}", typ))
             }
+            (Type::Bool, Type::Bool) if op.kind.is_bitwise() => Ok(Type::Bool),
+            // Booleans are implicitly converted into field elements in arithmetic,
+            // since they are represented as either 0 or 1
+            (Type::Bool, Type::Bool) | (Type::Bool, Type::FieldElement(_)) | (Type::FieldElement(_), Type::Bool) if op.kind.is_arithmetic() => {
+                Ok(Type::FieldElement(FieldElementType::Private))
+            }
+            (Type::Bool, Type::Bool) => Err(format!("The {:?} operation cannot be used on booleans, try casting them with `as Field`", op.kind)),
             // Currently, arrays are not supported in binary operations
             (Type::Array(_,_,_), _) | (_,Type::Array(_,_, _)) => Err(format!("Arrays cannot be used in an infix operation")),
+            (Type::Struct(_), _) | (_, Type::Struct(_)) => Err(format!("Structs cannot be used in an infix operation")),
+            (Type::Tuple(_), _) | (_, Type::Tuple(_)) => Err(format!("Tuples cannot be used in an infix operation")),
+            (Type::Enum(_), _) | (_, Type::Enum(_)) => Err(format!("Enums cannot be used in an infix operation, use a match expression instead")),
+            (Type::Unresolved(_), _) | (_, Type::Unresolved(_)) => unreachable!("ice: all types should have been resolved before type checking"),
             //
             // An error type on either side will always return an error
             (Type::Error, _) | (_,Type::Error) => Ok(Type::Error),
+            // The type that a generic is bound to is only known in the instances of the function,
+            // so a generic can only be used with itself or with a constant, which takes its type
+            (Type::Generic(lhs), Type::Generic(rhs)) if lhs == rhs => Ok(lhs_type.clone()),
+            (Type::Generic(_), Type::FieldElement(FieldElementType::Constant)) => Ok(lhs_type.clone()),
+            (Type::FieldElement(FieldElementType::Constant), Type::Generic(_)) => Ok(other.clone()),
+            (Type::Generic(_), _) | (_, Type::Generic(_)) => {
+                Err(format!("Cannot use a {} and a {} in a binary operation, as the type of a generic is not known until it is instantiated", lhs_type, other))
+            }
             (Type::Unspecified, _) | (_,Type::Unspecified) => Ok(Type::Unspecified),
             (Type::Unknown, _) | (_,Type::Unknown) => Ok(Type::Unknown),
             (Type::Unit, _) | (_,Type::Unit) => Ok(Type::Unit),
+            (Type::Bool, typ) | (typ, Type::Bool) => {
+                Err(format!("Cannot use a bool and a {} in a binary operation, try casting the bool with `as Field`", typ))
+            }
             //
             // If no side contains an integer. Then we check if either side contains a witness
             // If either side contains a witness, then the final result will be a witness
             (Type::FieldElement(FieldElementType::Private), _) | (_,Type::FieldElement(FieldElementType::Private)) => Ok(Type::FieldElement(FieldElementType::Private)),
             // Public types are added as witnesses under the hood
             (Type::FieldElement(FieldElementType::Public), _) | (_,Type::FieldElement(FieldElementType::Public)) => Ok(Type::FieldElement(FieldElementType::Private)),
-            (Type::Bool, _) | (_,Type::Bool) => Ok(Type::Bool),
             //
             (Type::FieldElement(FieldElementType::Constant), Type::FieldElement(FieldElementType::Constant))  => Ok(Type::FieldElement(FieldElementType::Constant)),
         }
 }
 
+// Concatenating two arrays gives an array with the elements of both,
+// so its length is only fixed if the length of both arrays is fixed
+fn concat_type_rules(lhs_type: &Type, other: &Type) -> Result<Type, String> {
+    match (lhs_type, other) {
+        (Type::Array(_, lhs_size, lhs_element), Type::Array(_, rhs_size, rhs_element)) => {
+            let element_type = if lhs_element.is_super_type_of(rhs_element) {
+                lhs_element
+            } else if rhs_element.is_super_type_of(lhs_element) {
+                rhs_element
+            } else {
+                return Err(format!(
+                    "Cannot concatenate arrays with elements of type {} and {}",
+                    lhs_element, rhs_element
+                ));
+            };
+
+            let size = match (lhs_size, rhs_size) {
+                (ArraySize::Fixed(lhs), ArraySize::Fixed(rhs)) => ArraySize::Fixed(lhs + rhs),
+                _ => ArraySize::Variable,
+            };
+            Ok(Type::Array(FieldElementType::Private, size, element_type.clone()))
+        }
+        (Type::Error, _) | (_, Type::Error) => Ok(Type::Error),
+        _ => Err(format!(
+            "The ++ operation can only be used on arrays, found {} and {}",
+            lhs_type, other
+        )),
+    }
+}
+
 fn field_type_rules(lhs: &FieldElementType, rhs: &FieldElementType) -> FieldElementType {
     match (lhs, rhs) {
         (FieldElementType::Private, FieldElementType::Private) => FieldElementType::Private,
@@ -338,6 +670,228 @@ fn field_type_rules(lhs: &FieldElementType, rhs: &FieldElementType) -> FieldElem
     }
 }
 
+/// Checks that the arms of a match expression cover each variant of the enum exactly once
+/// and sets the type of the match to the type of its arms, which must all be the same.
+fn type_check_match(
+    interner: &mut NodeInterner,
+    expr_id: &ExprId,
+    match_expr: HirMatchExpression,
+) -> Result<(), TypeCheckError> {
+    type_check_expression(interner, &match_expr.expression)?;
+    let enum_type = match interner.id_type(&match_expr.expression) {
+        Type::Enum(enum_type) => enum_type,
+        typ => {
+            return Err(TypeCheckError::TypeCannotBeUsed {
+                typ,
+                place: "match expression",
+                span: interner.expr_span(&match_expr.expression),
+            })
+        }
+    };
+
+    // An arm is unreachable if all of the variants it matches have been matched by previous arms
+    let mut matched = vec![false; enum_type.variants.len()];
+    let mut has_wildcard = false;
+    for (pattern, _) in match_expr.arms.iter() {
+        let is_reachable = match pattern {
+            HirMatchPattern::Variant(variant, span) => {
+                if variant.r#type != enum_type {
+                    return Err(TypeCheckError::TypeMismatch {
+                        expected_typ: enum_type.name.0.contents.clone(),
+                        expr_typ: variant.r#type.name.0.contents.clone(),
+                        expr_span: *span,
+                    });
+                }
+                let already_matched = std::mem::replace(&mut matched[variant.index], true);
+                !has_wildcard && !already_matched
+            }
+            HirMatchPattern::Wildcard(_) => {
+                let is_reachable = !has_wildcard && matched.contains(&false);
+                has_wildcard = true;
+                is_reachable
+            }
+        };
+        if !is_reachable {
+            return Err(TypeCheckError::UnreachableMatchArm {
+                span: pattern.span(),
+            });
+        }
+    }
+
+    if !has_wildcard {
+        let missing_variants: Vec<_> = enum_type
+            .variants
+            .iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(variant, _)| variant.0.contents.clone())
+            .collect();
+        if !missing_variants.is_empty() {
+            return Err(TypeCheckError::NonExhaustiveMatch {
+                enum_name: enum_type.name.0.contents.clone(),
+                missing_variants,
+                span: interner.expr_span(expr_id),
+            });
+        }
+    }
+
+    let mut match_type: Option<Type> = None;
+    for (_, arm) in match_expr.arms.iter() {
+        type_check_expression(interner, arm)?;
+        let arm_type = interner.id_type(arm);
+
+        // An arm which always returns from the function does not produce a value
+        if always_returns(interner, arm) {
+            continue;
+        }
+
+        match_type = Some(match match_type {
+            None => arm_type,
+            Some(match_type) if match_type.is_super_type_of(&arm_type) => match_type,
+            Some(match_type) if arm_type.is_super_type_of(&match_type) => arm_type,
+            Some(match_type) => {
+                let err = TypeCheckError::TypeMismatch {
+                    expected_typ: match_type.to_string(),
+                    expr_typ: arm_type.to_string(),
+                    expr_span: interner.expr_span(arm),
+                };
+                let ctx = "all arms of a match expression must have the same type";
+                return Err(err.add_context(ctx).unwrap());
+            }
+        });
+    }
+
+    // Like an if expression on a witness, every arm is evaluated and the result
+    // is selected in the circuit, so it cannot be a constant
+    let match_type = match_type.map_or(Type::Unit, Type::as_witness);
+    interner.push_expr_type(expr_id, match_type);
+    Ok(())
+}
+
+/// Checks the arguments of a call against the parameters of the function being called
+/// and sets the type of the call to the return type of the function.
+fn type_check_call(
+    interner: &mut NodeInterner,
+    expr_id: &ExprId,
+    call_expr: &HirCallExpression,
+    arg_types: Vec<Type>,
+) -> Result<(), TypeCheckError> {
+    let func_meta = interner.function_meta(&call_expr.func_id);
+
+    // Check function call arity is correct
+    let param_len = func_meta.parameters.len();
+    let arg_len = call_expr.arguments.len();
+    if param_len != arg_len {
+        let span = interner.expr_span(expr_id);
+        return Err(TypeCheckError::ArityMisMatch {
+            expected: param_len as u16,
+            found: arg_len as u16,
+            span,
+        });
+    }
+
+    // The generics of the function are bound by unifying the parameters with the arguments
+    let mut bindings = GenericBindings::default();
+    for (param, arg) in func_meta.parameters.iter().zip(&arg_types) {
+        unify_param(interner, param, arg, &mut bindings)?
+    }
+
+    // Check for argument param equality
+    for (param, arg) in func_meta.parameters.iter().zip(arg_types) {
+        let param = Param(param.0, bindings.substitute(&param.1));
+        check_param_argument(interner, &param, &arg)?
+    }
+
+    // The types that the generics are bound to must implement the traits they are bounded by.
+    // A generic of the calling function is checked once the caller is instantiated
+    for (generic, trait_id) in func_meta.trait_bounds.iter() {
+        let typ = bindings
+            .type_of(generic)
+            .expect("ice: the type generics were bound by the parameter type check");
+        if let Type::Generic(_) = typ {
+            continue;
+        }
+        if !interner.implements_trait(typ, *trait_id) {
+            return Err(TypeCheckError::UnsatisfiedTraitBound {
+                typ: typ.clone(),
+                trait_name: interner.get_trait(*trait_id).borrow().name.0.contents.clone(),
+                generic: generic.0.contents.clone(),
+                span: interner.expr_span(expr_id),
+            });
+        }
+    }
+
+    // A generic function is monomorphised for the values that its generics are bound to.
+    // Calls in the body of a generic function which depend on its generics are skipped,
+    // as they are recorded again when the function itself is instantiated
+    if !func_meta.generics.is_empty() || !func_meta.type_generics.is_empty() {
+        if let Some(args) = bindings.generic_args(interner, &func_meta) {
+            interner.push_generic_call(*expr_id, call_expr.func_id, args);
+        }
+    }
+
+    // The type of the call expression is the return type of the function being called
+    let return_type = bindings.substitute(&func_meta.return_type);
+    interner.push_expr_type(expr_id, return_type);
+    Ok(())
+}
+
+/// Finds the method that is called on an object of type `object_type`.
+/// Methods are declared in the impl blocks of structs, and take `self` as their first parameter.
+fn lookup_method(
+    interner: &mut NodeInterner,
+    object_type: &Type,
+    method: &Ident,
+) -> Result<FuncId, TypeCheckError> {
+    let func_id = match object_type {
+        Type::Struct(struct_type) => {
+            interner.lookup_method(struct_type.borrow().id, &method.0.contents)
+        }
+        // The methods of a generic are declared by the traits it is bounded by
+        Type::Generic(generic) => interner
+            .lookup_trait_method(generic, &method.0.contents)
+            .map(|trait_method| interner.push_trait_method(generic, trait_method)),
+        _ => None,
+    };
+    let func_id = func_id.ok_or_else(|| TypeCheckError::Unstructured {
+        msg: format!(
+            "no method named {} found for type {}",
+            method.0.contents, object_type
+        ),
+        span: method.0.span(),
+    })?;
+
+    // Methods declared without `pub` can only be called from the module of their impl block
+    if let Some((module, file_id, definition)) = interner.private_method(&func_id) {
+        if !interner.is_visible_module(module) {
+            return Err(TypeCheckError::PrivateMethod {
+                method: method.clone(),
+                definition: definition.clone(),
+                file_id: *file_id,
+            });
+        }
+    }
+
+    // Associated functions which do not take `self` can only be called through a path
+    let func_meta = interner.function_meta(&func_id);
+    let takes_self = func_meta
+        .parameters
+        .iter()
+        .next()
+        .map_or(false, |param| interner.ident_name(&param.0) == "self");
+    if !takes_self {
+        return Err(TypeCheckError::Unstructured {
+            msg: format!(
+                "{} is an associated function of type {}, not a method. Call it as `{}::{}(..)` instead",
+                method.0.contents, object_type, object_type, method.0.contents
+            ),
+            span: method.0.span(),
+        });
+    }
+
+    Ok(func_id)
+}
+
 fn check_param_argument(
     interner: &NodeInterner,
     param: &Param,
@@ -362,6 +916,41 @@ fn check_param_argument(
     Ok(())
 }
 
+/// Returns true if evaluating the expression always returns from the function
+/// that it is in, so that it never produces a value.
+pub(crate) fn always_returns(interner: &NodeInterner, expr_id: &ExprId) -> bool {
+    match interner.expression(expr_id) {
+        // The statements after a return are never evaluated
+        HirExpression::Block(block_expr) => {
+            block_expr
+                .statements()
+                .iter()
+                .any(|stmt_id| match interner.statement(stmt_id) {
+                    HirStatement::Return(_) => true,
+                    HirStatement::Expression(expr_id) | HirStatement::Semi(expr_id) => {
+                        always_returns(interner, &expr_id)
+                    }
+                    _ => false,
+                })
+        }
+        HirExpression::If(if_expr) => match if_expr.alternative {
+            Some(alternative) => {
+                always_returns(interner, &if_expr.consequence)
+                    && always_returns(interner, &alternative)
+            }
+            None => false,
+        },
+        HirExpression::Match(match_expr) => {
+            !match_expr.arms.is_empty()
+                && match_expr
+                    .arms
+                    .iter()
+                    .all(|(_, arm)| always_returns(interner, arm))
+        }
+        _ => false,
+    }
+}
+
 fn extract_ret_type(interner: &NodeInterner, stmt_id: &StmtId) -> Type {
     let stmt = interner.statement(stmt_id);
     match stmt {
@@ -372,7 +961,11 @@ fn extract_ret_type(interner: &NodeInterner, stmt_id: &StmtId) -> Type {
         // It would return Unit, as we modify the 
         // return type in the interner after type checking it
         | HirStatement::Semi(_)
-        | HirStatement::Constrain(_) => Type::Unit,
+        | HirStatement::Assign(_)
+        | HirStatement::Constrain(_)
+        // The statements after a return are never evaluated, so the
+        // type of a block ending with a return does not matter
+        | HirStatement::Return(_) => Type::Unit,
         HirStatement::Expression(expr_id) => interner.id_type(&expr_id),
     }
 }
@@ -1,19 +1,28 @@
 mod errors;
 mod expr;
 mod stmt;
+mod unify;
 
 // Type checking at the moment is very simple due to what is supported in the grammar.
 // If polymorphism is never need, then Wands algorithm should be powerful enough to accommodate
 // all foreseeable types, if it is needed then we would need to switch to Hindley-Milner type or maybe bidirectional
 
-use errors::TypeCheckError;
+pub use errors::TypeCheckError;
 use expr::type_check_expression;
 
+use crate::hir::def_map::ModuleId;
 use crate::node_interner::{FuncId, NodeInterner};
+use crate::Type;
 
 /// Type checks a function and assigns the
-/// appropriate types to expressions in a side table
-pub fn type_check_func(interner: &mut NodeInterner, func_id: FuncId) -> Result<(), TypeCheckError> {
+/// appropriate types to expressions in a side table.
+///
+/// `visible_modules` are the modules whose private methods can be called from the function
+pub fn type_check_func(
+    interner: &mut NodeInterner,
+    func_id: FuncId,
+    visible_modules: Vec<ModuleId>,
+) -> Result<(), TypeCheckError> {
     // First fetch the metadata and add the types for parameters
     // Note that we do not look for the defining Identifier for a parameter,
     // since we know that it is the parameter itself
@@ -25,6 +34,15 @@ pub fn type_check_func(interner: &mut NodeInterner, func_id: FuncId) -> Result<(
         interner.push_ident_type(&param.0, param.1);
     }
 
+    // The value of a generic is known at compile time
+    for generic in meta.generics {
+        interner.push_ident_type(&generic, Type::CONSTANT);
+    }
+
+    // Methods can be called on values of a generic type through its trait bounds
+    interner.set_trait_bounds(meta.trait_bounds);
+    interner.set_visible_modules(visible_modules);
+
     // Fetch the HirFunction and iterate all of it's statements
     let hir_func = interner.function(&func_id);
     let func_as_expr = hir_func.as_expr();
@@ -32,10 +50,33 @@ pub fn type_check_func(interner: &mut NodeInterner, func_id: FuncId) -> Result<(
     // Convert the function to a block expression and then type check the block expr
     type_check_expression(interner, func_as_expr)?;
 
-    // Check declared return type and actual return type
-    let function_last_type = interner.id_type(func_as_expr);
+    // Check the value of each return statement against the declared return type.
+    // Constants can also be returned, as the value may be selected in the circuit
+    let has_return_statements = !meta.return_values.is_empty();
+    for return_value in meta.return_values {
+        let return_type = interner.id_type(&return_value);
+        if &return_type.clone().as_witness() != declared_return_type {
+            let err = TypeCheckError::TypeMismatch {
+                expected_typ: declared_return_type.to_string(),
+                expr_typ: return_type.to_string(),
+                expr_span: interner.expr_span(&return_value),
+            };
+            let ctx = "the returned value must have the return type of the function";
+            return Err(err.add_context(ctx).unwrap());
+        }
+    }
+
+    // Check declared return type and actual return type.
+    // If the function body always returns, then it does not have a last value
+    let mut function_last_type = interner.id_type(func_as_expr);
+    let always_returns = expr::always_returns(interner, func_as_expr);
+
+    // Like the returned values, the last value may be selected in the circuit
+    if has_return_statements {
+        function_last_type = function_last_type.as_witness();
+    }
 
-    if !can_ignore_ret && (&function_last_type != declared_return_type) {
+    if !can_ignore_ret && !always_returns && (&function_last_type != declared_return_type) {
         let func_span = interner.id_span(func_as_expr); // XXX: We could be more specific and return the span of the last stmt, however stmts do not have spans yet
         return Err(TypeCheckError::TypeMismatch {
             expected_typ: declared_return_type.to_string(),
@@ -63,6 +104,7 @@ mod test {
 
     use noirc_errors::{Span, Spanned};
 
+    use crate::graph::CrateId;
     use crate::hir_def::{
         expr::{
             HirBinaryOp, HirBinaryOpKind, HirBlockExpression, HirExpression, HirInfixExpression,
@@ -71,13 +113,16 @@ mod test {
         stmt::{HirPrivateStatement, HirStatement},
     };
     use crate::node_interner::{FuncId, NodeInterner};
-    use crate::{graph::CrateId, Ident};
     use crate::{
         hir::{
-            def_map::{CrateDefMap, ModuleDefId},
-            resolution::{path_resolver::PathResolver, resolver::Resolver},
+            def_map::{CrateDefMap, ModuleDefId, ModuleId, PerNs},
+            resolution::{
+                import::{PathResolution, PathResolutionError},
+                path_resolver::PathResolver,
+                resolver::Resolver,
+            },
         },
-        FunctionKind, Parser, Path, Type,
+        FunctionKind, Ident, Parser, Path, Type,
     };
 
     #[test]
@@ -132,13 +177,17 @@ mod test {
             name: String::from("test_func"),
             kind: FunctionKind::Normal,
             attributes: None,
+            generics: Vec::new(),
+            type_generics: Vec::new(),
+            trait_bounds: Vec::new(),
             parameters: vec![Param(x_id, Type::WITNESS), Param(y_id, Type::WITNESS)].into(),
             return_type: Type::Unit,
+            return_values: Vec::new(),
             has_body: true,
         };
         interner.push_fn_meta(func_meta, func_id);
 
-        super::type_check_func(&mut interner, func_id).unwrap();
+        super::type_check_func(&mut interner, func_id, Vec::new()).unwrap();
     }
     #[test]
     fn basic_priv_simplified() {
@@ -210,19 +259,26 @@ mod test {
     struct TestPathResolver(HashMap<String, ModuleDefId>);
 
     impl PathResolver for TestPathResolver {
-        fn resolve(
-            &self,
-            _def_maps: &HashMap<CrateId, CrateDefMap>,
-            path: Path,
-        ) -> Result<Option<ModuleDefId>, Ident> {
+        fn resolve(&self, _def_maps: &HashMap<CrateId, CrateDefMap>, path: Path) -> PathResolution {
             // Not here that foo::bar and hello::foo::bar would fetch the same thing
             let name = path.segments.last().unwrap();
             let mod_def = self.0.get(&name.0.contents).cloned();
             match mod_def {
-                None => Err(name.clone()),
-                Some(_) => Ok(mod_def),
+                None => Err(PathResolutionError::Unresolved(name.clone())),
+                Some(def) => Ok(PerNs::values(def)),
             }
         }
+
+        // Every item is declared in the same module
+        fn check_private_method(
+            &self,
+            _def_maps: &HashMap<CrateId, CrateDefMap>,
+            _method: &Ident,
+            _definition: &Ident,
+            _module: ModuleId,
+        ) -> Result<(), PathResolutionError> {
+            Ok(())
+        }
     }
 
     impl TestPathResolver {
@@ -265,6 +321,11 @@ mod test {
         }
 
         // Type check section
-        super::type_check_func(&mut interner, func_ids.first().cloned().unwrap()).unwrap();
+        super::type_check_func(
+            &mut interner,
+            func_ids.first().cloned().unwrap(),
+            Vec::new(),
+        )
+        .unwrap();
     }
 }
@@ -1,7 +1,11 @@
+use noirc_errors::Span;
+
+use crate::hir_def::expr::{HirExpression, HirLiteral};
 use crate::hir_def::stmt::{
-    HirConstStatement, HirConstrainStatement, HirLetStatement, HirPrivateStatement, HirStatement,
+    HirAssignStatement, HirConstStatement, HirConstrainStatement, HirLValue, HirLetStatement,
+    HirPattern, HirPrivateStatement, HirStatement,
 };
-use crate::node_interner::{ExprId, NodeInterner, StmtId};
+use crate::node_interner::{ExprId, IdentId, NodeInterner, StmtId};
 use crate::Type;
 
 use super::{errors::TypeCheckError, expr::type_check_expression};
@@ -45,6 +49,9 @@ pub(crate) fn type_check(
         HirStatement::Constrain(constrain_stmt) => {
             type_check_constrain_stmt(interner, constrain_stmt)
         }
+        HirStatement::Assign(assign_stmt) => type_check_assign_stmt(interner, assign_stmt),
+        // The value is checked against the return type of the function in `type_check_func`
+        HirStatement::Return(expr_id) => type_check_expression(interner, &expr_id),
     }
 }
 
@@ -86,10 +93,54 @@ fn type_check_let_stmt(
         });
     }
 
-    // Set the type of the identifier to be equal to the annotated type
-    interner.push_ident_type(&let_stmt.identifier, resolved_type);
+    bind_pattern(interner, &let_stmt.pattern, resolved_type, let_stmt.mutable)
+}
 
-    Ok(())
+// Sets the type of each identifier in the pattern to the part of the type that it binds
+fn bind_pattern(
+    interner: &mut NodeInterner,
+    pattern: &HirPattern,
+    typ: Type,
+    mutable: bool,
+) -> Result<(), TypeCheckError> {
+    match (pattern, typ) {
+        (HirPattern::Identifier(ident_id), mut typ) => {
+            // A mutable variable which is initialised with a constant will usually be
+            // assigned a witness later on, so we give it the witness type instead.
+            if mutable && typ == Type::CONSTANT {
+                typ = Type::WITNESS;
+            }
+            interner.push_ident_type(ident_id, typ);
+            Ok(())
+        }
+        (HirPattern::Tuple(patterns, span), Type::Tuple(elements)) => {
+            if patterns.len() != elements.len() {
+                return Err(TypeCheckError::Unstructured {
+                    msg: format!(
+                        "expected a pattern with {} elements, found one with {} elements",
+                        elements.len(),
+                        patterns.len()
+                    ),
+                    span: *span,
+                });
+            }
+            for (pattern, typ) in patterns.iter().zip(elements) {
+                bind_pattern(interner, pattern, typ, mutable)?;
+            }
+            Ok(())
+        }
+        (HirPattern::Tuple(patterns, _), Type::Error) => {
+            for pattern in patterns {
+                bind_pattern(interner, pattern, Type::Error, mutable)?;
+            }
+            Ok(())
+        }
+        (HirPattern::Tuple(_, span), typ) => Err(TypeCheckError::TypeMismatch {
+            expected_typ: "tuple".to_owned(),
+            expr_typ: typ.to_string(),
+            expr_span: *span,
+        }),
+    }
 }
 fn type_check_const_stmt(
     interner: &mut NodeInterner,
@@ -99,7 +150,13 @@ fn type_check_const_stmt(
     // Unless we later want to have u32 constants and check those at compile time.
     let resolved_type = type_check_declaration(interner, const_stmt.expression, const_stmt.r#type)?;
 
-    if resolved_type != Type::CONSTANT {
+    // A string literal is an array of constant bytes, so it can also be declared as a constant
+    let is_string_literal = match interner.expression(&const_stmt.expression) {
+        HirExpression::Literal(HirLiteral::Str(_)) => true,
+        _ => false,
+    };
+
+    if resolved_type != Type::CONSTANT && !is_string_literal {
         let span = interner.expr_span(&const_stmt.expression);
         let mut err = TypeCheckError::TypeCannotBeUsed {
             typ: resolved_type,
@@ -160,6 +217,80 @@ fn type_check_constrain_stmt(
     Ok(())
 }
 
+fn type_check_assign_stmt(
+    interner: &mut NodeInterner,
+    assign_stmt: HirAssignStatement,
+) -> Result<(), TypeCheckError> {
+    type_check_expression(interner, &assign_stmt.expression)?;
+    let expr_type = interner.id_type(&assign_stmt.expression);
+
+    let expected_type = type_check_lvalue(interner, assign_stmt.lvalue)?;
+
+    if !expected_type.is_super_type_of(&expr_type) && expr_type != Type::Error {
+        let expr_span = interner.expr_span(&assign_stmt.expression);
+        let err = TypeCheckError::TypeMismatch {
+            expected_typ: expected_type.to_string(),
+            expr_typ: expr_type.to_string(),
+            expr_span,
+        };
+        let err = err
+            .add_context("the type of a variable cannot change after it has been declared")
+            .unwrap();
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+// Returns the type that the variable was declared with
+/// Returns the type of the place which is being assigned to
+fn type_check_lvalue(
+    interner: &mut NodeInterner,
+    lvalue: HirLValue,
+) -> Result<Type, TypeCheckError> {
+    match lvalue {
+        HirLValue::Ident(ident_id) => Ok(variable_type(interner, &ident_id)),
+        HirLValue::Index { array, index } => {
+            type_check_expression(interner, &index)?;
+            let index_type = interner.id_type(&index);
+            // Booleans are used as the index 0 or 1
+            if !index_type.is_base_type() && index_type != Type::Bool && index_type != Type::Error {
+                let span = interner.expr_span(&index);
+                return Err(TypeCheckError::TypeCannotBeUsed {
+                    typ: index_type,
+                    place: "array index",
+                    span,
+                });
+            }
+
+            let span = lvalue_span(interner, &array);
+            match type_check_lvalue(interner, *array)? {
+                Type::Array(_, _, element_type) => Ok(*element_type),
+                typ => Err(TypeCheckError::TypeMismatch {
+                    expected_typ: "Array".to_owned(),
+                    expr_typ: typ.to_string(),
+                    expr_span: span,
+                }),
+            }
+        }
+    }
+}
+
+// The span of an array element is the span of its index, as lvalues do not have spans
+fn lvalue_span(interner: &NodeInterner, lvalue: &HirLValue) -> Span {
+    match lvalue {
+        HirLValue::Ident(ident_id) => interner.id_span(ident_id),
+        HirLValue::Index { index, .. } => interner.expr_span(index),
+    }
+}
+
+fn variable_type(interner: &NodeInterner, ident_id: &IdentId) -> Type {
+    let ident_def = interner
+        .ident_def(ident_id)
+        .expect("ice : all identifiers should have a def");
+    interner.id_type(&ident_def)
+}
+
 /// All declaration statements check that the user specified type(UST) is equal to the
 /// expression on the RHS, unless the UST is unspecified
 /// In that case, the UST because the expression
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use crate::hir_def::function::{FuncMeta, GenericArgs, Param};
+use crate::node_interner::NodeInterner;
+use crate::{ArraySize, FieldElementType, Ident, Type};
+
+use super::errors::TypeCheckError;
+
+/// The values that the generics of a function are bound to at a call site
+#[derive(Debug, Default)]
+pub(crate) struct GenericBindings {
+    sizes: HashMap<Ident, ArraySize>,
+    types: HashMap<Ident, Type>,
+}
+
+impl GenericBindings {
+    /// Returns the type that a type generic was bound to
+    pub(crate) fn type_of(&self, generic: &Ident) -> Option<&Type> {
+        self.types.get(generic)
+    }
+
+    /// Returns the values that the generics of `func_meta` were bound to, which
+    /// identify the instance of the function to call.
+    ///
+    /// Returns None if a generic was bound to a value which depends on the generics of
+    /// the calling function. Such a call is monomorphised once the caller is instantiated.
+    pub(crate) fn generic_args(
+        &self,
+        interner: &NodeInterner,
+        func_meta: &FuncMeta,
+    ) -> Option<GenericArgs> {
+        let mut lengths = Vec::with_capacity(func_meta.generics.len());
+        for generic in func_meta.generics.iter() {
+            let generic = Ident::from(interner.ident_name(generic));
+            match self.sizes.get(&generic) {
+                Some(ArraySize::Fixed(length)) => lengths.push(*length),
+                _ => return None,
+            }
+        }
+
+        let mut types = Vec::with_capacity(func_meta.type_generics.len());
+        for generic in func_meta.type_generics.iter() {
+            match self.types.get(generic) {
+                Some(typ) if !contains_generic(typ) => types.push(typ.clone()),
+                _ => return None,
+            }
+        }
+
+        Some(GenericArgs { lengths, types })
+    }
+
+    /// Replaces each generic in `typ` with the value that it is bound to.
+    /// Generics which have not been bound are left as is.
+    pub(crate) fn substitute(&self, typ: &Type) -> Type {
+        match typ {
+            Type::Generic(generic) => self
+                .types
+                .get(generic)
+                .cloned()
+                .unwrap_or_else(|| typ.clone()),
+            Type::Array(fe_type, size, elem_type) => {
+                let size = match size {
+                    ArraySize::Generic(generic) => self
+                        .sizes
+                        .get(generic)
+                        .cloned()
+                        .unwrap_or_else(|| size.clone()),
+                    size => size.clone(),
+                };
+                Type::Array(fe_type.clone(), size, Box::new(self.substitute(elem_type)))
+            }
+            Type::Tuple(elements) => {
+                Type::Tuple(elements.iter().map(|elem| self.substitute(elem)).collect())
+            }
+            typ => typ.clone(),
+        }
+    }
+}
+
+/// Binds the generics used in the type of a parameter, by unifying it with the type of the argument.
+///
+/// A generic which is used by multiple parameters must be bound to the same value each time.
+/// The exception is a type generic which is used for both a constant and a witness, in which case
+/// it is bound to the witness type.
+pub(crate) fn unify_param(
+    interner: &NodeInterner,
+    param: &Param,
+    arg_type: &Type,
+    bindings: &mut GenericBindings,
+) -> Result<(), TypeCheckError> {
+    if unify(&param.1, arg_type, bindings) {
+        return Ok(());
+    }
+
+    let span = interner.ident_span(&param.0);
+    let err = TypeCheckError::TypeMismatch {
+        expected_typ: bindings.substitute(&param.1).to_string(),
+        expr_typ: arg_type.to_string(),
+        expr_span: span,
+    };
+    let ctx = "a generic must have the same value for every parameter it is used in";
+    Err(err.add_context(ctx).unwrap())
+}
+
+// Returns false if a generic has already been bound to an incompatible value.
+// Any other mismatch between the two types is left to the parameter type check
+fn unify(param_type: &Type, arg_type: &Type, bindings: &mut GenericBindings) -> bool {
+    match (param_type, arg_type) {
+        (Type::Generic(generic), arg_type) => {
+            let arg_type = instance_type(arg_type);
+            match bindings.types.get(generic) {
+                Some(typ) if typ.is_super_type_of(&arg_type) => true,
+                Some(typ) if !arg_type.is_super_type_of(typ) => false,
+                _ => {
+                    bindings.types.insert(generic.clone(), arg_type);
+                    true
+                }
+            }
+        }
+        (Type::Array(_, param_size, param_elem), Type::Array(_, arg_size, arg_elem)) => {
+            if let ArraySize::Generic(generic) = param_size {
+                match bindings.sizes.get(generic) {
+                    Some(size) if size != arg_size => return false,
+                    Some(_) => {}
+                    None => {
+                        bindings.sizes.insert(generic.clone(), arg_size.clone());
+                    }
+                }
+            }
+            unify(param_elem, arg_elem, bindings)
+        }
+        (Type::Tuple(param_elements), Type::Tuple(arg_elements)) => param_elements
+            .iter()
+            .zip(arg_elements)
+            .all(|(param_elem, arg_elem)| unify(param_elem, arg_elem, bindings)),
+        _ => true,
+    }
+}
+
+// Public values are witnesses once they are passed to a function,
+// and the return type of a function cannot be public
+fn instance_type(typ: &Type) -> Type {
+    match typ {
+        Type::FieldElement(FieldElementType::Public) => Type::WITNESS,
+        Type::Integer(FieldElementType::Public, sign, num_bits) => {
+            Type::Integer(FieldElementType::Private, *sign, *num_bits)
+        }
+        Type::Array(FieldElementType::Public, size, elem_type) => Type::Array(
+            FieldElementType::Private,
+            size.clone(),
+            Box::new(instance_type(elem_type)),
+        ),
+        Type::Tuple(elements) => Type::Tuple(elements.iter().map(instance_type).collect()),
+        typ => typ.clone(),
+    }
+}
+
+// Returns true if the type refers to a generic of the function being type checked
+fn contains_generic(typ: &Type) -> bool {
+    match typ {
+        Type::Generic(_) => true,
+        Type::Array(_, ArraySize::Generic(_), _) => true,
+        Type::Array(_, _, elem_type) => contains_generic(elem_type),
+        Type::Tuple(elements) => elements.iter().any(contains_generic),
+        _ => false,
+    }
+}
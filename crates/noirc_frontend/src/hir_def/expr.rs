@@ -1,8 +1,12 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use noir_field::FieldElement;
 use noirc_errors::Span;
 
+use crate::hir_def::types::{EnumType, StructType};
 use crate::node_interner::{ExprId, FuncId, IdentId, StmtId};
-use crate::{BinaryOp, BinaryOpKind, Type, UnaryOp};
+use crate::{BinaryOp, BinaryOpKind, Ident, Type, UnaryOp};
 #[derive(Debug, Clone)]
 pub enum HirExpression {
     Ident(IdentId),
@@ -11,11 +15,19 @@ pub enum HirExpression {
     Prefix(HirPrefixExpression),
     Infix(HirInfixExpression),
     Index(HirIndexExpression),
+    Slice(HirSliceExpression),
+    Constructor(HirConstructorExpression),
+    Tuple(Vec<ExprId>),
+    MemberAccess(HirMemberAccess),
     Call(HirCallExpression),
+    MethodCall(HirMethodCallExpression),
     Cast(HirCastExpression),
     Predicate(HirInfixExpression),
     For(HirForExpression),
-    If(IfExpression),
+    While(HirWhileExpression),
+    If(HirIfExpression),
+    EnumVariant(HirEnumVariant),
+    Match(HirMatchExpression),
 }
 
 impl HirExpression {
@@ -33,12 +45,19 @@ pub struct HirForExpression {
     pub block: ExprId,
 }
 
+#[derive(Debug, Clone)]
+pub struct HirWhileExpression {
+    pub condition: ExprId,
+    pub block: ExprId,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum HirBinaryOpKind {
     Add,
     Subtract,
     Multiply,
     Divide,
+    Modulo,
     Equal,
     NotEqual,
     Less,
@@ -48,6 +67,9 @@ pub enum HirBinaryOpKind {
     And,
     Or,
     Xor,
+    ShiftLeft,
+    ShiftRight,
+    Concat,
     Assign,
 }
 
@@ -64,6 +86,7 @@ impl From<BinaryOpKind> for HirBinaryOpKind {
             BinaryOpKind::Subtract => HirBinaryOpKind::Subtract,
             BinaryOpKind::Multiply => HirBinaryOpKind::Multiply,
             BinaryOpKind::Divide => HirBinaryOpKind::Divide,
+            BinaryOpKind::Modulo => HirBinaryOpKind::Modulo,
             BinaryOpKind::Equal => HirBinaryOpKind::Equal,
             BinaryOpKind::NotEqual => HirBinaryOpKind::NotEqual,
             BinaryOpKind::Less => HirBinaryOpKind::Less,
@@ -73,6 +96,9 @@ impl From<BinaryOpKind> for HirBinaryOpKind {
             BinaryOpKind::And => HirBinaryOpKind::And,
             BinaryOpKind::Or => HirBinaryOpKind::Or,
             BinaryOpKind::Xor => HirBinaryOpKind::Xor,
+            BinaryOpKind::ShiftLeft => HirBinaryOpKind::ShiftLeft,
+            BinaryOpKind::ShiftRight => HirBinaryOpKind::ShiftRight,
+            BinaryOpKind::Concat => HirBinaryOpKind::Concat,
             BinaryOpKind::Assign => HirBinaryOpKind::Assign,
         }
     }
@@ -103,6 +129,23 @@ impl HirBinaryOpKind {
             _ => false,
         }
     }
+
+    pub fn is_arithmetic(&self) -> bool {
+        match self {
+            HirBinaryOpKind::Add
+            | HirBinaryOpKind::Subtract
+            | HirBinaryOpKind::Multiply
+            | HirBinaryOpKind::Divide => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_bitwise(&self) -> bool {
+        match self {
+            HirBinaryOpKind::And | HirBinaryOpKind::Or | HirBinaryOpKind::Xor => true,
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -123,6 +166,7 @@ impl From<UnaryOp> for HirUnaryOp {
 #[derive(Debug, Clone)]
 pub enum HirLiteral {
     Array(HirArrayLiteral),
+    RepeatedArray(HirRepeatedArrayLiteral),
     Bool(bool),
     Integer(FieldElement),
     Str(String),
@@ -142,12 +186,40 @@ pub struct HirInfixExpression {
 }
 
 #[derive(Debug, Clone)]
-pub struct IfExpression {
+pub struct HirIfExpression {
     pub condition: ExprId,
     pub consequence: ExprId,
     pub alternative: Option<ExprId>,
 }
 
+// A variant of an enum, such as `Op::Deposit`
+#[derive(Debug, Clone)]
+pub struct HirEnumVariant {
+    pub r#type: Rc<EnumType>,
+    // The discriminant of the variant, which is its position in the enum declaration
+    pub index: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct HirMatchExpression {
+    pub expression: ExprId,
+    pub arms: Vec<(HirMatchPattern, ExprId)>,
+}
+
+#[derive(Debug, Clone)]
+pub enum HirMatchPattern {
+    Variant(HirEnumVariant, Span),
+    Wildcard(Span),
+}
+
+impl HirMatchPattern {
+    pub fn span(&self) -> Span {
+        match self {
+            HirMatchPattern::Variant(_, span) | HirMatchPattern::Wildcard(span) => *span,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct HirCastExpression {
     pub lhs: ExprId,
@@ -160,17 +232,57 @@ pub struct HirArrayLiteral {
     pub contents: Vec<ExprId>,
 }
 
+// The length of a repeated array literal has already been evaluated by the resolver
+#[derive(Debug, Clone)]
+pub struct HirRepeatedArrayLiteral {
+    pub element: ExprId,
+    pub length: u128,
+}
+
 #[derive(Debug, Clone)]
 pub struct HirCallExpression {
     pub func_id: FuncId,
     pub arguments: Vec<ExprId>,
 }
+// A method call is replaced with a call to the method during type checking,
+// since the method is looked up using the type of the object
+#[derive(Debug, Clone)]
+pub struct HirMethodCallExpression {
+    pub object: ExprId,
+    pub method: Ident,
+    pub arguments: Vec<ExprId>,
+}
+
 #[derive(Debug, Clone)]
 pub struct HirIndexExpression {
-    pub collection_name: IdentId,
+    pub collection: ExprId,
     pub index: ExprId,
 }
 
+// The bounds of a slice have already been evaluated by the resolver
+#[derive(Debug, Clone)]
+pub struct HirSliceExpression {
+    pub collection: ExprId,
+    pub start: u128,
+    pub end: u128,
+}
+
+#[derive(Debug, Clone)]
+pub struct HirConstructorExpression {
+    pub r#type: Rc<RefCell<StructType>>,
+
+    // NOTE: It is tempting to make this a BTreeMap to force ordering of fields.
+    //       However, that would break the ordering of side effects in the
+    //       arguments, which are evaluated in the order they are written.
+    pub fields: Vec<(Ident, ExprId)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HirMemberAccess {
+    pub lhs: ExprId,
+    pub rhs: Ident,
+}
+
 #[derive(Debug, Clone)]
 pub struct HirBlockExpression(pub Vec<StmtId>);
 
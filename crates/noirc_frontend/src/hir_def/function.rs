@@ -1,8 +1,8 @@
 use noirc_abi::Abi;
 
 use super::expr::{HirBlockExpression, HirExpression};
-use crate::node_interner::{ExprId, IdentId, NodeInterner};
-use crate::{token::Attribute, FunctionKind, Type};
+use crate::node_interner::{ExprId, IdentId, NodeInterner, TraitId};
+use crate::{token::Attribute, FunctionKind, Ident, Type};
 
 /// A Hir function is a block expression
 /// with a list of statements
@@ -79,13 +79,40 @@ pub struct FuncMeta {
     pub kind: FunctionKind,
 
     pub attributes: Option<Attribute>,
+    // Generics are inferred from the lengths of the arrays passed in as arguments
+    pub generics: Vec<IdentId>,
+    // Type generics are inferred from the types of the arguments. The function is
+    // monomorphised for each set of types that it is called with
+    pub type_generics: Vec<Ident>,
+    // The traits that each type generic must implement, which are checked at each call site
+    pub trait_bounds: Vec<(Ident, TraitId)>,
     pub parameters: Parameters,
     pub return_type: Type,
+    // The values of the return statements in the function body,
+    // which are checked against the return type
+    pub return_values: Vec<ExprId>,
 
     // This flag is needed for the attribute check pass
     pub has_body: bool,
 }
 
+/// The values that the generics of a function are bound to at a call site.
+/// A generic function is monomorphised once for each distinct set of values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenericArgs {
+    // The lengths that the generics are bound to, in the order of `FuncMeta::generics`
+    pub lengths: Vec<u128>,
+    // The types that the type generics are bound to, in the order of `FuncMeta::type_generics`
+    pub types: Vec<Type>,
+}
+
+impl GenericArgs {
+    /// Returns true if the function being called is not generic
+    pub fn is_empty(&self) -> bool {
+        self.lengths.is_empty() && self.types.is_empty()
+    }
+}
+
 impl FuncMeta {
     /// Builtin and LowLevel functions usually have the return type
     /// declared, however their function bodies will be empty
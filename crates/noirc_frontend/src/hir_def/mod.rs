@@ -1,3 +1,4 @@
 pub mod expr;
 pub mod function;
 pub mod stmt;
+pub mod types;
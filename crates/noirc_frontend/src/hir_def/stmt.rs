@@ -1,12 +1,33 @@
 use crate::Type;
+use noirc_errors::Span;
 
 use super::expr::HirInfixExpression;
 use crate::node_interner::{ExprId, IdentId};
 #[derive(Debug, Clone)]
 pub struct HirLetStatement {
-    pub identifier: IdentId,
+    pub pattern: HirPattern,
     pub r#type: Type,
     pub expression: ExprId,
+    pub mutable: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum HirPattern {
+    Identifier(IdentId),
+    Tuple(Vec<HirPattern>, Span),
+}
+
+impl HirPattern {
+    /// Returns the identifiers in the pattern, in the order that they are declared
+    pub fn identifiers(&self) -> Vec<IdentId> {
+        match self {
+            HirPattern::Identifier(ident) => vec![*ident],
+            HirPattern::Tuple(patterns, _) => patterns
+                .iter()
+                .flat_map(|pattern| pattern.identifiers())
+                .collect(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -33,6 +54,21 @@ pub struct HirPrivateStatement {
 
 #[derive(Debug, Clone)]
 pub struct HirConstrainStatement(pub HirInfixExpression);
+
+#[derive(Debug, Clone)]
+pub struct HirAssignStatement {
+    pub lvalue: HirLValue,
+    pub expression: ExprId,
+}
+
+#[derive(Debug, Clone)]
+pub enum HirLValue {
+    Ident(IdentId),
+    Index {
+        array: Box<HirLValue>,
+        index: ExprId,
+    },
+}
 #[derive(Debug, Clone)]
 pub struct BinaryStatement {
     pub lhs: ExprId,
@@ -45,6 +81,8 @@ pub enum HirStatement {
     Const(HirConstStatement),
     Constrain(HirConstrainStatement),
     Private(HirPrivateStatement),
+    Assign(HirAssignStatement),
     Expression(ExprId),
     Semi(ExprId),
+    Return(ExprId),
 }
@@ -0,0 +1,159 @@
+use noirc_errors::Span;
+
+use crate::{
+    node_interner::{EnumId, StructId, TraitId},
+    Ident, Type,
+};
+
+/// A resolved struct definition.
+///
+/// Types refer to a struct definition through a shared reference, so
+/// that two structs with the same fields, but different names, are
+/// not equal.
+#[derive(Debug)]
+pub struct StructType {
+    pub id: StructId,
+    pub name: Ident,
+    /// Fields are ordered as they were declared. This ordering is used
+    /// when flattening a struct in the evaluator and in the ABI.
+    pub fields: Vec<(Ident, Type)>,
+    pub span: Span,
+}
+
+impl StructType {
+    pub fn new(id: StructId, name: Ident, span: Span) -> StructType {
+        StructType {
+            id,
+            name,
+            fields: Vec::new(),
+            span,
+        }
+    }
+
+    /// Returns the position of the field and it's type
+    pub fn field(&self, name: &Ident) -> Option<(usize, &Type)> {
+        self.fields
+            .iter()
+            .enumerate()
+            .find(|(_, (field_name, _))| field_name == name)
+            .map(|(index, (_, typ))| (index, typ))
+    }
+}
+
+// Two struct types are the same, iff they were created from the same definition
+impl PartialEq for StructType {
+    fn eq(&self, other: &StructType) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for StructType {}
+
+/// An enum definition.
+///
+/// Each variant is represented by its discriminant, which is
+/// the position of the variant in the declaration.
+#[derive(Debug)]
+pub struct EnumType {
+    pub id: EnumId,
+    pub name: Ident,
+    pub variants: Vec<Ident>,
+    pub span: Span,
+}
+
+impl EnumType {
+    pub fn new(id: EnumId, name: Ident, variants: Vec<Ident>, span: Span) -> EnumType {
+        EnumType {
+            id,
+            name,
+            variants,
+            span,
+        }
+    }
+
+    /// Returns the discriminant of the variant with the given name
+    pub fn variant(&self, name: &str) -> Option<usize> {
+        self.variants
+            .iter()
+            .position(|variant| variant.0.contents == name)
+    }
+}
+
+// Like structs, two enum types are the same iff they were created from the same definition
+impl PartialEq for EnumType {
+    fn eq(&self, other: &EnumType) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for EnumType {}
+
+/// A resolved trait definition.
+///
+/// `Self` is resolved to the generic type `Self` in the signatures
+/// of the methods, which is bound to the type implementing the trait.
+#[derive(Debug)]
+pub struct TraitType {
+    pub id: TraitId,
+    pub name: Ident,
+    pub methods: Vec<TraitMethod>,
+    pub span: Span,
+}
+
+/// The signature of a method declared in a trait
+#[derive(Debug, Clone)]
+pub struct TraitMethod {
+    pub name: Ident,
+    pub parameters: Vec<(Ident, Type)>,
+    pub return_type: Type,
+}
+
+impl TraitType {
+    pub fn new(id: TraitId, name: Ident, span: Span) -> TraitType {
+        TraitType {
+            id,
+            name,
+            methods: Vec::new(),
+            span,
+        }
+    }
+
+    /// Returns the signature of the method declared with the given name
+    pub fn method(&self, name: &str) -> Option<&TraitMethod> {
+        self.methods
+            .iter()
+            .find(|method| method.name.0.contents == name)
+    }
+}
+
+impl TraitMethod {
+    /// Returns the types of the parameters and the return type of the method,
+    /// when the trait is implemented for `self_type`
+    pub fn signature(&self, self_type: &Type) -> (Vec<Type>, Type) {
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|(_, typ)| replace_self_type(typ, self_type))
+            .collect();
+        let return_type = replace_self_type(&self.return_type, self_type);
+        (parameters, return_type)
+    }
+}
+
+fn replace_self_type(typ: &Type, self_type: &Type) -> Type {
+    match typ {
+        Type::Generic(generic) if generic.0.contents == "Self" => self_type.clone(),
+        Type::Array(fe_type, size, elem_type) => Type::Array(
+            fe_type.clone(),
+            size.clone(),
+            Box::new(replace_self_type(elem_type, self_type)),
+        ),
+        Type::Tuple(elements) => Type::Tuple(
+            elements
+                .iter()
+                .map(|elem| replace_self_type(elem, self_type))
+                .collect(),
+        ),
+        typ => typ.clone(),
+    }
+}
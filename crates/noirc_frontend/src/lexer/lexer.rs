@@ -88,7 +88,7 @@ impl<'a> Lexer<'a> {
             Some('(') => self.single_char_token(Token::LeftParen),
             Some(')') => self.single_char_token(Token::RightParen),
             Some(',') => self.single_char_token(Token::Comma),
-            Some('+') => self.single_char_token(Token::Plus),
+            Some('+') => self.glue(Token::Plus),
             Some('{') => self.single_char_token(Token::LeftBrace),
             Some('|') => self.single_char_token(Token::Pipe),
             Some('}') => self.single_char_token(Token::RightBrace),
@@ -133,9 +133,19 @@ impl<'a> Lexer<'a> {
         let spanned_prev_token = prev_token.clone().into_single_span(self.position.mark());
         match prev_token {
             Token::Dot => self.single_double_peek_token('.', prev_token, Token::DoubleDot),
+            Token::Plus => self.single_double_peek_token('+', prev_token, Token::PlusPlus),
+            Token::Less if self.peek_char_is('<') => {
+                self.single_double_peek_token('<', prev_token, Token::ShiftLeft)
+            }
             Token::Less => self.single_double_peek_token('=', prev_token, Token::LessEqual),
+            Token::Greater if self.peek_char_is('>') => {
+                self.single_double_peek_token('>', prev_token, Token::ShiftRight)
+            }
             Token::Greater => self.single_double_peek_token('=', prev_token, Token::GreaterEqual),
             Token::Bang => self.single_double_peek_token('=', prev_token, Token::NotEqual),
+            Token::Assign if self.peek_char_is('>') => {
+                self.single_double_peek_token('>', prev_token, Token::FatArrow)
+            }
             Token::Assign => self.single_double_peek_token('=', prev_token, Token::Equal),
             Token::Minus => self.single_double_peek_token('>', prev_token, Token::Arrow),
             Token::Colon => self.single_double_peek_token(':', prev_token, Token::DoubleColon),
@@ -285,6 +295,14 @@ impl<'a> Lexer<'a> {
     }
     fn eat_string_literal(&mut self) -> SpannedToken {
         let (str_literal, start_span, end_span) = self.eat_while(None, |ch| ch != '"');
+
+        // The closing quote is not part of the literal
+        if !self.peek_char_is('"') {
+            let err_msg = format!("Lexer expected a closing '\"' character for the string literal");
+            return Token::Error(err_msg).into_single_span(self.position.mark());
+        }
+        self.next_char();
+
         let str_literal_token = Token::Str(str_literal);
         str_literal_token.into_span(start_span, end_span)
     }
@@ -308,12 +326,13 @@ impl<'a> Iterator for Lexer<'a> {
 
 #[test]
 fn test_single_double_char() {
-    let input = "! != + ( ) { } [ ] | , ; : :: < <= > >= & - -> . .. % / * = ==";
+    let input = "! != + ++ ( ) { } [ ] | , ; : :: < <= > >= << >> & - -> . .. % / * = == =>";
 
     let expected = vec![
         Token::Bang,
         Token::NotEqual,
         Token::Plus,
+        Token::PlusPlus,
         Token::LeftParen,
         Token::RightParen,
         Token::LeftBrace,
@@ -329,6 +348,8 @@ fn test_single_double_char() {
         Token::LessEqual,
         Token::Greater,
         Token::GreaterEqual,
+        Token::ShiftLeft,
+        Token::ShiftRight,
         Token::Ampersand,
         Token::Minus,
         Token::Arrow,
@@ -339,6 +360,7 @@ fn test_single_double_char() {
         Token::Star,
         Token::Assign,
         Token::Equal,
+        Token::FatArrow,
         Token::EOF,
     ];
 
@@ -409,13 +431,25 @@ fn test_comment() {
 
 #[test]
 fn test_eat_string_literal() {
-    let input = "let _word = \"hello\"";
+    let input = "let _word = \"hello\"; let _empty : str<0> = \"\";";
 
     let expected = vec![
         Token::Keyword(Keyword::Let),
         Token::Ident("_word".to_string()),
         Token::Assign,
         Token::Str("hello".to_string()),
+        Token::Semicolon,
+        Token::Keyword(Keyword::Let),
+        Token::Ident("_empty".to_string()),
+        Token::Colon,
+        Token::Keyword(Keyword::Str),
+        Token::Less,
+        Token::Int(0.into()),
+        Token::Greater,
+        Token::Assign,
+        Token::Str("".to_string()),
+        Token::Semicolon,
+        Token::EOF,
     ];
     let mut lexer = Lexer::new(input);
 
@@ -425,6 +459,14 @@ fn test_eat_string_literal() {
     }
 }
 #[test]
+fn test_unterminated_string_literal() {
+    let mut lexer = Lexer::new("\"hello");
+    match lexer.next_token().unwrap().token() {
+        Token::Error(_) => {}
+        token => panic!("expected an error token, found {}", token),
+    }
+}
+#[test]
 fn test_eat_hex_int() {
     let input = "0x05";
 
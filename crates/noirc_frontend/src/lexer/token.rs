@@ -64,12 +64,18 @@ pub enum Token {
     Greater,
     // >=
     GreaterEqual,
+    // <<
+    ShiftLeft,
+    // >>
+    ShiftRight,
     // ==
     Equal,
     // !=
     NotEqual,
     // +
     Plus,
+    // ++
+    PlusPlus,
     // -
     Minus,
     // *
@@ -100,6 +106,8 @@ pub enum Token {
     RightBracket,
     // ->
     Arrow,
+    // =>
+    FatArrow,
     // |
     Pipe,
     // #
@@ -137,9 +145,12 @@ impl fmt::Display for Token {
             Token::LessEqual => write!(f, "<="),
             Token::Greater => write!(f, ">"),
             Token::GreaterEqual => write!(f, ">="),
+            Token::ShiftLeft => write!(f, "<<"),
+            Token::ShiftRight => write!(f, ">>"),
             Token::Equal => write!(f, "=="),
             Token::NotEqual => write!(f, "!="),
             Token::Plus => write!(f, "+"),
+            Token::PlusPlus => write!(f, "++"),
             Token::Minus => write!(f, "-"),
             Token::Star => write!(f, "*"),
             Token::Slash => write!(f, "/"),
@@ -155,6 +166,7 @@ impl fmt::Display for Token {
             Token::LeftBracket => write!(f, "["),
             Token::RightBracket => write!(f, "]"),
             Token::Arrow => write!(f, "->"),
+            Token::FatArrow => write!(f, "=>"),
             Token::Pipe => write!(f, "|"),
             Token::Pound => write!(f, "#"),
             Token::Comma => write!(f, ","),
@@ -392,7 +404,11 @@ pub enum Keyword {
     Crate,
     Fn,
     Struct,
+    Impl,
+    Trait,
+    Enum,
     If,
+    Match,
     Mod,
     Else,
     While,
@@ -401,6 +417,7 @@ pub enum Keyword {
     In,
     Use,
     Constrain,
+    Return,
     // Field types
     Pub,
     Priv,
@@ -410,8 +427,12 @@ pub enum Keyword {
     //
     // Let declarations will be for Structures and possibly closures, if they are added
     Let,
+    Mut,
     // Field type can only be used in Directive functions. They are explicitly for doing Field operations without applying constraints
     Field,
+    Bool,
+    // Strings are arrays of bytes, whose length is given as `str<N>`
+    Str,
 }
 
 impl fmt::Display for Keyword {
@@ -421,20 +442,28 @@ impl fmt::Display for Keyword {
             Keyword::Crate => write!(f, "crate"),
             Keyword::Fn => write!(f, "fn"),
             Keyword::Struct => write!(f, "struct"),
+            Keyword::Impl => write!(f, "impl"),
+            Keyword::Trait => write!(f, "trait"),
+            Keyword::Enum => write!(f, "enum"),
             Keyword::If => write!(f, "if"),
+            Keyword::Match => write!(f, "match"),
             Keyword::Mod => write!(f, "mod"),
             Keyword::For => write!(f, "for"),
             Keyword::In => write!(f, "in"),
             Keyword::Else => write!(f, "else"),
             Keyword::While => write!(f, "while"),
             Keyword::Constrain => write!(f, "constrain"),
+            Keyword::Return => write!(f, "return"),
             Keyword::Let => write!(f, "let"),
+            Keyword::Mut => write!(f, "mut"),
             Keyword::As => write!(f, "as"),
             Keyword::Use => write!(f, "use"),
             Keyword::SetPub => write!(f, "setpub"),
             Keyword::Pub => write!(f, "pub"),
             Keyword::Priv => write!(f, "priv"),
             Keyword::Field => write!(f, "Field"),
+            Keyword::Bool => write!(f, "bool"),
+            Keyword::Str => write!(f, "str"),
             Keyword::Const => write!(f, "const"),
         }
     }
@@ -448,16 +477,22 @@ impl Keyword {
         match word {
             "fn" => Some(Token::Keyword(Keyword::Fn)),
             "struct" => Some(Token::Keyword(Keyword::Struct)),
+            "impl" => Some(Token::Keyword(Keyword::Impl)),
+            "trait" => Some(Token::Keyword(Keyword::Trait)),
+            "enum" => Some(Token::Keyword(Keyword::Enum)),
             "dep" => Some(Token::Keyword(Keyword::Dep)),
             "crate" => Some(Token::Keyword(Keyword::Crate)),
             "if" => Some(Token::Keyword(Keyword::If)),
+            "match" => Some(Token::Keyword(Keyword::Match)),
             "mod" => Some(Token::Keyword(Keyword::Mod)),
             "for" => Some(Token::Keyword(Keyword::For)),
             "in" => Some(Token::Keyword(Keyword::In)),
             "else" => Some(Token::Keyword(Keyword::Else)),
             "while" => Some(Token::Keyword(Keyword::While)),
             "constrain" => Some(Token::Keyword(Keyword::Constrain)),
+            "return" => Some(Token::Keyword(Keyword::Return)),
             "let" => Some(Token::Keyword(Keyword::Let)),
+            "mut" => Some(Token::Keyword(Keyword::Mut)),
             "as" => Some(Token::Keyword(Keyword::As)),
             "use" => Some(Token::Keyword(Keyword::Use)),
             "true" => Some(Token::Bool(true)),
@@ -470,6 +505,8 @@ impl Keyword {
             "const" => Some(Token::Keyword(Keyword::Const)),
             // Native Types
             "Field" => Some(Token::Keyword(Keyword::Field)),
+            "bool" => Some(Token::Keyword(Keyword::Bool)),
+            "str" => Some(Token::Keyword(Keyword::Str)),
 
             "_" => Some(Token::Underscore),
             _ => None,
@@ -38,6 +38,8 @@ fn main() {
         println!("func name is {:?}", name);
         let func_id = match def_id {
             ModuleDefId::FunctionId(func_id) => func_id,
+            // Globals are also in the value namespace
+            ModuleDefId::GlobalId(_) => continue,
             _ => unreachable!(),
         };
 
@@ -1,14 +1,20 @@
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 
 use arena::{Arena, Index};
+use fm::FileId;
+use noir_field::FieldElement;
 use noirc_errors::Span;
 
-use crate::{Ident, Type};
+use crate::hir::def_map::ModuleId;
+use crate::{FunctionKind, Ident, Type};
 
 use crate::hir_def::{
     expr::HirExpression,
-    function::{FuncMeta, HirFunction},
+    function::{FuncMeta, GenericArgs, HirFunction, Param},
     stmt::HirStatement,
+    types::{EnumType, StructType, TraitMethod, TraitType},
 };
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -44,6 +50,25 @@ impl FuncId {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct StructId(Index);
+
+impl StructId {
+    //dummy id for error reporting
+    pub fn dummy_id() -> StructId {
+        StructId(Index::from_raw_parts(std::usize::MAX, 0))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct EnumId(Index);
+
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct GlobalId(Index);
+
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct TraitId(Index);
+
 macro_rules! into_index {
     ($id_type:ty) => {
         impl Into<Index> for $id_type {
@@ -88,6 +113,10 @@ enum Node {
     Ident(Ident),
     Statement(HirStatement),
     Expression(HirExpression),
+    Struct(Rc<RefCell<StructType>>),
+    Enum(Rc<EnumType>),
+    Global(Ident),
+    Trait(Rc<RefCell<TraitType>>),
 }
 
 #[derive(Debug, Clone)]
@@ -117,6 +146,34 @@ pub struct NodeInterner {
     // Further note, that an ExprId and an IdentId will never have the same underlying Index
     // Because we use one Arena to store all Definitions/Nodes
     id_to_type: HashMap<Index, Type>,
+
+    // Calls to generic functions, along with the values that the generics
+    // were bound to. These are replaced with calls to an instance of the
+    // function during monomorphisation.
+    generic_calls: Vec<(ExprId, FuncId, GenericArgs)>,
+    // Maps each generic function to its instances, along with the values
+    // of the generics that each instance was created for.
+    instances: HashMap<FuncId, Vec<(GenericArgs, FuncId)>>,
+
+    // The values of the global constants, which are evaluated at compile time
+    global_values: HashMap<GlobalId, FieldElement>,
+
+    // The functions declared in the impl blocks of each struct, keyed by their name
+    methods: HashMap<(StructId, String), FuncId>,
+
+    // The module of the impl block that each private method is declared in,
+    // along with the file of that module and the name of the method
+    private_methods: HashMap<FuncId, (ModuleId, FileId, Ident)>,
+    // The module of the function being type checked and the ancestors of that module,
+    // which are the modules whose private methods can be called from the function
+    visible_modules: Vec<ModuleId>,
+
+    // The traits that each struct implements
+    trait_impls: HashSet<(StructId, TraitId)>,
+
+    // The trait bounds of the function being type checked, which
+    // are used to look up methods on values of a generic type
+    trait_bounds: Vec<(Ident, TraitId)>,
 }
 
 impl Default for NodeInterner {
@@ -128,6 +185,14 @@ impl Default for NodeInterner {
             id_to_span: HashMap::new(),
             ident_to_name: HashMap::new(),
             id_to_type: HashMap::new(),
+            generic_calls: Vec::new(),
+            instances: HashMap::new(),
+            global_values: HashMap::new(),
+            methods: HashMap::new(),
+            private_methods: HashMap::new(),
+            visible_modules: Vec::new(),
+            trait_impls: HashSet::new(),
+            trait_bounds: Vec::new(),
         };
 
         // An empty block expression is used often, we add this into the `node` on startup
@@ -199,6 +264,226 @@ impl NodeInterner {
         *func = hir_func;
     }
 
+    /// Updates the underlying interned Expression.
+    ///
+    /// This is used to redirect calls to generic functions
+    /// to the instance of the function which is monomorphised.
+    pub fn update_expr(&mut self, expr_id: ExprId, hir_expr: HirExpression) {
+        let def = self
+            .nodes
+            .get_mut(expr_id.0)
+            .expect("ice: all expression ids should have definitions");
+
+        let expr = match def {
+            Node::Expression(expr) => expr,
+            _ => {
+                panic!("ice: all expression ids should correspond to a expression in the interner")
+            }
+        };
+        *expr = hir_expr;
+    }
+
+    /// Records a call to a generic function.
+    pub fn push_generic_call(&mut self, call_expr: ExprId, func_id: FuncId, args: GenericArgs) {
+        self.generic_calls.push((call_expr, func_id, args));
+    }
+
+    /// Returns the calls to generic functions which have not been monomorphised yet.
+    pub fn take_generic_calls(&mut self) -> Vec<(ExprId, FuncId, GenericArgs)> {
+        std::mem::take(&mut self.generic_calls)
+    }
+
+    /// Records that `instance` is the instance of the generic function `func_id` for `args`.
+    pub fn push_instance(&mut self, func_id: FuncId, args: GenericArgs, instance: FuncId) {
+        self.instances
+            .entry(func_id)
+            .or_default()
+            .push((args, instance));
+    }
+
+    /// Returns the instance of the generic function `func_id` for `args`, if it has been created.
+    pub fn instance(&self, func_id: &FuncId, args: &GenericArgs) -> Option<FuncId> {
+        let instances = self.instances.get(func_id)?;
+        instances
+            .iter()
+            .find(|(instance_args, _)| instance_args == args)
+            .map(|(_, instance)| *instance)
+    }
+
+    /// Intern a struct with no fields.
+    ///
+    /// Like functions, structs are eagerly interned to generate their
+    /// identifiers. The fields are resolved at a later point in time,
+    /// once all structs in the crate have an identifier.
+    pub fn push_empty_struct(&mut self, name: Ident, span: Span) -> StructId {
+        let index = self.nodes.insert_with(|index| {
+            let def = StructType::new(StructId(index), name, span);
+            Node::Struct(Rc::new(RefCell::new(def)))
+        });
+        StructId(index)
+    }
+
+    /// Intern an enum.
+    ///
+    /// Unlike structs, the variants of an enum do not need to be resolved,
+    /// so the definition is complete once it has been interned.
+    pub fn push_enum(&mut self, name: Ident, variants: Vec<Ident>, span: Span) -> EnumId {
+        let index = self.nodes.insert_with(|index| {
+            let def = EnumType::new(EnumId(index), name, variants, span);
+            Node::Enum(Rc::new(def))
+        });
+        EnumId(index)
+    }
+
+    /// Intern a trait with no methods.
+    ///
+    /// Like structs, traits are eagerly interned so that they can be added to
+    /// the scope of their module. The signatures of the methods are resolved later.
+    pub fn push_empty_trait(&mut self, name: Ident, span: Span) -> TraitId {
+        let index = self.nodes.insert_with(|index| {
+            let def = TraitType::new(TraitId(index), name, span);
+            Node::Trait(Rc::new(RefCell::new(def)))
+        });
+        TraitId(index)
+    }
+
+    /// Intern a global constant without its value.
+    ///
+    /// Like structs, globals are eagerly interned so that they can be added to
+    /// the scope of their module. Their values are evaluated once imports have been resolved.
+    pub fn push_empty_global(&mut self, name: Ident) -> GlobalId {
+        let span = name.0.span();
+        let id = GlobalId(self.nodes.insert(Node::Global(name)));
+        self.id_to_span.insert(id.0, span);
+        id
+    }
+
+    pub fn push_global_value(&mut self, global_id: GlobalId, value: FieldElement) {
+        self.global_values.insert(global_id, value);
+    }
+
+    /// Returns the value of a global constant, or None if it has not been evaluated
+    pub fn global_value(&self, global_id: &GlobalId) -> Option<FieldElement> {
+        self.global_values.get(global_id).copied()
+    }
+
+    /// Adds a function declared in an impl block of a struct.
+    ///
+    /// If the struct already has a function with the same name, it is
+    /// not replaced and its FuncId is returned instead.
+    pub fn add_method(
+        &mut self,
+        struct_id: StructId,
+        name: String,
+        func_id: FuncId,
+    ) -> Option<FuncId> {
+        match self.methods.entry((struct_id, name)) {
+            std::collections::hash_map::Entry::Occupied(entry) => Some(*entry.get()),
+            std::collections::hash_map::Entry::Vacant(entry) => {
+                entry.insert(func_id);
+                None
+            }
+        }
+    }
+
+    /// Returns the function with the given name declared in an impl block of the struct
+    pub fn lookup_method(&self, struct_id: StructId, name: &str) -> Option<FuncId> {
+        self.methods.get(&(struct_id, name.to_owned())).copied()
+    }
+
+    /// Records that a method declared without `pub` can only be used from
+    /// the module of its impl block and the descendants of that module.
+    pub fn add_private_method(
+        &mut self,
+        func_id: FuncId,
+        module: ModuleId,
+        file_id: FileId,
+        name: Ident,
+    ) {
+        self.private_methods
+            .insert(func_id, (module, file_id, name));
+    }
+
+    /// Returns the module of the impl block that a private method is declared in,
+    /// along with the file of that module and the name of the method.
+    /// Returns None if the function is not a private method.
+    pub fn private_method(&self, func_id: &FuncId) -> Option<&(ModuleId, FileId, Ident)> {
+        self.private_methods.get(func_id)
+    }
+
+    /// Sets the modules whose private methods can be called from the function being type checked
+    pub fn set_visible_modules(&mut self, modules: Vec<ModuleId>) {
+        self.visible_modules = modules;
+    }
+
+    /// Returns true if the private methods declared in `module` can be
+    /// called from the function being type checked
+    pub fn is_visible_module(&self, module: &ModuleId) -> bool {
+        self.visible_modules.contains(module)
+    }
+
+    /// Records that the struct implements the trait.
+    /// Returns false if the struct already implemented the trait.
+    pub fn add_trait_impl(&mut self, struct_id: StructId, trait_id: TraitId) -> bool {
+        self.trait_impls.insert((struct_id, trait_id))
+    }
+
+    /// Returns true if the type implements the trait.
+    /// Currently, only structs can implement traits.
+    pub fn implements_trait(&self, typ: &Type, trait_id: TraitId) -> bool {
+        match typ {
+            Type::Struct(struct_type) => self
+                .trait_impls
+                .contains(&(struct_type.borrow().id, trait_id)),
+            _ => false,
+        }
+    }
+
+    /// Sets the trait bounds of the function being type checked
+    pub fn set_trait_bounds(&mut self, trait_bounds: Vec<(Ident, TraitId)>) {
+        self.trait_bounds = trait_bounds;
+    }
+
+    /// Returns the method with the given name of the traits that `generic` is bounded by,
+    /// in the function being type checked
+    pub fn lookup_trait_method(&self, generic: &Ident, name: &str) -> Option<TraitMethod> {
+        self.trait_bounds
+            .iter()
+            .filter(|(bounded, _)| bounded == generic)
+            .find_map(|(_, trait_id)| self.get_trait(*trait_id).borrow().method(name).cloned())
+    }
+
+    /// Interns a function with the signature of a trait method, in which `Self` is `generic`.
+    ///
+    /// The body of a generic function is type checked once against its generics, before it
+    /// is instantiated. A call to a method of a trait bound is then a call to this function.
+    /// In each instance, the method is resolved through the type that the generic is bound to.
+    pub fn push_trait_method(&mut self, generic: &Ident, method: TraitMethod) -> FuncId {
+        let (parameter_types, return_type) = method.signature(&Type::Generic(generic.clone()));
+        let parameters: Vec<_> = method
+            .parameters
+            .into_iter()
+            .zip(parameter_types)
+            .map(|((param, _), typ)| Param(self.push_ident(param), typ))
+            .collect();
+
+        let func_id = self.push_empty_fn();
+        let func_meta = FuncMeta {
+            name: method.name.0.contents,
+            kind: FunctionKind::Normal,
+            attributes: None,
+            generics: Vec::new(),
+            type_generics: Vec::new(),
+            trait_bounds: Vec::new(),
+            parameters: parameters.into(),
+            return_type,
+            return_values: Vec::new(),
+            has_body: false,
+        };
+        self.push_fn_meta(func_meta, func_id);
+        func_id
+    }
+
     ///Interns a function's metadata.
     ///
     /// Note that the FuncId has been created already.
@@ -257,6 +542,42 @@ impl NodeInterner {
             _ => panic!("ice: all function ids should correspond to a function in the interner"),
         }
     }
+    /// Returns the interned struct definition corresponding to `struct_id`
+    pub fn get_struct(&self, struct_id: StructId) -> Rc<RefCell<StructType>> {
+        let def = self
+            .nodes
+            .get(struct_id.0)
+            .expect("ice: all struct ids should have definitions");
+
+        match def {
+            Node::Struct(def) => return def.clone(),
+            _ => panic!("ice: all struct ids should correspond to a struct in the interner"),
+        }
+    }
+    /// Returns the interned enum definition corresponding to `enum_id`
+    pub fn get_enum(&self, enum_id: EnumId) -> Rc<EnumType> {
+        let def = self
+            .nodes
+            .get(enum_id.0)
+            .expect("ice: all enum ids should have definitions");
+
+        match def {
+            Node::Enum(def) => return def.clone(),
+            _ => panic!("ice: all enum ids should correspond to an enum in the interner"),
+        }
+    }
+    /// Returns the interned trait definition corresponding to `trait_id`
+    pub fn get_trait(&self, trait_id: TraitId) -> Rc<RefCell<TraitType>> {
+        let def = self
+            .nodes
+            .get(trait_id.0)
+            .expect("ice: all trait ids should have definitions");
+
+        match def {
+            Node::Trait(def) => return def.clone(),
+            _ => panic!("ice: all trait ids should correspond to a trait in the interner"),
+        }
+    }
     /// Returns the interned meta data corresponding to `func_id`
     pub fn function_meta(&self, func_id: &FuncId) -> FuncMeta {
         self.func_meta
@@ -53,7 +53,16 @@ mod test {
 
     #[test]
     fn valid_syntax() {
-        let vectors = vec![" + 6", " - k", " + (x + a)", " * (x + a) + (x - 4)"];
+        let vectors = vec![
+            " + 6",
+            " - k",
+            " + (x + a)",
+            " * (x + a) + (x - 4)",
+            " % k",
+            " / 2 % (x + a)",
+            " << 2 >> k",
+            " | (x & a)",
+        ];
 
         for src in vectors {
             let mut parser = test_parse(src);
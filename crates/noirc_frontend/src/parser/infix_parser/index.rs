@@ -3,26 +3,18 @@ use super::*;
 pub struct IndexParser;
 
 impl IndexParser {
-    /// Parses an Index Expression of the form:
+    /// Parses an Index Expression or a Slice Expression of the form:
     ///
     /// EXPR[EXPR]
+    /// EXPR[EXPR..EXPR]
+    ///
+    /// The collection may be any expression, so that the elements
+    /// of multi-dimensional arrays can be indexed with `matrix[i][j]`
     ///
     /// Cursor Start : `[`
     ///
     /// Cursor End : `]`
-    pub fn parse(parser: &mut Parser, collection_name: Expression) -> ParserExprKindResult {
-        // XXX: We will clean up these unnecessary format! allocations in the refactor after the next
-        let msg = format!("expected an identifier for the collection name. Arbitrary expressions are yet to arrive");
-        let err = ParserErrorKind::UnstructuredError {
-            message: msg,
-            span: collection_name.span,
-        };
-
-        let collection_name = match collection_name.kind {
-            ExpressionKind::Path(path) => path.into_ident().ok_or(err)?,
-            _ => return Err(err),
-        };
-
+    pub fn parse(parser: &mut Parser, collection: Expression) -> ParserExprKindResult {
         // Current token is '['
         //
         // Bump Cursor.
@@ -31,17 +23,33 @@ impl IndexParser {
         // this will put the cursor at the beginning of the index's token
         //
         parser.advance_tokens();
-        let index = parser.parse_expression(Precedence::Lowest)?;
+        let index = parser.parse_expression_allow_constructors(Precedence::Lowest)?;
+
+        // Check if this is a slice, in which case the index is its start
+        if parser.peek_token == Token::DoubleDot {
+            // Current token is the end of the start expression
+            //
+            // Bump cursor twice. Current token is the beginning of the end expression
+            parser.advance_tokens();
+            parser.advance_tokens();
+            let end = parser.parse_expression_allow_constructors(Precedence::Lowest)?;
+
+            parser.peek_check_variant_advance(&Token::RightBracket)?;
+
+            let slice_expr = SliceExpression {
+                collection,
+                start: index,
+                end,
+            };
+            return Ok(ExpressionKind::Slice(Box::new(slice_expr)));
+        }
 
         // Current token is now at the end of the expression
         //
         // Peek ahead and check if the next token is `]`
         parser.peek_check_variant_advance(&Token::RightBracket)?;
 
-        let index_expr = IndexExpression {
-            collection_name,
-            index,
-        };
+        let index_expr = IndexExpression { collection, index };
 
         Ok(ExpressionKind::Index(Box::new(index_expr)))
     }
@@ -51,11 +59,23 @@ impl IndexParser {
 mod test {
 
     use super::IndexParser;
-    use crate::parser::{dummy_expr, test_parse};
+    use crate::{
+        parser::{dummy_expr, test_parse, Precedence},
+        token::Token,
+        ExpressionKind,
+    };
 
     #[test]
     fn valid_syntax() {
-        let vectors = vec!["[9]", "[x+a]", "[foo+5]", "[bar]"];
+        let vectors = vec![
+            "[9]",
+            "[x+a]",
+            "[foo+5]",
+            "[bar]",
+            "[2..10]",
+            "[0..N - 1]",
+            "[x+1..y]",
+        ];
 
         for src in vectors {
             let mut parser = test_parse(src);
@@ -70,4 +90,49 @@ mod test {
             assert_eq!(end, crate::token::Token::RightBracket);
         }
     }
+
+    #[test]
+    fn nested_index() {
+        let mut parser = test_parse("matrix[i][j + 1]");
+        let expr = parser.parse_expression(Precedence::Lowest).unwrap();
+
+        // The row is indexed first, then the element in the row
+        let index_expr = match expr.kind {
+            ExpressionKind::Index(index_expr) => index_expr,
+            _ => unreachable!("expected an index expression"),
+        };
+        assert!(matches!(
+            index_expr.collection.kind,
+            ExpressionKind::Index(_)
+        ));
+        assert_eq!(parser.curr_token, Token::RightBracket);
+    }
+
+    #[test]
+    fn slice() {
+        let mut parser = test_parse("arr[2..10][0]");
+        let expr = parser.parse_expression(Precedence::Lowest).unwrap();
+
+        // The slice is indexed like any other array
+        let index_expr = match expr.kind {
+            ExpressionKind::Index(index_expr) => index_expr,
+            _ => unreachable!("expected an index expression"),
+        };
+        let slice_expr = match index_expr.collection.kind {
+            ExpressionKind::Slice(slice_expr) => slice_expr,
+            _ => unreachable!("expected a slice expression"),
+        };
+        assert!(slice_expr.start.kind.is_integer());
+        assert!(slice_expr.end.kind.is_integer());
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        let vectors = vec!["[2..]", "[..2]", "[2..10", "[2..10..12]"];
+
+        for src in vectors {
+            let mut parser = test_parse(src);
+            IndexParser::parse(&mut parser, dummy_expr()).unwrap_err();
+        }
+    }
 }
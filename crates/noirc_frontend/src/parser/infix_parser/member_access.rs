@@ -0,0 +1,107 @@
+use super::*;
+use noirc_errors::Spanned;
+
+pub struct MemberAccessParser;
+
+impl MemberAccessParser {
+    /// Parses a Member Access Expression of the form:
+    ///
+    /// EXPR.IDENT or EXPR.INTEGER
+    ///
+    /// The elements of a tuple are accessed using their index.
+    ///
+    /// If the member is followed by a list of arguments, this is a method call instead:
+    ///
+    /// EXPR.IDENT(<EXPR>, <EXPR>, ...)
+    ///
+    /// Cursor Start : `.`
+    ///
+    /// Cursor End : `IDENT` or `)` for method calls
+    pub fn parse(parser: &mut Parser, lhs: Expression) -> ParserExprKindResult {
+        // Current token is '.'
+        //
+        // Peek ahead and check if the next token is the field name or tuple index
+        let rhs: Ident = match parser.peek_token.token() {
+            Token::Int(index) if index.fits_in_u128() => {
+                let index = index.to_u128();
+                parser.advance_tokens();
+                let span = parser.curr_token.into_span();
+                Spanned::from(span, index.to_string()).into()
+            }
+            _ => {
+                parser.peek_check_kind_advance(TokenKind::Ident)?;
+                parser.curr_token.clone().into()
+            }
+        };
+
+        if parser.peek_token == Token::LeftParen {
+            // Current token is the method name
+            //
+            // Bump cursor. Current token is now `(`
+            parser.advance_tokens();
+            let arguments = parser.parse_comma_separated_argument_list(Token::RightParen)?;
+
+            return Ok(ExpressionKind::MethodCall(Box::new(MethodCallExpression {
+                object: lhs,
+                method_name: rhs,
+                arguments,
+            })));
+        }
+
+        Ok(ExpressionKind::MemberAccess(Box::new(
+            MemberAccessExpression { lhs, rhs },
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::MemberAccessParser;
+    use crate::parser::{dummy_expr, test_parse};
+
+    #[test]
+    fn valid_syntax() {
+        let mut parser = test_parse(".foo");
+
+        let start = parser.curr_token.clone();
+        MemberAccessParser::parse(&mut parser, dummy_expr()).unwrap();
+        let end = parser.curr_token.clone();
+
+        assert_eq!(start, crate::token::Token::Dot);
+        assert_eq!(end, crate::token::Token::Ident("foo".to_string()));
+    }
+    #[test]
+    fn valid_tuple_index_syntax() {
+        let mut parser = test_parse(".1");
+
+        MemberAccessParser::parse(&mut parser, dummy_expr()).unwrap();
+        let end = parser.curr_token.clone();
+
+        assert_eq!(end, crate::token::Token::Int(1.into()));
+    }
+    #[test]
+    fn valid_method_call_syntax() {
+        let mut parser = test_parse(".add(x, y)");
+
+        let expr = MemberAccessParser::parse(&mut parser, dummy_expr()).unwrap();
+        let end = parser.curr_token.clone();
+
+        assert_eq!(end, crate::token::Token::RightParen);
+        match expr {
+            crate::ExpressionKind::MethodCall(method_call) => {
+                assert_eq!(method_call.method_name.0.contents, "add");
+                assert_eq!(method_call.arguments.len(), 2);
+            }
+            _ => unreachable!("expected a method call"),
+        }
+    }
+    #[test]
+    fn invalid_syntax() {
+        let vectors = vec![".", ".[foo]", ".foo(x", ".foo(,)"];
+
+        for src in vectors {
+            MemberAccessParser::parse(&mut test_parse(src), dummy_expr()).unwrap_err();
+        }
+    }
+}
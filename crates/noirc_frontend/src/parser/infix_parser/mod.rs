@@ -2,18 +2,21 @@ mod binary;
 mod call;
 mod cast;
 mod index;
+mod member_access;
 
 use binary::BinaryParser;
 use call::CallParser;
 use cast::CastParser;
 use index::IndexParser;
+use member_access::MemberAccessParser;
 
 use super::Precedence;
 use crate::ast::{BinaryOp, BinaryOpKind};
 use crate::ast::{
-    CallExpression, CastExpression, Expression, ExpressionKind, IndexExpression, InfixExpression,
+    CallExpression, CastExpression, Expression, ExpressionKind, Ident, IndexExpression,
+    InfixExpression, MemberAccessExpression, MethodCallExpression, SliceExpression,
 };
-use crate::token::Token;
+use crate::token::{Token, TokenKind};
 
 use super::parser::{ParserExprKindResult, ParserExprResult};
 use super::Parser;
@@ -26,6 +29,7 @@ pub enum InfixParser {
     Call,
     Index,
     Cast,
+    MemberAccess,
 }
 
 impl InfixParser {
@@ -35,6 +39,7 @@ impl InfixParser {
             InfixParser::Call => span_parser(parser, left, CallParser::parse),
             InfixParser::Index => span_parser(parser, left, IndexParser::parse),
             InfixParser::Cast => span_parser(parser, left, CastParser::parse),
+            InfixParser::MemberAccess => span_parser(parser, left, MemberAccessParser::parse),
         }
     }
 }
@@ -3,7 +3,10 @@ mod infix_parser;
 mod parser;
 mod prefix_parser;
 
-use crate::{ast::ImportStatement, NoirFunction};
+use crate::{
+    ast::{ConstStatement, ImportStatement},
+    NoirEnum, NoirFunction, NoirImpl, NoirStruct, NoirTrait, Visibility,
+};
 use crate::{
     token::{Keyword, SpannedToken, Token},
     Ident,
@@ -15,7 +18,12 @@ pub use parser::{Parser, ParserExprKindResult, ParserExprResult};
 pub struct ParsedModule {
     pub imports: Vec<ImportStatement>,
     pub functions: Vec<NoirFunction>,
-    pub module_decls: Vec<Ident>,
+    pub types: Vec<NoirStruct>,
+    pub enums: Vec<NoirEnum>,
+    pub impls: Vec<NoirImpl>,
+    pub traits: Vec<NoirTrait>,
+    pub globals: Vec<(ConstStatement, Visibility)>,
+    pub module_decls: Vec<(Ident, Visibility)>,
 }
 
 impl ParsedModule {
@@ -23,6 +31,11 @@ impl ParsedModule {
         ParsedModule {
             imports: Vec::with_capacity(cap),
             functions: Vec::with_capacity(cap),
+            types: Vec::new(),
+            enums: Vec::new(),
+            impls: Vec::new(),
+            traits: Vec::new(),
+            globals: Vec::new(),
             module_decls: Vec::new(),
         }
     }
@@ -30,11 +43,26 @@ impl ParsedModule {
     fn push_function(&mut self, func: NoirFunction) {
         self.functions.push(func);
     }
-    fn push_import(&mut self, import_stmt: ImportStatement) {
-        self.imports.push(import_stmt);
+    fn push_type(&mut self, typ: NoirStruct) {
+        self.types.push(typ);
+    }
+    fn push_enum(&mut self, r#enum: NoirEnum) {
+        self.enums.push(r#enum);
+    }
+    fn push_impl(&mut self, r#impl: NoirImpl) {
+        self.impls.push(r#impl);
+    }
+    fn push_trait(&mut self, r#trait: NoirTrait) {
+        self.traits.push(r#trait);
+    }
+    fn push_global(&mut self, global: ConstStatement, visibility: Visibility) {
+        self.globals.push((global, visibility));
+    }
+    fn push_imports(&mut self, import_stmts: Vec<ImportStatement>) {
+        self.imports.extend(import_stmts);
     }
-    fn push_module_decl(&mut self, mod_name: Ident) {
-        self.module_decls.push(mod_name);
+    fn push_module_decl(&mut self, mod_name: Ident, visibility: Visibility) {
+        self.module_decls.push((mod_name, visibility));
     }
 }
 
@@ -43,34 +71,42 @@ pub enum Precedence {
     Lowest,
     Equals,
     LessGreater,
+    Shift,
     Sum,
     Product,
     Prefix,
     Call,
     Index,
+    MemberAccess,
 }
 impl Precedence {
     // Higher the number, the higher(more priority) the precedence
     // XXX: Check the precedence is correct for operators
     fn token_precedence(tok: &Token) -> Precedence {
         match tok {
-            Token::Assign => Precedence::Equals,
+            // Assignments are statements, so an expression never continues past an `=`
+            Token::Assign => Precedence::Lowest,
             Token::Equal => Precedence::Equals,
             Token::NotEqual => Precedence::Equals,
             Token::Less => Precedence::LessGreater,
             Token::LessEqual => Precedence::LessGreater,
             Token::Greater => Precedence::LessGreater,
             Token::GreaterEqual => Precedence::LessGreater,
+            Token::ShiftLeft => Precedence::Shift,
+            Token::ShiftRight => Precedence::Shift,
             Token::Ampersand => Precedence::Sum,
             Token::Caret => Precedence::Sum,
             Token::Pipe => Precedence::Sum,
             Token::Plus => Precedence::Sum,
+            Token::PlusPlus => Precedence::Sum,
             Token::Minus => Precedence::Sum,
             Token::Slash => Precedence::Product,
+            Token::Percent => Precedence::Product,
             Token::Star => Precedence::Product,
             Token::Keyword(Keyword::As) => Precedence::Prefix,
             Token::LeftParen => Precedence::Call,
             Token::LeftBracket => Precedence::Index,
+            Token::Dot => Precedence::MemberAccess,
             _ => Precedence::Lowest,
         }
     }
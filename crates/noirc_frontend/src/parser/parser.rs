@@ -2,12 +2,14 @@ use super::{errors::ParserErrorKind, ParsedModule, Precedence};
 use crate::lexer::Lexer;
 use crate::token::{Keyword, SpannedToken, Token, TokenKind};
 use crate::{
-    ast::{ArraySize, Expression, ExpressionKind, Statement, Type},
-    FieldElementType,
+    ast::{
+        ArraySize, AssignStatement, Expression, ExpressionKind, LValue, Literal, Statement, Type,
+    },
+    FieldElementType, Visibility,
 };
 
 use super::infix_parser::InfixParser;
-use super::prefix_parser::PrefixParser;
+use super::prefix_parser::{PathParser, PrefixParser};
 
 pub type ParserResult<T> = Result<T, ParserErrorKind>;
 pub type ParserExprKindResult = ParserResult<ExpressionKind>;
@@ -22,6 +24,10 @@ pub struct Parser<'a> {
     pub(crate) curr_token: SpannedToken,
     pub(crate) peek_token: SpannedToken,
     pub(crate) errors: Vec<ParserErrorKind>,
+    // Struct literals are not allowed in places where a `{` could also
+    // start a block, such as the condition of an if expression or a for loop range.
+    // `if x {}` would otherwise be parsed as the start of a struct literal.
+    pub(crate) no_constructors: bool,
 }
 
 impl<'a> Parser<'a> {
@@ -34,6 +40,7 @@ impl<'a> Parser<'a> {
             curr_token,
             peek_token,
             errors: Vec::new(),
+            no_constructors: false,
         }
     }
     pub fn from_src(src: &'a str) -> Self {
@@ -113,30 +120,65 @@ impl<'a> Parser<'a> {
 
     /// A Program corresponds to a single module
     pub fn parse_program(&mut self) -> Result<ParsedModule, &Vec<ParserErrorKind>> {
-        use super::prefix_parser::{FuncParser, ModuleParser, UseParser};
+        use super::prefix_parser::{
+            DeclarationParser, EnumParser, FuncParser, ImplParser, ModuleParser, StructParser,
+            TraitParser, UseParser,
+        };
 
         let mut program = ParsedModule::with_capacity(self.lexer.by_ref().approx_len());
 
         while self.curr_token != Token::EOF {
+            let visibility = match self.parse_item_visibility() {
+                Ok(visibility) => visibility,
+                Err(err) => {
+                    self.errors.push(err);
+                    return Err(&self.errors);
+                }
+            };
+
             match self.curr_token.clone().into() {
                 Token::Attribute(attr) => {
                     self.advance_tokens(); // Skip the attribute
-                    let func_def = FuncParser::parse_fn_definition(self, Some(attr));
+
+                    // The visibility of a function comes after its attribute
+                    let func_def = self.parse_item_visibility().and_then(|visibility| {
+                        FuncParser::parse_fn_definition(self, Some(attr), visibility)
+                    });
                     self.on_value(func_def, |value| program.push_function(value));
                 }
                 Token::Keyword(Keyword::Fn) => {
-                    let func_def = FuncParser::parse_fn_definition(self, None);
+                    let func_def = FuncParser::parse_fn_definition(self, None, visibility);
                     self.on_value(func_def, |value| program.push_function(value));
                 }
+                Token::Keyword(Keyword::Struct) => {
+                    let struct_def = StructParser::parse_struct_definition(self, visibility);
+                    self.on_value(struct_def, |value| program.push_type(value));
+                }
+                Token::Keyword(Keyword::Enum) => {
+                    let enum_def = EnumParser::parse_enum_definition(self, visibility);
+                    self.on_value(enum_def, |value| program.push_enum(value));
+                }
+                Token::Keyword(Keyword::Impl) => {
+                    let impl_def = ImplParser::parse_impl(self);
+                    self.on_value(impl_def, |value| program.push_impl(value));
+                }
+                Token::Keyword(Keyword::Trait) => {
+                    let trait_def = TraitParser::parse_trait_definition(self, visibility);
+                    self.on_value(trait_def, |value| program.push_trait(value));
+                }
                 Token::Keyword(Keyword::Mod) => {
                     let parsed_mod = ModuleParser::parse_decl(self);
                     self.on_value(parsed_mod, |module_identifier| {
-                        program.push_module_decl(module_identifier)
+                        program.push_module_decl(module_identifier, visibility)
                     });
                 }
                 Token::Keyword(Keyword::Use) => {
-                    let import_stmt = UseParser::parse(self);
-                    self.on_value(import_stmt, |value| program.push_import(value));
+                    let import_stmts = UseParser::parse(self, visibility);
+                    self.on_value(import_stmts, |values| program.push_imports(values));
+                }
+                Token::Keyword(Keyword::Const) => {
+                    let global = DeclarationParser::parse_global(self);
+                    self.on_value(global, |value| program.push_global(value, visibility));
                 }
                 Token::Comment(_) => {
                     // This is a comment outside of a function.
@@ -145,8 +187,8 @@ impl<'a> Parser<'a> {
                     // XXX: Maybe we can follow Rust and say by default all public functions need documentation?
                 }
                 tok => {
-                    // XXX: We may allow global constants. We can use a subenum to remove the wildcard pattern
-                    let expected_tokens = r#" expected "`mod`, `use`,`fn` `#`"#;
+                    // XXX: We can use a subenum to remove the wildcard pattern
+                    let expected_tokens = r#" expected "`mod`, `use`,`fn`, `struct`, `enum`, `impl`, `trait`, `const`, `pub` `#`"#;
                     let err = ParserErrorKind::UnstructuredError {
                         span: self.curr_token.into_span(),
                         message: format!("found `{}`. {}", tok, expected_tokens), // XXX: Fix in next refactor, avoid allocations with error messages
@@ -167,6 +209,43 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses the visibility of an item declared at the module level.
+    /// Items which are not declared with `pub` are private.
+    ///
+    /// Cursor Start : `pub` or the first token of the item
+    ///
+    /// Cursor End : The first token of the item
+    fn parse_item_visibility(&mut self) -> ParserResult<Visibility> {
+        if self.curr_token != Token::Keyword(Keyword::Pub) {
+            return Ok(Visibility::Private);
+        }
+
+        // Only the items which are added to the scope of a module have a visibility.
+        // An import declared with `pub` re-exports the items that it imports
+        match self.peek_token.token() {
+            Token::Keyword(Keyword::Fn)
+            | Token::Keyword(Keyword::Struct)
+            | Token::Keyword(Keyword::Enum)
+            | Token::Keyword(Keyword::Trait)
+            | Token::Keyword(Keyword::Mod)
+            | Token::Keyword(Keyword::Use)
+            | Token::Keyword(Keyword::Const) => {}
+            tok => {
+                let message = format!(
+                    "found `{}`. expected `fn`, `struct`, `enum`, `trait`, `mod`, `use` or `const` after `pub`",
+                    tok
+                );
+                return Err(ParserErrorKind::UnstructuredError {
+                    span: self.peek_token.into_span(),
+                    message,
+                });
+            }
+        }
+
+        self.advance_tokens();
+        Ok(Visibility::Public)
+    }
+
     fn on_value<T, F>(&mut self, parser_res: ParserResult<T>, mut func: F)
     where
         F: FnMut(T),
@@ -211,7 +290,7 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_statement(&mut self) -> ParserStmtResult {
-        use crate::parser::prefix_parser::{ConstrainParser, DeclarationParser};
+        use crate::parser::prefix_parser::{ConstrainParser, DeclarationParser, ReturnParser};
 
         let stmt = match self.curr_token.token() {
             tk if tk.can_start_declaration() => {
@@ -225,9 +304,16 @@ impl<'a> Parser<'a> {
             Token::Keyword(Keyword::Constrain) => {
                 Statement::Constrain(ConstrainParser::parse_statement(self)?)
             }
+            Token::Keyword(Keyword::Return) => {
+                Statement::Return(ReturnParser::parse_statement(self)?)
+            }
             _ => {
                 let expr = self.parse_expression_statement()?;
 
+                if self.peek_token == Token::Assign {
+                    return Ok(Statement::Assign(self.parse_assign_statement(expr)?));
+                }
+
                 // Check if the next token is a semi-colon
                 // If it is, it is a SemiExpr
                 if self.peek_token == Token::Semicolon {
@@ -241,6 +327,47 @@ impl<'a> Parser<'a> {
         return Ok(stmt);
     }
 
+    /// Parses statements of the form
+    /// - LVALUE = EXPR;
+    ///
+    /// Cursor Start : `LVALUE`
+    ///
+    /// Cursor End : `;`
+    ///
+    /// The lvalue has already been parsed as an expression, since we only know that
+    /// the statement is an assignment once we see the `=`
+    fn parse_assign_statement(
+        &mut self,
+        lvalue: Expression,
+    ) -> Result<AssignStatement, ParserErrorKind> {
+        let lvalue = Parser::expression_to_lvalue(lvalue)?;
+
+        // Advance past the `=` and onto the start of the expression
+        self.advance_tokens();
+        self.advance_tokens();
+
+        let expression = self.parse_expression(Precedence::Lowest)?;
+        self.peek_check_variant_advance(&Token::Semicolon)?;
+
+        Ok(AssignStatement { lvalue, expression })
+    }
+
+    // Converts an expression which is being assigned to into an lvalue.
+    // Array elements are assigned to by indexing into a variable, possibly several times
+    fn expression_to_lvalue(lvalue: Expression) -> Result<LValue, ParserErrorKind> {
+        match lvalue.kind {
+            ExpressionKind::Path(path) if path.is_ident() => Ok(LValue::Ident(path.last_segment())),
+            ExpressionKind::Index(index_expr) => Ok(LValue::Index {
+                array: Box::new(Parser::expression_to_lvalue(index_expr.collection)?),
+                index: index_expr.index,
+            }),
+            _ => Err(ParserErrorKind::UnstructuredError {
+                message: "only variables and array elements can be assigned to".to_string(),
+                span: lvalue.span,
+            }),
+        }
+    }
+
     fn parse_expression_statement(&mut self) -> ParserExprResult {
         self.parse_expression(Precedence::Lowest)
     }
@@ -275,10 +402,37 @@ impl<'a> Parser<'a> {
 
         return Ok(left_exp);
     }
+    /// Parses an expression where struct literals are not allowed.
+    ///
+    /// Struct literals are still allowed inside of parenthesis, since
+    /// there is no ambiguity there.
+    pub(crate) fn parse_expression_no_constructors(
+        &mut self,
+        precedence: Precedence,
+    ) -> ParserExprResult {
+        let old_value = std::mem::replace(&mut self.no_constructors, true);
+        let expr = self.parse_expression(precedence);
+        self.no_constructors = old_value;
+        expr
+    }
+    /// Parses an expression, regardless of whether we are in the
+    /// condition of an if expression or a for loop range
+    pub(crate) fn parse_expression_allow_constructors(
+        &mut self,
+        precedence: Precedence,
+    ) -> ParserExprResult {
+        let old_value = std::mem::replace(&mut self.no_constructors, false);
+        let expr = self.parse_expression(precedence);
+        self.no_constructors = old_value;
+        expr
+    }
+
     fn choose_prefix_parser(&self) -> Option<PrefixParser> {
         match self.curr_token.token() {
             Token::Keyword(Keyword::If) => Some(PrefixParser::If),
+            Token::Keyword(Keyword::Match) => Some(PrefixParser::Match),
             Token::Keyword(Keyword::For) => Some(PrefixParser::For),
+            Token::Keyword(Keyword::While) => Some(PrefixParser::While),
             Token::LeftBracket => Some(PrefixParser::Array),
             x if x.kind() == TokenKind::Ident => Some(PrefixParser::Path),
             x if x.kind() == TokenKind::Literal => Some(PrefixParser::Literal),
@@ -291,8 +445,10 @@ impl<'a> Parser<'a> {
     fn choose_infix_parser(&self) -> Option<InfixParser> {
         match self.peek_token.token() {
             Token::Plus
+            | Token::PlusPlus
             | Token::Minus
             | Token::Slash
+            | Token::Percent
             | Token::Pipe
             | Token::Ampersand
             | Token::Caret
@@ -301,12 +457,15 @@ impl<'a> Parser<'a> {
             | Token::LessEqual
             | Token::Greater
             | Token::GreaterEqual
+            | Token::ShiftLeft
+            | Token::ShiftRight
             | Token::Equal
             | Token::Assign
             | Token::NotEqual => Some(InfixParser::Binary),
             Token::Keyword(Keyword::As) => Some(InfixParser::Cast),
             Token::LeftParen => Some(InfixParser::Call),
             Token::LeftBracket => Some(InfixParser::Index),
+            Token::Dot => Some(InfixParser::MemberAccess),
             _ => None,
         }
     }
@@ -345,7 +504,7 @@ impl<'a> Parser<'a> {
         // Parse the first element, implicitly assuming that `parse_expression`
         // does not advance the token from what it has just parsed
         self.advance_tokens();
-        arguments.push(self.parse_expression(Precedence::Lowest)?);
+        arguments.push(self.parse_expression_allow_constructors(Precedence::Lowest)?);
 
         while self.peek_token == Token::Comma {
             self.advance_tokens();
@@ -358,7 +517,7 @@ impl<'a> Parser<'a> {
 
             self.advance_tokens();
 
-            arguments.push(self.parse_expression(Precedence::Lowest)?);
+            arguments.push(self.parse_expression_allow_constructors(Precedence::Lowest)?);
         }
 
         self.peek_check_variant_advance(&closing_token)?;
@@ -409,8 +568,12 @@ impl<'a> Parser<'a> {
         // If we get into this function, then the user is specifying a type
         match self.curr_token.token() {
             Token::Keyword(Keyword::Field) => Ok(Type::FieldElement(field_type)),
+            Token::Keyword(Keyword::Bool) => self.parse_bool_type(field_type),
             Token::IntType(int_type) => Ok(Type::from_int_tok(field_type, int_type)),
             Token::LeftBracket => self.parse_array_type(field_type),
+            Token::Keyword(Keyword::Str) => self.parse_string_type(field_type),
+            Token::LeftParen => self.parse_tuple_type(field_type),
+            Token::Ident(_) => self.parse_named_type(field_type),
             k => {
                 let message = format!("Expected a type, found {}", k);
                 return Err(ParserErrorKind::UnstructuredError {
@@ -441,57 +604,170 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Cursor Start : `bool`
+    ///
+    /// Cursor End : `bool`
+    fn parse_bool_type(&mut self, field_type: FieldElementType) -> Result<Type, ParserErrorKind> {
+        // XXX: Booleans are always private, until the Bool type carries a visibility
+        if field_type != FieldElementType::Private {
+            let message =
+                format!("a visibility cannot be applied to a bool type. Only `priv` is allowed");
+            return Err(ParserErrorKind::UnstructuredError {
+                message,
+                span: self.curr_token.into_span(),
+            });
+        }
+
+        Ok(Type::Bool)
+    }
+
+    /// Parses a user defined type, such as a struct.
+    ///
+    /// Cursor Start : `FIRST_PATH_SEGMENT`
+    ///
+    /// Cursor End : `LAST_PATH_SEGMENT`
+    fn parse_named_type(&mut self, field_type: FieldElementType) -> Result<Type, ParserErrorKind> {
+        let start_span = self.curr_token.into_span();
+
+        // The visibility of a struct is determined by the visibility of its fields
+        if field_type != FieldElementType::Private {
+            let message =
+                format!("a visibility cannot be applied to a struct type. Only `priv` is allowed");
+            return Err(ParserErrorKind::UnstructuredError {
+                message,
+                span: start_span,
+            });
+        }
+
+        let path = PathParser::parse_path(self)?;
+
+        Ok(Type::Unresolved(path))
+    }
+
+    /// Parses a tuple type of the form (TYPE, TYPE, ..)
+    ///
+    /// Cursor Start : `(`
+    ///
+    /// Cursor End : `)`
+    fn parse_tuple_type(&mut self, field_type: FieldElementType) -> Result<Type, ParserErrorKind> {
+        // The visibility of a tuple is determined by the visibility of its elements
+        if field_type != FieldElementType::Private {
+            let message =
+                "a visibility cannot be applied to a tuple type. Only `priv` is allowed".to_owned();
+            return Err(ParserErrorKind::UnstructuredError {
+                message,
+                span: self.curr_token.into_span(),
+            });
+        }
+
+        // `()` is the unit type
+        if self.peek_token == Token::RightParen {
+            self.advance_tokens();
+            return Ok(Type::Unit);
+        }
+
+        let mut elements = Vec::new();
+        loop {
+            // Advance past the `(` or `,`
+            self.advance_tokens();
+            elements.push(self.parse_type(false)?);
+
+            if self.peek_token != Token::Comma {
+                break;
+            }
+            self.advance_tokens();
+
+            // The list of elements may have a trailing comma
+            if self.peek_token == Token::RightParen {
+                break;
+            }
+        }
+        self.peek_check_variant_advance(&Token::RightParen)?;
+
+        Ok(Type::Tuple(elements))
+    }
+
     fn parse_array_type(&mut self, field_type: FieldElementType) -> Result<Type, ParserErrorKind> {
         // Expression is of the form [3]Type
 
         // Current token is '['
         //
-        // Next token should be an Integer or right brace
-        let array_len = match self.peek_token.clone().into() {
-            Token::Int(integer) => {
+        // Next token should be the start of the length expression or a right bracket
+        let array_len = if self.peek_token == Token::RightBracket {
+            ArraySize::Variable
+        } else {
+            self.advance_tokens();
+            self.parse_array_size(Precedence::Lowest)?
+        };
+
+        self.peek_check_variant_advance(&Token::RightBracket)?;
+
+        // Skip Right bracket
+        self.advance_tokens();
+
+        // The element type may itself be an array, as in `[4][8]u8`.
+        // Set this to false as we do not allow something like `[4] pub Witness`
+        let array_type = self.parse_type(false)?;
+
+        Ok(Type::Array(field_type, array_len, Box::new(array_type)))
+    }
+
+    /// Parses a string type of the form str<N>, which is an array of N bytes
+    ///
+    /// Cursor Start : `str`
+    ///
+    /// Cursor End : `>`
+    fn parse_string_type(&mut self, field_type: FieldElementType) -> Result<Type, ParserErrorKind> {
+        self.peek_check_variant_advance(&Token::Less)?;
+        self.advance_tokens();
+
+        // The length is parsed with a higher precedence than comparisons,
+        // so that the closing `>` is not parsed as an infix operator
+        let length = self.parse_array_size(Precedence::LessGreater)?;
+        self.peek_check_variant_advance(&Token::Greater)?;
+
+        Ok(Type::string(field_type, length))
+    }
+
+    /// Parses the length of an array type
+    ///
+    /// Cursor Start : `FIRST_LENGTH_TOKEN`
+    ///
+    /// Cursor End : `LAST_LENGTH_TOKEN`
+    fn parse_array_size(&mut self, precedence: Precedence) -> Result<ArraySize, ParserErrorKind> {
+        let length = self.parse_expression_no_constructors(precedence)?;
+
+        let array_len = match length.kind {
+            ExpressionKind::Literal(Literal::Integer(integer)) => {
                 if !integer.fits_in_u128() {
                     let message = format!("Array sizes must fit within a u128");
                     return Err(ParserErrorKind::UnstructuredError {
                         message,
-                        span: self.peek_token.into_span(),
+                        span: length.span,
                     });
                 }
-                self.advance_tokens();
                 ArraySize::Fixed(integer.to_u128())
             }
-            Token::RightBracket => ArraySize::Variable,
-            _ => {
-                let message = format!("The array size is defined as [k] for fixed size or [] for variable length. k must be a literal");
-                return Err(ParserErrorKind::UnstructuredError {
-                    message,
-                    span: self.peek_token.into_span(),
-                });
+            // A single identifier may either be a generic length or a constant.
+            // This is decided during name resolution
+            ExpressionKind::Path(path) if path.as_ident().is_some() => {
+                ArraySize::Generic(path.as_ident().unwrap().clone())
             }
+            // Any other expression must be evaluated at compile time
+            kind => ArraySize::Unevaluated(Box::new(Expression {
+                kind,
+                span: length.span,
+            })),
         };
 
-        self.peek_check_variant_advance(&Token::RightBracket)?;
-
-        // Skip Right bracket
-        self.advance_tokens();
-
-        // Disallow [4][3]Witness ie Matrices
-        if self.peek_token == Token::LeftBracket {
-            return Err(ParserErrorKind::UnstructuredError {
-                message: format!("Currently Multi-dimensional arrays are not supported"),
-                span: self.peek_token.into_span(),
-            });
-        }
-
-        // set this to false as we do not allow something like `[4] pub Witness`
-        let array_type = self.parse_type(false)?;
-
-        Ok(Type::Array(field_type, array_len, Box::new(array_type)))
+        Ok(array_len)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::Signedness;
 
     #[test]
     fn regression_skip_comment() {
@@ -520,4 +796,255 @@ mod test {
         parser = Parser::from_src(COMMENT_BETWEEN_CALL);
         let program = parser.parse_program().unwrap();
     }
+
+    #[test]
+    fn parse_assign() {
+        const VALID: &'static [&str] = &[
+            "x = y;",
+            "x = x + 1;",
+            "arr[0] = y * 2;",
+            "arr[i] = if c { 1 } else { 2 };",
+            "matrix[i][0] = y;",
+        ];
+        for src in VALID {
+            let mut parser = Parser::from_src(src);
+            let stmt = parser.parse_statement().unwrap();
+            assert!(matches!(stmt, Statement::Assign(_)));
+        }
+
+        const INVALID: &'static [&str] = &[
+            "foo() = y;",
+            "x + 1 = y;",
+            "x = y",
+            "foo()[0] = y;",
+            "arr[0..2] = y;",
+        ];
+        for src in INVALID {
+            let mut parser = Parser::from_src(src);
+            assert!(parser.parse_statement().is_err());
+        }
+    }
+
+    #[test]
+    fn parse_global() {
+        const SRC: &'static str = r#"
+            const DEPTH = 32;
+            const WIDTH : const Field = 4;
+
+            fn main(x : [DEPTH]Field) {
+                constrain x[0] == WIDTH;
+            }
+        "#;
+        let mut parser = Parser::from_src(SRC);
+        let program = parser.parse_program().unwrap();
+        assert_eq!(program.globals.len(), 2);
+        assert_eq!(program.functions.len(), 1);
+
+        const INVALID: &'static [&str] = &["let DEPTH = 32;", "const mut DEPTH = 32;"];
+        for src in INVALID {
+            let mut parser = Parser::from_src(src);
+            assert!(parser.parse_program().is_err());
+        }
+    }
+
+    #[test]
+    fn parse_array_type_lengths() {
+        let parse = |src: &str| {
+            let mut parser = Parser::from_src(src);
+            parser.parse_type(true)
+        };
+
+        let array_size = |typ: Type| match typ {
+            Type::Array(_, size, _) => size,
+            _ => unreachable!("expected an array type"),
+        };
+
+        assert_eq!(array_size(parse("[4]Field").unwrap()), ArraySize::Fixed(4));
+        assert_eq!(array_size(parse("[]Field").unwrap()), ArraySize::Variable);
+        assert!(matches!(
+            array_size(parse("[N]Field").unwrap()),
+            ArraySize::Generic(_)
+        ));
+        assert!(matches!(
+            array_size(parse("[N * 2 + 1]Field").unwrap()),
+            ArraySize::Unevaluated(_)
+        ));
+
+        assert!(parse("[N +]Field").is_err());
+    }
+
+    #[test]
+    fn parse_nested_array_type() {
+        let mut parser = Parser::from_src("[4][8]u8");
+        let typ = parser.parse_type(true).unwrap();
+
+        // The outer length is written first
+        let (outer_size, row) = match typ {
+            Type::Array(_, size, row) => (size, *row),
+            _ => unreachable!("expected an array type"),
+        };
+        let (inner_size, element) = match row {
+            Type::Array(_, size, element) => (size, *element),
+            _ => unreachable!("expected an array of arrays"),
+        };
+        assert_eq!(outer_size, ArraySize::Fixed(4));
+        assert_eq!(inner_size, ArraySize::Fixed(8));
+        assert_eq!(
+            element,
+            Type::Integer(FieldElementType::Private, Signedness::Unsigned, 8)
+        );
+
+        // Only the outer array can have a visibility
+        let mut parser = Parser::from_src("[4] pub [8]u8");
+        assert!(parser.parse_type(true).is_err());
+    }
+
+    #[test]
+    fn parse_string_type() {
+        let parse = |src: &str| {
+            let mut parser = Parser::from_src(src);
+            parser.parse_type(true)
+        };
+
+        // Strings are arrays of bytes
+        let byte = Type::Integer(FieldElementType::Private, Signedness::Unsigned, 8);
+        assert_eq!(
+            parse("str<5>").unwrap(),
+            Type::Array(
+                FieldElementType::Private,
+                ArraySize::Fixed(5),
+                Box::new(byte)
+            )
+        );
+        assert!(parse("pub str<5>").unwrap().is_public());
+        assert!(matches!(
+            parse("str<N>").unwrap(),
+            Type::Array(_, ArraySize::Generic(_), _)
+        ));
+        assert!(matches!(
+            parse("str<N * 2>").unwrap(),
+            Type::Array(_, ArraySize::Unevaluated(_), _)
+        ));
+
+        for src in &["str", "str<>", "str<5", "str[5]", "str<5>>"] {
+            let mut parser = Parser::from_src(src);
+            let is_valid = parser.parse_type(true).is_ok() && parser.peek_token == Token::EOF;
+            assert!(!is_valid, "{} should not be a valid type", src);
+        }
+    }
+
+    #[test]
+    fn parse_impl() {
+        const SRC: &'static str = r#"
+            struct Point { x : Field, y : Field }
+
+            impl Point {
+                fn add(self, other : Point) -> Point {
+                    Point { x: self.x + other.x, y: self.y + other.y }
+                }
+            }
+
+            fn main(p : Point) {
+                constrain p.add(Point::new(1, 2)).x == 0;
+            }
+        "#;
+        let mut parser = Parser::from_src(SRC);
+        let program = parser.parse_program().unwrap();
+        assert_eq!(program.impls.len(), 1);
+        assert_eq!(program.impls[0].methods.len(), 1);
+        assert_eq!(program.functions.len(), 1);
+    }
+
+    #[test]
+    fn parse_trait() {
+        const SRC: &'static str = r#"
+            trait Hasher {
+                fn hash(left : Field, right : Field) -> Field;
+            }
+
+            struct Pedersen {}
+
+            impl Hasher for Pedersen {
+                fn hash(left : Field, right : Field) -> Field {
+                    std::hash::pedersen([left, right])
+                }
+            }
+
+            fn root<H: Hasher>(hasher : H, leaf : Field) -> Field {
+                H::hash(leaf, leaf)
+            }
+        "#;
+        let mut parser = Parser::from_src(SRC);
+        let program = parser.parse_program().unwrap();
+        assert_eq!(program.traits.len(), 1);
+        assert_eq!(program.impls.len(), 1);
+        assert!(program.impls[0].trait_path.is_some());
+        assert_eq!(program.functions[0].def().trait_bounds.len(), 1);
+    }
+
+    #[test]
+    fn parse_enum() {
+        const SRC: &'static str = r#"
+            enum Op { Deposit, Withdraw, Transfer }
+
+            fn main(op : Op, balance : Field, amount : Field) -> Field {
+                let new_balance = match op {
+                    Op::Deposit => balance + amount,
+                    Op::Withdraw => balance - amount,
+                    _ => balance,
+                };
+                new_balance
+            }
+        "#;
+        let mut parser = Parser::from_src(SRC);
+        let program = parser.parse_program().unwrap();
+        assert_eq!(program.enums.len(), 1);
+        assert_eq!(program.enums[0].variants.len(), 3);
+        assert_eq!(program.functions.len(), 1);
+    }
+
+    #[test]
+    fn parse_item_visibility() {
+        const SRC: &'static str = r#"
+            pub mod hash;
+            mod merkle;
+            pub use hash::pedersen;
+            use merkle::*;
+
+            pub const DEPTH = 32;
+            pub struct Point { x : Field, y : Field }
+            pub enum Op { Deposit, Withdraw }
+            pub trait Hasher {
+                fn hash(left : Field, right : Field) -> Field;
+            }
+
+            #[foreign(pedersen)]
+            pub fn pedersen(_input : []Field) -> Field {}
+
+            fn main(x : pub Field) {}
+        "#;
+        let mut parser = Parser::from_src(SRC);
+        let program = parser.parse_program().unwrap();
+        assert_eq!(program.module_decls[0].1, Visibility::Public);
+        assert_eq!(program.module_decls[1].1, Visibility::Private);
+        assert_eq!(program.imports[0].visibility, Visibility::Public);
+        assert_eq!(program.imports[1].visibility, Visibility::Private);
+        assert_eq!(program.globals[0].1, Visibility::Public);
+        assert_eq!(program.types[0].visibility, Visibility::Public);
+        assert_eq!(program.enums[0].visibility, Visibility::Public);
+        assert_eq!(program.traits[0].visibility, Visibility::Public);
+        assert_eq!(program.functions[0].def().visibility, Visibility::Public);
+        assert_eq!(program.functions[1].def().visibility, Visibility::Private);
+
+        const INVALID: &'static [&str] = &[
+            "pub impl Point {}",
+            "pub #[foreign(pedersen)] fn pedersen(_input : []Field) -> Field {}",
+            "pub pub fn main() {}",
+            "pub",
+        ];
+        for src in INVALID {
+            let mut parser = Parser::from_src(src);
+            assert!(parser.parse_program().is_err(), "src: {}", src);
+        }
+    }
 }
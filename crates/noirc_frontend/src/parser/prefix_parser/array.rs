@@ -5,6 +5,7 @@ pub struct ArrayParser;
 impl ArrayParser {
     /// Parses Arrays of the form
     /// - [<EXPR>, <EXPR>, <EXPR>, <EXPR>]
+    /// - [<EXPR>; <CONST_EXPR>]
     ///
     /// The last expression can end with a comma, before the closing delimiter
     ///
@@ -14,18 +15,59 @@ impl ArrayParser {
     pub fn parse(parser: &mut Parser) -> ParserExprKindResult {
         // Current token is '['
         //
-        // parse the contents of the array
-        let elements = parser.parse_comma_separated_argument_list(Token::RightBracket)?;
+        // An empty array
+        if parser.peek_token == Token::RightBracket {
+            parser.advance_tokens();
+            return Ok(Self::array_literal(Vec::new()));
+        }
+
+        // Parse the first element, so that we can check whether this is a repeated array
+        parser.advance_tokens();
+        let first_element = parser.parse_expression_allow_constructors(Precedence::Lowest)?;
+
+        if parser.peek_token == Token::Semicolon {
+            // Skip the `;`
+            parser.advance_tokens();
+            parser.advance_tokens();
+            let length = parser.parse_expression(Precedence::Lowest)?;
+
+            parser.peek_check_variant_advance(&Token::RightBracket)?;
+
+            return Ok(ExpressionKind::Literal(Literal::RepeatedArray(
+                RepeatedArrayLiteral {
+                    element: Box::new(first_element),
+                    length: Box::new(length),
+                },
+            )));
+        }
+
+        // parse the rest of the contents of the array
+        let mut elements = vec![first_element];
+        while parser.peek_token == Token::Comma {
+            parser.advance_tokens();
+
+            if parser.peek_token == Token::RightBracket {
+                // The list has a trailing comma
+                break;
+            }
+
+            parser.advance_tokens();
+            elements.push(parser.parse_expression_allow_constructors(Precedence::Lowest)?);
+        }
+
+        parser.peek_check_variant_advance(&Token::RightBracket)?;
+
+        Ok(Self::array_literal(elements))
+    }
 
+    fn array_literal(elements: Vec<Expression>) -> ExpressionKind {
         let array_len = elements.len() as u128;
 
-        let expr = ExpressionKind::Literal(Literal::Array(ArrayLiteral {
+        ExpressionKind::Literal(Literal::Array(ArrayLiteral {
             contents: elements,
             length: array_len,
             r#type: Type::Unknown,
-        }));
-
-        Ok(expr)
+        }))
     }
 }
 
@@ -129,6 +171,32 @@ mod test {
         ArrayParser::parse(&mut test_parse(DOUBLE_POSTFIX)).unwrap();
     }
 
+    #[test]
+    fn repeated_array() {
+        const SRC: &'static str = r#"
+            [0; N + 1]
+        "#;
+
+        let mut parser = test_parse(SRC);
+        let expr = ArrayParser::parse(&mut parser).unwrap();
+        assert_eq!(parser.curr_token.token(), &Token::RightBracket);
+
+        match expr {
+            ExpressionKind::Literal(Literal::RepeatedArray(arr)) => match arr.length.kind {
+                ExpressionKind::Infix(_) => {}
+                _ => unreachable!("expected the length to be an infix expression"),
+            },
+            _ => unreachable!("expected a repeated array"),
+        }
+    }
+    #[test]
+    fn repeated_array_missing_length() {
+        const SRC: &'static str = r#"
+            [0;]
+        "#;
+        ArrayParser::parse(&mut test_parse(SRC)).unwrap_err();
+    }
+
     #[test]
     fn missing_closing_bracket() {
         // This is a valid user error. We return an unexpected token error.
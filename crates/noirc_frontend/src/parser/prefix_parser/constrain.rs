@@ -22,7 +22,11 @@ fn disallowed_operators() -> Vec<BinaryOpKind> {
         BinaryOpKind::And,
         BinaryOpKind::Or,
         BinaryOpKind::Divide,
+        BinaryOpKind::Modulo,
         BinaryOpKind::Multiply,
+        BinaryOpKind::ShiftLeft,
+        BinaryOpKind::ShiftRight,
+        BinaryOpKind::Concat,
     ]
 }
 
@@ -5,7 +5,8 @@ use super::*;
 /// Since they follow the same structure, the parsing strategy for
 /// them are generic
 struct GenericDeclStructure {
-    identifier: Ident,
+    mutable: bool,
+    pattern: Pattern,
     typ: Option<Type>,
     rhs: Expression,
 }
@@ -32,10 +33,19 @@ impl DeclarationParser {
             }
         }
     }
+
+    /// Parses a global constant, which is a `const` statement declared at the module level
+    ///
+    /// Cursor Start : `const`
+    ///
+    /// Cursor End : `;`
+    pub fn parse_global(parser: &mut Parser) -> Result<ConstStatement, ParserErrorKind> {
+        parse_const_statement(parser)
+    }
 }
 
 /// Parses statements of the form
-/// - DECL_KEYWORD IDENT : TYPE? = EXPR
+/// - DECL_KEYWORD mut? PATTERN : TYPE? = EXPR
 ///
 /// The TYPE? signifies that the parameter can be optional
 ///
@@ -45,14 +55,18 @@ impl DeclarationParser {
 fn parse_generic_decl_statement(
     parser: &mut Parser,
 ) -> Result<GenericDeclStructure, ParserErrorKind> {
-    // The next token should be an identifier
-    parser.peek_check_kind_advance(TokenKind::Ident)?;
+    let mutable = parser.peek_token == Token::Keyword(Keyword::Mut);
+    if mutable {
+        parser.advance_tokens();
+    }
 
-    let identifier: Ident = parser.curr_token.clone().into();
+    // Advance to the start of the pattern
+    parser.advance_tokens();
+    let pattern = parse_pattern(parser)?;
 
     let mut declared_typ = None;
     if parser.peek_token == Token::Colon {
-        // Advance past the identifier.
+        // Advance past the pattern.
         // Current token is now the Colon
         parser.advance_tokens();
         // Advance past the Colon
@@ -78,27 +92,92 @@ fn parse_generic_decl_statement(
     parser.peek_check_variant_advance(&Token::Semicolon)?;
 
     Ok(GenericDeclStructure {
-        identifier,
+        mutable,
+        pattern,
         typ: declared_typ,
         rhs: expr,
     })
 }
 
+/// Parses the names that a declaration binds its value to, which are either
+/// - IDENT
+/// - (PATTERN, PATTERN, ..)
+///
+/// Cursor Start : `IDENT` or `(`
+///
+/// Cursor End : `IDENT` or `)`
+fn parse_pattern(parser: &mut Parser) -> Result<Pattern, ParserErrorKind> {
+    if parser.curr_token.kind() == TokenKind::Ident {
+        return Ok(Pattern::Identifier(parser.curr_token.clone().into()));
+    }
+    if parser.curr_token != Token::LeftParen {
+        return Err(ParserErrorKind::UnexpectedTokenKind {
+            span: parser.curr_token.into_span(),
+            expected: TokenKind::Ident,
+            found: parser.curr_token.kind(),
+        });
+    }
+    let start = parser.curr_token.into_span();
+
+    let mut patterns = Vec::new();
+    loop {
+        // Advance past the `(` or `,`
+        parser.advance_tokens();
+        patterns.push(parse_pattern(parser)?);
+
+        if parser.peek_token != Token::Comma {
+            break;
+        }
+        parser.advance_tokens();
+
+        // The list of patterns may have a trailing comma
+        if parser.peek_token == Token::RightParen {
+            break;
+        }
+    }
+    parser.peek_check_variant_advance(&Token::RightParen)?;
+
+    let span = start.merge(parser.curr_token.into_span());
+    Ok(Pattern::Tuple(patterns, span))
+}
+
+// Only `let` declarations can bind a tuple pattern
+fn expect_identifier(pattern: Pattern, keyword: &str) -> Result<Ident, ParserErrorKind> {
+    match pattern {
+        Pattern::Identifier(identifier) => Ok(identifier),
+        Pattern::Tuple(_, span) => {
+            let message = format!(
+                "`{}` declarations can only bind a single name, use `let` to destructure a tuple",
+                keyword
+            );
+            Err(ParserErrorKind::UnstructuredError { message, span })
+        }
+    }
+}
+
 fn parse_let_statement(parser: &mut Parser) -> Result<LetStatement, ParserErrorKind> {
     let generic_stmt = parse_generic_decl_statement(parser)?;
 
     let stmt = LetStatement {
-        identifier: generic_stmt.identifier,
+        pattern: generic_stmt.pattern,
         r#type: generic_stmt.typ.unwrap_or(Type::Unspecified), //XXX: Haven't implemented this yet for general structs, we only parse arrays using this
         expression: generic_stmt.rhs,
+        mutable: generic_stmt.mutable,
     };
     Ok(stmt)
 }
 fn parse_const_statement(parser: &mut Parser) -> Result<ConstStatement, ParserErrorKind> {
     let generic_stmt = parse_generic_decl_statement(parser)?;
+    check_not_mutable(&generic_stmt, "const")?;
 
     // Note: If a Type is supplied for some reason in a const statement, it can only be a Field element/Constant
-    let mut default_type = Type::CONSTANT;
+    //
+    // String literals are also constants. Their type is left unspecified,
+    // so that the type checker infers the length of the string.
+    let mut default_type = match &generic_stmt.rhs.kind {
+        ExpressionKind::Literal(Literal::Str(_)) => Type::Unspecified,
+        _ => Type::CONSTANT,
+    };
     if let Some(declared_type) = generic_stmt.typ {
         if !declared_type.is_constant() {
             let message = format!("Const statements can only have constant type, you supplied a {:?}. Suggestion: Remove the type and the compiler will default to Constant ",declared_type);
@@ -112,7 +191,7 @@ fn parse_const_statement(parser: &mut Parser) -> Result<ConstStatement, ParserEr
     }
 
     let stmt = ConstStatement {
-        identifier: generic_stmt.identifier,
+        identifier: expect_identifier(generic_stmt.pattern, "const")?,
         r#type: default_type,
         expression: generic_stmt.rhs,
     };
@@ -120,6 +199,7 @@ fn parse_const_statement(parser: &mut Parser) -> Result<ConstStatement, ParserEr
 }
 fn parse_private_statement(parser: &mut Parser) -> Result<PrivateStatement, ParserErrorKind> {
     let generic_stmt = parse_generic_decl_statement(parser)?;
+    check_not_mutable(&generic_stmt, "priv")?;
 
     // XXX: As of FieldElement refactor, we can catch basic type errors for private statements,
     // similar to the code for pub and const.
@@ -127,13 +207,28 @@ fn parse_private_statement(parser: &mut Parser) -> Result<PrivateStatement, Pars
     // This change may wait until, it is decided whether `let` will be the only declaration keyword.
 
     let stmt = PrivateStatement {
-        identifier: generic_stmt.identifier,
+        identifier: expect_identifier(generic_stmt.pattern, "priv")?,
         r#type: generic_stmt.typ.unwrap_or(Type::Unspecified),
         expression: generic_stmt.rhs,
     };
     Ok(stmt)
 }
 
+// Only `let` declarations can be mutable
+fn check_not_mutable(stmt: &GenericDeclStructure, keyword: &str) -> Result<(), ParserErrorKind> {
+    if stmt.mutable {
+        let message = format!(
+            "`{}` declarations cannot be mutable, use `let mut` instead",
+            keyword
+        );
+        return Err(ParserErrorKind::UnstructuredError {
+            message,
+            span: stmt.pattern.span(),
+        });
+    }
+    Ok(())
+}
+
 //XXX: Maybe do a second pass for invalid?
 #[cfg(test)]
 mod test {
@@ -155,6 +250,15 @@ mod test {
             r#"
                 let x : u8 = y;
             "#,
+            r#"
+                let mut x : u8 = y;
+            "#,
+            r#"
+                let (x, y) = z;
+            "#,
+            r#"
+                let mut (x, (y, z)) : (u8, (u8, Field)) = w;
+            "#,
         ];
 
         for valid in VALID {
@@ -214,6 +318,49 @@ mod test {
         }
     }
     #[test]
+    fn invalid_tuple_pattern_syntax() {
+        // Only let declarations can destructure a tuple
+        const INVALID: &'static [&str] = &[
+            r#"
+                priv (x, y) = z;
+            "#,
+            r#"
+                const (x, y) = z;
+            "#,
+            r#"
+                let (x, y = z;
+            "#,
+            r#"
+                let (x, 1) = z;
+            "#,
+        ];
+
+        for invalid in INVALID {
+            let mut parser = test_parse(invalid);
+            assert!(DeclarationParser::parse_statement(&mut parser).is_err());
+        }
+    }
+    #[test]
+    fn invalid_mut_syntax() {
+        // Only let declarations can be mutable
+        const INVALID: &'static [&str] = &[
+            r#"
+                priv mut x = y;
+            "#,
+            r#"
+                const mut x = y;
+            "#,
+            r#"
+                let mut = y;
+            "#,
+        ];
+
+        for invalid in INVALID {
+            let mut parser = test_parse(invalid);
+            assert!(DeclarationParser::parse_statement(&mut parser).is_err());
+        }
+    }
+    #[test]
     fn valid_const_syntax() {
         /// XXX: We have `Constant` because we may allow constants to
         /// be casted to integers. Maybe rename this to `Field` instead
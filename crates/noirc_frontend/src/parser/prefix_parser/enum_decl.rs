@@ -0,0 +1,110 @@
+use crate::NoirEnum;
+
+use super::*;
+
+pub struct EnumParser;
+
+impl EnumParser {
+    /// Parses an enum declaration.
+    ///
+    /// enum IDENT {
+    ///     IDENT,
+    ///     IDENT,
+    ///     ...
+    /// }
+    ///
+    /// Cursor Start : `enum`
+    ///
+    /// Cursor End : `}`
+    pub(crate) fn parse_enum_definition(
+        parser: &mut Parser,
+        visibility: Visibility,
+    ) -> Result<NoirEnum, ParserErrorKind> {
+        let start = parser.curr_token.into_span();
+
+        // Current token is `enum`
+        //
+        // Peek ahead and check if the next token is an identifier
+        parser.peek_check_kind_advance(TokenKind::Ident)?;
+        let name: Ident = parser.curr_token.clone().into();
+
+        // Current token is the enum name
+        //
+        // Peek ahead and check if the next token is `{`
+        parser.peek_check_variant_advance(&Token::LeftBrace)?;
+
+        let mut variants = Vec::new();
+
+        // Trailing commas are allowed, so we check for the closing
+        // brace before every variant.
+        while parser.peek_token != Token::RightBrace {
+            // Peek ahead and check if the next token is the variant name
+            parser.peek_check_kind_advance(TokenKind::Ident)?;
+            variants.push(parser.curr_token.clone().into());
+
+            // Variants are separated by a comma, unless this is the last variant
+            if parser.peek_token == Token::Comma {
+                parser.advance_tokens();
+            } else {
+                break;
+            }
+        }
+
+        parser.peek_check_variant_advance(&Token::RightBrace)?;
+
+        // Current token is `}`
+        let end = parser.curr_token.into_span();
+
+        Ok(NoirEnum {
+            name,
+            visibility,
+            variants,
+            span: start.merge(end),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parser::test_parse, token::Token, Visibility};
+
+    use super::EnumParser;
+
+    #[test]
+    fn valid_syntax() {
+        let vectors = vec![
+            ("enum Op {}", 0),
+            ("enum Op { Deposit }", 1),
+            ("enum Op { Deposit, Withdraw, Transfer }", 3),
+            ("enum Op { Deposit, Withdraw, }", 2),
+        ];
+
+        for (src, num_variants) in vectors {
+            let mut parser = test_parse(src);
+            let enum_def =
+                EnumParser::parse_enum_definition(&mut parser, Visibility::Private).unwrap();
+
+            assert_eq!(enum_def.name.0.contents, "Op");
+            assert_eq!(enum_def.variants.len(), num_variants);
+            assert_eq!(parser.curr_token.token(), &Token::RightBrace);
+        }
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        let vectors = vec![
+            "enum {}",
+            "enum Op",
+            "enum foo::Op { Deposit }",
+            "enum Op { Deposit Withdraw }",
+            "enum Op { Deposit : Field }",
+            "enum Op { Deposit, 1 }",
+            "enum Op { Deposit, ",
+        ];
+
+        for src in vectors {
+            EnumParser::parse_enum_definition(&mut test_parse(src), Visibility::Private)
+                .unwrap_err();
+        }
+    }
+}
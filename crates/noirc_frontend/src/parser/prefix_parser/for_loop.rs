@@ -33,7 +33,7 @@ impl ForParser {
 
         // Current token should now be the
         // token that starts the expression for RANGE_START
-        let start_range = parser.parse_expression(Precedence::Lowest)?;
+        let start_range = parser.parse_expression_no_constructors(Precedence::Lowest)?;
 
         // Current token is now the end of RANGE_START
         //
@@ -47,7 +47,7 @@ impl ForParser {
 
         // Current token should now be the token that starts the expression
         // for RANGE_END
-        let end_range = parser.parse_expression(Precedence::Lowest)?;
+        let end_range = parser.parse_expression_no_constructors(Precedence::Lowest)?;
 
         // Current token is now the end of RANGE_END
         //
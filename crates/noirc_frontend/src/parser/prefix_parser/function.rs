@@ -1,14 +1,17 @@
 use super::*;
+use crate::{Path, PathKind};
+use noirc_errors::Spanned;
 
 pub struct FuncParser;
 
 impl FuncParser {
     /// Parses a function definition.
     ///
-    /// fn IDENT(IDENT : Type,IDENT : Type,... ) (-> Type)? {
+    /// fn IDENT(<IDENT, IDENT, ...>)?(IDENT : Type,IDENT : Type,... ) (-> Type)? {
     ///         <STMT> <STMT> ...   
     /// }
     ///
+    /// (<IDENT, ...>)? indicates that the list of generics is optional.
     /// (->Type)? indicates that the return type is optional.
     /// If not return type is supplied, the return type is
     /// implied to be the unit type.
@@ -21,7 +24,56 @@ impl FuncParser {
     pub(crate) fn parse_fn_definition(
         parser: &mut Parser,
         attribute: Option<Attribute>,
+        visibility: Visibility,
     ) -> Result<NoirFunction, ParserErrorKind> {
+        let mut func_def = FuncParser::parse_fn_signature(parser, attribute, visibility)?;
+
+        parser.peek_check_variant_advance(&Token::LeftBrace)?;
+
+        let start = parser.curr_token.into_span();
+        let body = BlockParser::parse_block_expression(parser)?;
+        let end = parser.curr_token.into_span();
+
+        // The cursor position is inherited from the block expression
+        // parsing procedure which is `}`
+        func_def.body = body;
+        func_def.span = start.merge(end);
+
+        Ok(func_def.into())
+    }
+
+    /// Parses a function declaration, which is a function signature without a body.
+    /// These are used to declare the methods of a trait.
+    ///
+    /// fn IDENT(<IDENT, IDENT, ...>)?(IDENT : Type,IDENT : Type,... ) (-> Type)?;
+    ///
+    /// Cursor Start : `fn`
+    ///
+    /// Cursor End : `;`
+    pub(crate) fn parse_fn_declaration(
+        parser: &mut Parser,
+    ) -> Result<NoirFunction, ParserErrorKind> {
+        let mut func_def = FuncParser::parse_fn_signature(parser, None, Visibility::Private)?;
+
+        parser.peek_check_variant_advance(&Token::Semicolon)?;
+
+        // Current token is `;`
+        func_def.span = func_def.name.0.span().merge(parser.curr_token.into_span());
+
+        Ok(func_def.into())
+    }
+
+    /// Parses everything in a function definition up to its body.
+    /// The body of the returned definition is empty.
+    ///
+    /// Cursor Start : `fn`
+    ///
+    /// Cursor End : `)` or the last token of the return type
+    fn parse_fn_signature(
+        parser: &mut Parser,
+        attribute: Option<Attribute>,
+        visibility: Visibility,
+    ) -> Result<FunctionDefinition, ParserErrorKind> {
         // Current token is `fn`
         //
         // Peek ahead and check if the next token is an identifier
@@ -30,6 +82,9 @@ impl FuncParser {
 
         // Current token is the function name
         //
+        // When generics are successfully parsed, the current token will be `>`
+        let (generics, trait_bounds) = FuncParser::parse_generics(parser)?;
+
         // Peek ahead and check if the next token is the `(`
         parser.peek_check_variant_advance(&Token::LeftParen)?;
 
@@ -56,28 +111,79 @@ impl FuncParser {
             return_type = parser.parse_type(true)?
         }
 
-        parser.peek_check_variant_advance(&Token::LeftBrace)?;
-
-        let start = parser.curr_token.into_span();
-        let body = BlockParser::parse_block_expression(parser)?;
-        let end = parser.curr_token.into_span();
-
-        // The cursor position is inherited from the block expression
-        // parsing procedure which is `}`
-
         // Currently, we only allow lowlevel, builtin and normal functions
         // In the future, we can add a test attribute.
         // Arbitrary attributes will not be supported.
-        let func_def = FunctionDefinition {
-            name: spanned_func_name.into(),
+        Ok(FunctionDefinition {
+            span: spanned_func_name.0.span(),
+            name: spanned_func_name,
+            visibility,
             attribute,
+            generics,
+            trait_bounds,
             parameters,
-            body,
-            span: start.merge(end),
+            body: BlockExpression(Vec::new()),
             return_type,
-        };
+        })
+    }
 
-        Ok(func_def.into())
+    /// Parses the optional list of generics of a function.
+    /// A type generic may be followed by the traits that it must implement.
+    ///
+    /// <IDENT, IDENT : PATH + PATH, ...>
+    ///
+    /// Cursor Start : `IDENT`
+    ///
+    /// Cursor End : `>` or `IDENT` if there are no generics
+    fn parse_generics(
+        parser: &mut Parser,
+    ) -> Result<(Vec<Ident>, Vec<(Ident, Path)>), ParserErrorKind> {
+        let mut generics = Vec::new();
+        let mut trait_bounds = Vec::new();
+        if parser.peek_token != Token::Less {
+            return Ok((generics, trait_bounds));
+        }
+
+        // Current token is the function name
+        //
+        // Advance to the `<`
+        parser.advance_tokens();
+
+        loop {
+            // Current token is `<` or `,`
+            //
+            // Check that the next token is the name of a generic
+            parser.peek_check_kind_advance(TokenKind::Ident)?;
+            let generic: Ident = parser.curr_token.clone().into();
+
+            if parser.peek_token == Token::Colon {
+                // Advance to the `:`
+                parser.advance_tokens();
+                loop {
+                    // Current token is `:` or `+`
+                    //
+                    // Check that the next token is the start of the path to a trait
+                    parser.peek_check_kind_advance(TokenKind::Ident)?;
+                    let trait_path = PathParser::parse_path(parser)?;
+                    trait_bounds.push((generic.clone(), trait_path));
+
+                    if parser.peek_token != Token::Plus {
+                        break;
+                    }
+                    parser.advance_tokens();
+                }
+            }
+            generics.push(generic);
+
+            if parser.peek_token != Token::Comma {
+                break;
+            }
+            parser.advance_tokens();
+        }
+
+        parser.peek_check_variant_advance(&Token::Greater)?;
+
+        Ok((generics, trait_bounds))
     }
 
     /// Cursor Start : `(`
@@ -105,7 +211,18 @@ impl FuncParser {
         // Notice that parsing the type requires that the
         // cursor starts on the parameter name, which is upheld
         let spanned_name: Ident = parser.curr_token.clone().into();
-        parameters.push((spanned_name, FuncParser::parse_fn_type(parser)?));
+        let typ = if spanned_name.0.contents == "self" && parser.peek_token != Token::Colon {
+            // Methods can take `self` as their first parameter without a type.
+            // Its type is `Self`, which is replaced with the type of the impl block
+            let self_type: Ident = Spanned::from(spanned_name.0.span(), "Self".to_owned()).into();
+            Type::Unresolved(Path {
+                segments: vec![self_type],
+                kind: PathKind::Plain,
+            })
+        } else {
+            FuncParser::parse_fn_type(parser)?
+        };
+        parameters.push((spanned_name, typ));
 
         while parser.peek_token == Token::Comma {
             // Current token is Type
@@ -151,7 +268,7 @@ impl FuncParser {
 
 #[cfg(test)]
 mod test {
-    use crate::parser::test_parse;
+    use crate::{parser::test_parse, Visibility};
 
     use super::FuncParser;
 
@@ -181,12 +298,33 @@ mod test {
             "
         fn func_name(x: []Field, y : [2]Field,y : pub [2]Field, z : pub [5]u8)  {
 
+        }
+        ",
+            "
+        fn func_name<N>(x: [N]Field) -> [N]Field {
+            x
+        }
+        ",
+            "
+        fn func_name<N, M>(x: [N]Field, y : [M]u8)  {
+
+        }
+        ",
+            "
+        fn add(self, other : Self) -> Self {
+            other
+        }
+        ",
+            "
+        fn root<H: Hasher, T : foo::Bar + Baz, N>(x: H, y : [N]T) {
+
         }
         ",
         ];
 
         for src in valid_src {
-            FuncParser::parse_fn_definition(&mut test_parse(src), None).unwrap();
+            FuncParser::parse_fn_definition(&mut test_parse(src), None, Visibility::Private)
+                .unwrap();
         }
     }
     #[test]
@@ -197,6 +335,69 @@ mod test {
             }
         "#;
 
-        FuncParser::parse_fn_definition(&mut test_parse(SRC), None).unwrap_err();
+        FuncParser::parse_fn_definition(&mut test_parse(SRC), None, Visibility::Private)
+            .unwrap_err();
+    }
+    #[test]
+    fn invalid_generics() {
+        let invalid_src = vec![
+            "
+        fn func_name<>(x: [N]Field) {
+
+        }
+        ",
+            "
+        fn func_name<N,>(x: [N]Field) {
+
+        }
+        ",
+            "
+        fn func_name<N(x: [N]Field) {
+
+        }
+        ",
+            "
+        fn func_name<1>(x: [N]Field) {
+
+        }
+        ",
+            "
+        fn func_name<T:>(x: T) {
+
+        }
+        ",
+            "
+        fn func_name<T: A +>(x: T) {
+
+        }
+        ",
+        ];
+
+        for src in invalid_src {
+            FuncParser::parse_fn_definition(&mut test_parse(src), None, Visibility::Private)
+                .unwrap_err();
+        }
+    }
+    #[test]
+    fn declaration() {
+        let valid_src = vec![
+            "fn hash(left : Field, right : Field) -> Field;",
+            "fn zero() -> Self;",
+            "fn add(self, other : Self);",
+        ];
+        for src in valid_src {
+            let mut parser = test_parse(src);
+            let func = FuncParser::parse_fn_declaration(&mut parser).unwrap();
+            assert_eq!(func.number_of_statements(), 0);
+            assert_eq!(parser.curr_token.token(), &crate::token::Token::Semicolon);
+        }
+
+        let invalid_src = vec![
+            "fn hash(left : Field) -> Field",
+            "fn hash(left : Field) -> Field { left }",
+        ];
+        for src in invalid_src {
+            FuncParser::parse_fn_declaration(&mut test_parse(src)).unwrap_err();
+        }
     }
 }
@@ -4,6 +4,7 @@ pub struct GroupParser;
 
 impl GroupParser {
     /// The Group Parser is a precedent lifter.
+    /// It also parses tuples, which are groups containing a comma
     ///
     /// Cursor Start : `(`
     ///
@@ -19,7 +20,12 @@ impl GroupParser {
         // as an expression. So we do not check for `()`
 
         // Use the lowest precedence and parse the expression
-        let exp = parser.parse_expression(Precedence::Lowest)?;
+        let exp = parser.parse_expression_allow_constructors(Precedence::Lowest)?;
+
+        // A comma after the first expression means that this is a tuple
+        if parser.peek_token == Token::Comma {
+            return GroupParser::parse_tuple(parser, exp);
+        }
 
         // Once the expression is parsed, the next token should
         // be the `)`
@@ -27,11 +33,36 @@ impl GroupParser {
 
         Ok(exp.kind)
     }
+
+    /// Parses the remaining elements of a tuple
+    ///
+    /// Cursor Start : `FIRST_ELEMENT`
+    ///
+    /// Cursor End : `)`
+    fn parse_tuple(parser: &mut Parser, first_element: Expression) -> ParserExprKindResult {
+        let mut elements = vec![first_element];
+
+        while parser.peek_token == Token::Comma {
+            parser.advance_tokens();
+
+            // The tuple may have a trailing comma
+            if parser.peek_token == Token::RightParen {
+                break;
+            }
+            parser.advance_tokens();
+
+            elements.push(parser.parse_expression_allow_constructors(Precedence::Lowest)?);
+        }
+
+        parser.peek_check_variant_advance(&Token::RightParen)?;
+
+        Ok(ExpressionKind::Tuple(elements))
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::parser::test_parse;
+    use crate::{parser::test_parse, ExpressionKind};
 
     use super::GroupParser;
 
@@ -50,6 +81,23 @@ mod test {
         GroupParser::parse(&mut test_parse(SRC_DOUBLE_RPAREN)).unwrap();
     }
     #[test]
+    fn valid_tuple_syntax() {
+        let vectors = vec!["(x, y)", "(x + a, [1, 2], (y, z))", "(x,)"];
+
+        for src in vectors {
+            let kind = GroupParser::parse(&mut test_parse(src)).unwrap();
+            assert!(matches!(kind, ExpressionKind::Tuple(_)), "src: {}", src);
+        }
+    }
+    #[test]
+    fn invalid_tuple_syntax() {
+        let vectors = vec!["(x, y", "(x, , y)", "(x y)"];
+
+        for src in vectors {
+            GroupParser::parse(&mut test_parse(src)).unwrap_err();
+        }
+    }
+    #[test]
     fn invalid_syntax() {
         const SRC_MISSING_RPAREN: &'static str = r#"
             (x+a
@@ -19,7 +19,9 @@ impl IfParser {
 
         // Current token is the start of the expression (condition)
         //
-        let condition = parser.parse_expression(Precedence::Lowest)?;
+        // Struct literals are not allowed here, as `if x {}`
+        // would otherwise be parsed as a struct literal.
+        let condition = parser.parse_expression_no_constructors(Precedence::Lowest)?;
 
         // Current token is `)`
         //
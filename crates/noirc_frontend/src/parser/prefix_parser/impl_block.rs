@@ -0,0 +1,201 @@
+use crate::{NoirImpl, Path};
+
+use super::*;
+
+pub struct ImplParser;
+
+impl ImplParser {
+    /// Parses an impl block, which may implement a trait for the type.
+    ///
+    /// impl (PATH for)? PATH {
+    ///     fn IDENT(...) { ... }
+    ///     pub fn IDENT(...) { ... }
+    ///     ...
+    /// }
+    ///
+    /// Cursor Start : `impl`
+    ///
+    /// Cursor End : `}`
+    pub(crate) fn parse_impl(parser: &mut Parser) -> Result<NoirImpl, ParserErrorKind> {
+        let start = parser.curr_token.into_span();
+
+        // Current token is `impl`
+        //
+        // Peek ahead and check if the next token is the start of the type's path
+        parser.peek_check_kind_advance(TokenKind::Ident)?;
+        let mut trait_path = None;
+        let mut type_path = PathParser::parse_path(parser)?;
+
+        // If the path is followed by `for`, then it was the path to a trait
+        if parser.peek_token == Token::Keyword(Keyword::For) {
+            parser.advance_tokens();
+
+            // Current token is `for`
+            //
+            // Peek ahead and check if the next token is the start of the type's path
+            parser.peek_check_kind_advance(TokenKind::Ident)?;
+            trait_path = Some(type_path);
+            type_path = PathParser::parse_path(parser)?;
+        }
+
+        // Current token is the last segment of the path
+        //
+        // Peek ahead and check if the next token is `{`
+        parser.peek_check_variant_advance(&Token::LeftBrace)?;
+
+        let mut methods = Vec::new();
+        loop {
+            // Current token is `{` or the `}` of the previous method
+            parser.advance_tokens();
+
+            match parser.curr_token.token() {
+                Token::RightBrace => break,
+                Token::Comment(_) => continue,
+                Token::Keyword(Keyword::Fn) => {
+                    let mut method =
+                        FuncParser::parse_fn_definition(parser, None, Visibility::Private)?;
+                    ImplParser::replace_self_type(&mut method, &type_path);
+                    methods.push(method);
+                }
+                // The methods of a trait impl are public, like the methods of the trait
+                Token::Keyword(Keyword::Pub) if trait_path.is_some() => {
+                    return Err(ParserErrorKind::UnstructuredError {
+                        span: parser.curr_token.into_span(),
+                        message: "the methods of a trait impl cannot be declared with `pub`, as they are always public".to_owned(),
+                    })
+                }
+                Token::Keyword(Keyword::Pub) => {
+                    // Current token is `pub`
+                    //
+                    // Peek ahead and check if the next token is `fn`
+                    parser.peek_check_variant_advance(&Token::Keyword(Keyword::Fn))?;
+                    let mut method =
+                        FuncParser::parse_fn_definition(parser, None, Visibility::Public)?;
+                    ImplParser::replace_self_type(&mut method, &type_path);
+                    methods.push(method);
+                }
+                _ => {
+                    return Err(ParserErrorKind::UnexpectedToken {
+                        span: parser.curr_token.into_span(),
+                        expected: Token::Keyword(Keyword::Fn),
+                        found: parser.curr_token.token().clone(),
+                    })
+                }
+            }
+        }
+
+        // Current token is `}`
+        let end = parser.curr_token.into_span();
+
+        Ok(NoirImpl {
+            trait_path,
+            type_path,
+            methods,
+            span: start.merge(end),
+        })
+    }
+
+    /// `Self` can be used in the signature of a method to refer to
+    /// the type of the impl block, so it is replaced with that type.
+    fn replace_self_type(method: &mut NoirFunction, type_path: &Path) {
+        let def = method.def_mut();
+        for (_, typ) in def.parameters.iter_mut() {
+            replace_self_type_in(typ, type_path);
+        }
+        replace_self_type_in(&mut def.return_type, type_path);
+    }
+}
+
+fn replace_self_type_in(typ: &mut Type, type_path: &Path) {
+    match typ {
+        Type::Unresolved(path)
+            if path
+                .as_ident()
+                .map_or(false, |name| name.0.contents == "Self") =>
+        {
+            *path = type_path.clone()
+        }
+        Type::Array(_, _, elem_type) => replace_self_type_in(elem_type, type_path),
+        Type::Tuple(elements) => {
+            for elem in elements {
+                replace_self_type_in(elem, type_path);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parser::test_parse, Type, Visibility};
+
+    use super::ImplParser;
+
+    #[test]
+    fn valid_syntax() {
+        const SRC: &'static str = r#"
+            impl Point {
+                // Associated functions do not take self
+                pub fn new(x : Field, y : Field) -> Self {
+                    Point { x: x, y: y }
+                }
+
+                fn add(self, other : Point) -> Point {
+                    Point { x: self.x + other.x, y: self.y + other.y }
+                }
+            }
+        "#;
+
+        let mut parser = test_parse(SRC);
+        let noir_impl = ImplParser::parse_impl(&mut parser).unwrap();
+        assert_eq!(parser.curr_token.token(), &crate::token::Token::RightBrace);
+
+        assert_eq!(noir_impl.methods.len(), 2);
+        assert_eq!(noir_impl.methods[0].def().visibility, Visibility::Public);
+        assert_eq!(noir_impl.methods[1].def().visibility, Visibility::Private);
+
+        // `self` and `Self` refer to the type of the impl block
+        let point_type = Type::Unresolved(noir_impl.type_path.clone());
+        assert_eq!(noir_impl.methods[0].return_type(), point_type);
+        assert_eq!(noir_impl.methods[1].parameters()[0].1, point_type);
+    }
+
+    #[test]
+    fn empty_impl() {
+        ImplParser::parse_impl(&mut test_parse("impl foo::Point {}")).unwrap();
+    }
+
+    #[test]
+    fn trait_impl() {
+        const SRC: &'static str = r#"
+            impl hash::Hasher for Pedersen {
+                fn hash(left : Field, right : Field) -> Field {
+                    std::hash::pedersen([left, right])
+                }
+            }
+        "#;
+
+        let noir_impl = ImplParser::parse_impl(&mut test_parse(SRC)).unwrap();
+        assert_eq!(noir_impl.trait_path.unwrap().as_string(), "hash::Hasher");
+        assert_eq!(noir_impl.type_path.as_string(), "Pedersen");
+        assert_eq!(noir_impl.methods.len(), 1);
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        let invalid_src = vec![
+            "impl { fn foo() {} }",
+            "impl Point { let x = 1; }",
+            "impl Point { fn foo() {} ",
+            "impl Point fn foo() {}",
+            "impl Hasher for { fn foo() {} }",
+            "impl Hasher for Point for Foo {}",
+            "impl Point { pub let x = 1; }",
+            "impl Hasher for Point { pub fn hash() {} }",
+        ];
+
+        for src in invalid_src {
+            ImplParser::parse_impl(&mut test_parse(src)).unwrap_err();
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{MatchExpression, MatchPattern};
+
+use super::*;
+
+pub struct MatchParser;
+
+impl MatchParser {
+    /// Parses match expressions of the form:
+    ///
+    /// match EXPR {
+    ///     PATTERN => EXPR,
+    ///     PATTERN => EXPR,
+    ///     ...
+    /// }
+    ///
+    /// where PATTERN is either the path to an enum variant or `_`.
+    /// The comma after an arm may be omitted if the arm is a block.
+    ///
+    /// Cursor Start : `match`
+    ///
+    /// Cursor End : `}`
+    pub fn parse(parser: &mut Parser) -> ParserExprKindResult {
+        // Current token is `match`
+        //
+        // Bump cursor.
+        parser.advance_tokens();
+
+        // Current token is the start of the expression being matched on
+        //
+        // Struct literals are not allowed here, as `match x {}`
+        // would otherwise be parsed as a struct literal.
+        let expression = parser.parse_expression_no_constructors(Precedence::Lowest)?;
+
+        // Current token is the end of the expression
+        //
+        // Peek ahead and check if the next token is `{`
+        parser.peek_check_variant_advance(&Token::LeftBrace)?;
+
+        let mut arms = Vec::new();
+        loop {
+            // Current token is `{` or the end of the previous arm
+            parser.advance_tokens();
+
+            if parser.curr_token == Token::RightBrace {
+                break;
+            }
+            if parser.curr_token.is_comment() {
+                continue;
+            }
+
+            let pattern = MatchParser::parse_pattern(parser)?;
+
+            // Current token is the end of the pattern
+            //
+            // Peek ahead and check if the next token is `=>`
+            parser.peek_check_variant_advance(&Token::FatArrow)?;
+
+            // Current token is `=>`
+            //
+            // Bump cursor. Current token is now the start of the arm
+            parser.advance_tokens();
+            let is_block = parser.curr_token == Token::LeftBrace;
+            let arm = parser.parse_expression_allow_constructors(Precedence::Lowest)?;
+            arms.push((pattern, arm));
+
+            // Current token is the end of the arm
+            //
+            // Arms are separated by a comma, unless this is the last arm
+            // or the arm is a block
+            if parser.peek_token == Token::Comma {
+                parser.advance_tokens();
+            } else if !is_block && parser.peek_token != Token::RightBrace {
+                parser.peek_check_variant_advance(&Token::Comma)?;
+            }
+        }
+
+        // Current token is `}`
+        let match_expr = MatchExpression { expression, arms };
+        Ok(ExpressionKind::Match(Box::new(match_expr)))
+    }
+
+    /// Cursor Start : `_` or `FIRST_PATH_SEGMENT`
+    ///
+    /// Cursor End : `_` or `LAST_PATH_SEGMENT`
+    fn parse_pattern(parser: &mut Parser) -> Result<MatchPattern, ParserErrorKind> {
+        if parser.curr_token == Token::Underscore {
+            return Ok(MatchPattern::Wildcard(parser.curr_token.into_span()));
+        }
+
+        Ok(MatchPattern::Variant(PathParser::parse_path(parser)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parser::test_parse, token::Token, ExpressionKind, MatchPattern};
+
+    use super::MatchParser;
+
+    #[test]
+    fn valid_syntax() {
+        const SRC: &'static str = r#"
+            match op {
+                Op::Deposit => balance + amount,
+                // Comments are allowed in between arms
+                Op::Withdraw => {
+                    balance - amount
+                }
+                _ => balance
+            }
+        "#;
+
+        let mut parser = test_parse(SRC);
+        let match_expr = match MatchParser::parse(&mut parser).unwrap() {
+            ExpressionKind::Match(match_expr) => match_expr,
+            _ => unreachable!("expected a match expression"),
+        };
+        assert_eq!(parser.curr_token.token(), &Token::RightBrace);
+
+        assert_eq!(match_expr.arms.len(), 3);
+        match &match_expr.arms[2].0 {
+            MatchPattern::Wildcard(_) => {}
+            _ => unreachable!("expected a wildcard pattern"),
+        }
+    }
+
+    #[test]
+    fn trailing_comma() {
+        const SRC: &'static str = r#"
+            match op {
+                Op::Deposit => { 1 },
+                Op::Withdraw => 2,
+            }
+        "#;
+
+        MatchParser::parse(&mut test_parse(SRC)).unwrap();
+        MatchParser::parse(&mut test_parse("match op {}")).unwrap();
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        let invalid_src = vec![
+            "match op { Op::Deposit => 1 Op::Withdraw => 2 }",
+            "match op { Op::Deposit 1 }",
+            "match op { Op::Deposit = 1 }",
+            "match op { 1 => 2 }",
+            "match op { Op::Deposit => }",
+            "match op Op::Deposit => 1",
+            "match op { Op::Deposit => 1, ",
+        ];
+
+        for src in invalid_src {
+            MatchParser::parse(&mut test_parse(src)).unwrap_err();
+        }
+    }
+}
@@ -2,16 +2,23 @@ mod array;
 mod block;
 mod constrain;
 mod declaration;
+mod enum_decl;
 mod for_loop;
 mod function;
 mod group;
 mod if_expr;
+mod impl_block;
 mod literal;
+mod match_expr;
 mod module;
 mod name;
 mod path;
+mod return_stmt;
+mod structure;
+mod trait_decl;
 mod unary;
 mod use_stmt;
+mod while_loop;
 
 use array::ArrayParser;
 use block::BlockParser;
@@ -19,33 +26,45 @@ use for_loop::ForParser;
 use group::GroupParser;
 use if_expr::IfParser;
 use literal::LiteralParser;
+use match_expr::MatchParser;
 use name::NameParser;
-use path::PathParser;
 use unary::UnaryParser;
+use while_loop::WhileParser;
 
 pub use constrain::ConstrainParser;
 pub use declaration::DeclarationParser;
+pub use enum_decl::EnumParser;
 pub use function::FuncParser;
+pub use impl_block::ImplParser;
 pub use module::ModuleParser;
+pub use path::PathParser;
+pub use return_stmt::ReturnParser;
+pub use structure::StructParser;
+pub use trait_decl::TraitParser;
 pub use use_stmt::UseParser;
 
 /// This file defines all Prefix parser ie it defines how we parser statements which begin with a specific token or token type
 use crate::ast::{
     ArrayLiteral, BlockExpression, Expression, ExpressionKind, ForExpression, FunctionDefinition,
-    Ident, IfExpression, Literal, NoirFunction, PrefixExpression, Type,
+    Ident, IfExpression, Literal, NoirFunction, PrefixExpression, RepeatedArrayLiteral, Type,
+    Visibility, WhileExpression,
 };
 use crate::token::{Attribute, Keyword, Token, TokenKind};
 use noirc_errors::Span;
 
 use super::{Parser, ParserErrorKind, ParserExprKindResult, ParserExprResult, Precedence};
 
-use crate::ast::{ConstStatement, ImportStatement, LetStatement, PrivateStatement, Statement};
+use crate::ast::{
+    ConstStatement, ImportStatement, LetStatement, Pattern, PrivateStatement, Statement,
+};
 
 /// Strictly speaking, this is not needed as we could import choose_prefix_parser
 /// and choose based on the token. This is a bit more modularised and cleaner to read however
 pub enum PrefixParser {
     For,
+    While,
     If,
+    Match,
     Group,
     Literal,
     Name,
@@ -59,7 +78,9 @@ impl PrefixParser {
     pub fn parse(&self, parser: &mut Parser) -> ParserExprResult {
         match self {
             PrefixParser::For => span_parser(parser, ForParser::parse),
+            PrefixParser::While => span_parser(parser, WhileParser::parse),
             PrefixParser::If => span_parser(parser, IfParser::parse),
+            PrefixParser::Match => span_parser(parser, MatchParser::parse),
             PrefixParser::Array => span_parser(parser, ArrayParser::parse),
             PrefixParser::Name => span_parser(parser, NameParser::parse),
             PrefixParser::Literal => span_parser(parser, LiteralParser::parse),
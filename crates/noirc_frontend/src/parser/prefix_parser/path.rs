@@ -1,19 +1,39 @@
-use crate::{Path, PathKind};
+use crate::{ConstructorExpression, Path, PathKind};
 
 use super::*;
 pub struct PathParser;
 
 impl PathParser {
-    /// Parses a Path
+    /// Parses a Path, optionally followed by a struct constructor
     ///
     /// std::hash
     /// std
     /// core::foo::bar
+    /// foo::Bar { x: EXPR, y: EXPR }
     ///
     /// Cursor Start : `FIRST_PATH_SEGMENT`
     ///
-    /// Cursor End : `LAST_PATH_SEGMENT`
+    /// Cursor End : `LAST_PATH_SEGMENT` or `}` for constructors
     pub fn parse(parser: &mut Parser) -> ParserExprKindResult {
+        let path = PathParser::parse_path(parser)?;
+
+        if parser.peek_token == Token::LeftBrace && !parser.no_constructors {
+            // Current token is the last path segment
+            //
+            // Bump cursor. Current token is now `{`
+            parser.advance_tokens();
+            return PathParser::parse_constructor(parser, path);
+        }
+
+        Ok(ExpressionKind::Path(path))
+    }
+
+    /// Parses a Path
+    ///
+    /// Cursor Start : `FIRST_PATH_SEGMENT`
+    ///
+    /// Cursor End : `LAST_PATH_SEGMENT`
+    pub(crate) fn parse_path(parser: &mut Parser) -> Result<Path, ParserErrorKind> {
         let mut parsed_path = Vec::new();
 
         // Parse the first path segment as a keyword or an identifier
@@ -46,17 +66,62 @@ impl PathParser {
             });
         }
 
-        Ok(ExpressionKind::Path(Path {
+        Ok(Path {
             segments: parsed_path,
             kind: path_kind,
-        }))
+        })
+    }
+
+    /// Parses the fields of a struct constructor
+    ///
+    /// { IDENT : EXPR, IDENT : EXPR, ... }
+    ///
+    /// Cursor Start : `{`
+    ///
+    /// Cursor End : `}`
+    fn parse_constructor(parser: &mut Parser, type_name: Path) -> ParserExprKindResult {
+        let mut fields = Vec::new();
+
+        // Current token is `{`
+        //
+        // Trailing commas are allowed, so we check for the closing
+        // brace before every field.
+        while parser.peek_token != Token::RightBrace {
+            // Peek ahead and check if the next token is the field name
+            parser.peek_check_kind_advance(TokenKind::Ident)?;
+            let field_name: Ident = parser.curr_token.clone().into();
+
+            parser.peek_check_variant_advance(&Token::Colon)?;
+
+            // Current token is `:`
+            //
+            // Bump cursor. Current token is the start of the field's expression
+            parser.advance_tokens();
+            let expr = parser.parse_expression_allow_constructors(Precedence::Lowest)?;
+            fields.push((field_name, expr));
+
+            // Current token is the end of the expression
+            //
+            // Fields are separated by a comma, unless this is the last field
+            if parser.peek_token == Token::Comma {
+                parser.advance_tokens();
+            } else {
+                break;
+            }
+        }
+
+        parser.peek_check_variant_advance(&Token::RightBrace)?;
+
+        Ok(ExpressionKind::Constructor(Box::new(
+            ConstructorExpression { type_name, fields },
+        )))
     }
 }
 
 /// Checks the token and returns the identifier along with the path kind
 /// only plain paths return identifiers, as the other path kinds implicitly
 /// contain the keyword used.
-fn path_identifer(
+pub(super) fn path_identifer(
     tok: &crate::token::SpannedToken,
 ) -> Result<(Option<Ident>, PathKind), ParserErrorKind> {
     use noirc_errors::Spanned;
@@ -145,4 +210,45 @@ mod test {
             PathParser::parse(&mut test_parse(src)).unwrap_err();
         }
     }
+    #[test]
+    fn valid_constructors() {
+        let vectors = vec![
+            ("Foo {}", 0),
+            ("Foo { x : 1 }", 1),
+            ("foo::Bar { x : a + b, y : [1,2] }", 2),
+            ("Foo { x : 1, y : Bar { z : 2 }, }", 2),
+        ];
+
+        for (src, num_fields) in vectors {
+            let mut parser = test_parse(src);
+            let expr = PathParser::parse(&mut parser).unwrap();
+            match expr {
+                ExpressionKind::Constructor(constructor) => {
+                    assert_eq!(constructor.fields.len(), num_fields)
+                }
+                _ => unreachable!("expected a constructor expression"),
+            }
+            assert_eq!(parser.curr_token, crate::token::Token::RightBrace);
+        }
+    }
+    #[test]
+    fn invalid_constructors() {
+        let vectors = vec![
+            "Foo { x }",
+            "Foo { x : 1 y : 2 }",
+            "Foo { 1 : 2 }",
+            "Foo { x : 1",
+        ];
+
+        for src in vectors {
+            PathParser::parse(&mut test_parse(src)).unwrap_err();
+        }
+    }
+    #[test]
+    fn no_constructors() {
+        let mut parser = test_parse("Foo { x : 1 }");
+        parser.no_constructors = true;
+        let expr = PathParser::parse(&mut parser).unwrap();
+        expr_to_path(expr);
+    }
 }
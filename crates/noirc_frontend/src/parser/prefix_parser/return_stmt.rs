@@ -0,0 +1,76 @@
+use super::*;
+
+pub struct ReturnParser;
+
+impl ReturnParser {
+    /// Parses statements of the form
+    /// - return <EXPR>;
+    /// - return;
+    ///
+    /// Cursor Start : `return`
+    ///
+    /// Cursor End : `;`
+    pub(crate) fn parse_statement(parser: &mut Parser) -> Result<Expression, ParserErrorKind> {
+        // Current token is `return`
+        //
+        // A return statement without an expression returns the unit value of an empty block
+        if parser.peek_token == Token::Semicolon {
+            let span = parser.curr_token.into_span();
+            parser.advance_tokens();
+            return Ok(Expression {
+                kind: ExpressionKind::Block(BlockExpression(Vec::new())),
+                span,
+            });
+        }
+
+        // Bump cursor. Current token is the start of the returned expression
+        parser.advance_tokens();
+
+        let expr = parser.parse_expression(Precedence::Lowest)?;
+
+        parser.peek_check_variant_advance(&Token::Semicolon)?;
+
+        Ok(expr)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        parser::test_parse,
+        token::{Keyword, Token},
+    };
+
+    use super::ReturnParser;
+
+    #[test]
+    fn valid_syntax() {
+        let vectors = vec![
+            "return x;",
+            "return x + y;",
+            "return foo(x);",
+            "return arr[0];",
+            "return if x == 0 { 1 } else { 2 };",
+            "return;",
+        ];
+
+        for src in vectors {
+            let mut parser = test_parse(src);
+            let start = parser.curr_token.clone();
+
+            ReturnParser::parse_statement(&mut parser).unwrap();
+
+            assert_eq!(start.token(), &Token::Keyword(Keyword::Return));
+            assert_eq!(parser.curr_token.token(), &Token::Semicolon);
+        }
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        let vectors = vec!["return x", "return x y;", "return let x = 1;"];
+
+        for src in vectors {
+            ReturnParser::parse_statement(&mut test_parse(src)).unwrap_err();
+        }
+    }
+}
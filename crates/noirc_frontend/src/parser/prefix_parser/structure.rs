@@ -0,0 +1,129 @@
+use crate::NoirStruct;
+
+use super::*;
+
+pub struct StructParser;
+
+impl StructParser {
+    /// Parses a struct definition.
+    ///
+    /// struct IDENT {
+    ///     IDENT : TYPE,
+    ///     IDENT : TYPE,
+    ///     ...
+    /// }
+    ///
+    /// Cursor Start : `struct`
+    ///
+    /// Cursor End : `}`
+    pub(crate) fn parse_struct_definition(
+        parser: &mut Parser,
+        visibility: Visibility,
+    ) -> Result<NoirStruct, ParserErrorKind> {
+        let start = parser.curr_token.into_span();
+
+        // Current token is `struct`
+        //
+        // Peek ahead and check if the next token is an identifier
+        parser.peek_check_kind_advance(TokenKind::Ident)?;
+        let name: Ident = parser.curr_token.clone().into();
+
+        // Current token is the struct name
+        //
+        // Peek ahead and check if the next token is `{`
+        parser.peek_check_variant_advance(&Token::LeftBrace)?;
+
+        let fields = StructParser::parse_fields(parser)?;
+
+        // Current token is `}`
+        let end = parser.curr_token.into_span();
+
+        Ok(NoirStruct::new(name, visibility, fields, start.merge(end)))
+    }
+
+    /// Cursor Start : `{`
+    ///
+    /// Cursor End : `}`
+    fn parse_fields(parser: &mut Parser) -> Result<Vec<(Ident, Type)>, ParserErrorKind> {
+        let mut fields = Vec::new();
+
+        // Trailing commas are allowed, so we check for the closing
+        // brace before every field.
+        while parser.peek_token != Token::RightBrace {
+            // Peek ahead and check if the next token is the field name
+            parser.peek_check_kind_advance(TokenKind::Ident)?;
+            let field_name: Ident = parser.curr_token.clone().into();
+
+            parser.peek_check_variant_advance(&Token::Colon)?;
+
+            // Current token is `:`
+            //
+            // Bump cursor. Next Token should be the Type
+            parser.advance_tokens();
+
+            // Fields cannot have a visibility, the visibility of the struct
+            // is determined by the context in which it is used.
+            let field_type = parser.parse_type(false)?;
+            fields.push((field_name, field_type));
+
+            // Current token is the end of the type
+            //
+            // Fields are separated by a comma, unless this is the last field
+            if parser.peek_token == Token::Comma {
+                parser.advance_tokens();
+            } else {
+                break;
+            }
+        }
+
+        parser.peek_check_variant_advance(&Token::RightBrace)?;
+
+        Ok(fields)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parser::test_parse, token::Token, Visibility};
+
+    use super::StructParser;
+
+    #[test]
+    fn valid_syntax() {
+        let vectors = vec![
+            ("struct Foo {}", 0),
+            ("struct Foo { x : Field }", 1),
+            ("struct Foo { x : Field, y : [4]u8, }", 2),
+            ("struct Foo { x : Field, bar : foo::Bar }", 2),
+        ];
+
+        for (src, num_fields) in vectors {
+            let mut parser = test_parse(src);
+
+            let start = parser.curr_token.clone();
+            let struct_def =
+                StructParser::parse_struct_definition(&mut parser, Visibility::Private).unwrap();
+            let end = parser.curr_token.clone();
+
+            assert_eq!(struct_def.fields.len(), num_fields);
+            assert_eq!(start, Token::Keyword(crate::token::Keyword::Struct));
+            assert_eq!(end, Token::RightBrace);
+        }
+    }
+    #[test]
+    fn invalid_syntax() {
+        let vectors = vec![
+            "struct {}",
+            "struct Foo",
+            "struct Foo { x }",
+            "struct Foo { x : Field y : Field }",
+            "struct Foo { x : pub Field }",
+            "struct Foo { 1 : Field }",
+        ];
+
+        for src in vectors {
+            StructParser::parse_struct_definition(&mut test_parse(src), Visibility::Private)
+                .unwrap_err();
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::NoirTrait;
+
+use super::*;
+
+pub struct TraitParser;
+
+impl TraitParser {
+    /// Parses a trait declaration.
+    ///
+    /// trait IDENT {
+    ///     fn IDENT(...) -> TYPE;
+    ///     fn IDENT(...) -> TYPE;
+    ///     ...
+    /// }
+    ///
+    /// Cursor Start : `trait`
+    ///
+    /// Cursor End : `}`
+    pub(crate) fn parse_trait_definition(
+        parser: &mut Parser,
+        visibility: Visibility,
+    ) -> Result<NoirTrait, ParserErrorKind> {
+        let start = parser.curr_token.into_span();
+
+        // Current token is `trait`
+        //
+        // Peek ahead and check if the next token is an identifier
+        parser.peek_check_kind_advance(TokenKind::Ident)?;
+        let name: Ident = parser.curr_token.clone().into();
+
+        // Current token is the trait name
+        //
+        // Peek ahead and check if the next token is `{`
+        parser.peek_check_variant_advance(&Token::LeftBrace)?;
+
+        let mut methods = Vec::new();
+        loop {
+            // Current token is `{` or the `;` of the previous method
+            parser.advance_tokens();
+
+            match parser.curr_token.token() {
+                Token::RightBrace => break,
+                Token::Comment(_) => continue,
+                Token::Keyword(Keyword::Fn) => {
+                    methods.push(FuncParser::parse_fn_declaration(parser)?);
+                }
+                _ => {
+                    return Err(ParserErrorKind::UnexpectedToken {
+                        span: parser.curr_token.into_span(),
+                        expected: Token::Keyword(Keyword::Fn),
+                        found: parser.curr_token.token().clone(),
+                    })
+                }
+            }
+        }
+
+        // Current token is `}`
+        let end = parser.curr_token.into_span();
+
+        Ok(NoirTrait {
+            name,
+            visibility,
+            methods,
+            span: start.merge(end),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parser::test_parse, Visibility};
+
+    use super::TraitParser;
+
+    #[test]
+    fn valid_syntax() {
+        const SRC: &'static str = r#"
+            trait Hasher {
+                // Methods only have a signature
+                fn hash(left : Field, right : Field) -> Field;
+                fn zero() -> Self;
+                fn combine(self, other : Self) -> Self;
+            }
+        "#;
+
+        let mut parser = test_parse(SRC);
+        let noir_trait =
+            TraitParser::parse_trait_definition(&mut parser, Visibility::Private).unwrap();
+        assert_eq!(parser.curr_token.token(), &crate::token::Token::RightBrace);
+
+        assert_eq!(noir_trait.name.0.contents, "Hasher");
+        assert_eq!(noir_trait.methods.len(), 3);
+    }
+
+    #[test]
+    fn empty_trait() {
+        TraitParser::parse_trait_definition(
+            &mut test_parse("trait Marker {}"),
+            Visibility::Private,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        let invalid_src = vec![
+            "trait { fn foo(); }",
+            "trait foo::Hasher { fn foo(); }",
+            "trait Hasher { fn foo() }",
+            "trait Hasher { fn foo() {} }",
+            "trait Hasher { let x = 1; }",
+            "trait Hasher { fn foo(); ",
+        ];
+
+        for src in invalid_src {
+            TraitParser::parse_trait_definition(&mut test_parse(src), Visibility::Private)
+                .unwrap_err();
+        }
+    }
+}
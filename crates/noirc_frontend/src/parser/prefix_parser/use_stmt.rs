@@ -1,23 +1,94 @@
+use crate::Path;
+
+use super::path::path_identifer;
 use super::*;
 
 pub struct UseParser;
 
 impl UseParser {
-    // Import statements of the form use std::hash::sha256;
-    pub fn parse(parser: &mut Parser) -> Result<ImportStatement, ParserErrorKind> {
+    /// Parses an import statement, which may import several items.
+    ///
+    /// use std::hash::sha256;
+    /// use std::hash::sha256 as hash;
+    /// use std::hash::*;
+    /// use std::{hash, merkle::check_membership};
+    ///
+    /// Groups may be nested, each path in a group is prefixed
+    /// with the path before the group.
+    ///
+    /// Cursor Start : `use`
+    ///
+    /// Cursor End : `;`
+    pub fn parse(
+        parser: &mut Parser,
+        visibility: Visibility,
+    ) -> Result<Vec<ImportStatement>, ParserErrorKind> {
         // Current token is 'use'
         //
-        // Next token should be the first segment for the Path
-        // The PathParser expects the cursor to be on the first segment
-        // so we advance the parser.
+        // Bump cursor. Current token should be the first segment of the path
         parser.advance_tokens();
 
-        // Current token is the first path segment
-        // Which is the correct condition to call the Path parser
-        // as a sub procedure.
-        let path = PathParser::parse(parser)?
-            .into_path()
-            .expect("ice : path parser did not produce a path");
+        // The first segment may also be `crate` or `dep`
+        let (ident, kind) = path_identifer(&parser.curr_token)?;
+        let prefix = Path {
+            segments: ident.into_iter().collect(),
+            kind,
+        };
+
+        let mut imports = Vec::new();
+        UseParser::parse_use_tree(parser, prefix, visibility, &mut imports)?;
+
+        // Current token is the end of the import tree
+        //
+        // Peek ahead and check if the next token is `;`
+        parser.peek_check_variant_advance(&Token::Semicolon)?;
+
+        Ok(imports)
+    }
+
+    /// Parses the rest of an import after its prefix, which is either a path
+    /// with an optional alias, a glob or a group of imports.
+    ///
+    /// Cursor Start : The last segment of the prefix
+    ///
+    /// Cursor End : The last segment, the alias, `*` or `}`
+    fn parse_use_tree(
+        parser: &mut Parser,
+        mut prefix: Path,
+        visibility: Visibility,
+        imports: &mut Vec<ImportStatement>,
+    ) -> Result<(), ParserErrorKind> {
+        while parser.peek_token == Token::DoubleColon {
+            // Current token is the last segment
+            //
+            // Bump cursor twice. Current token is now after the `::`
+            parser.advance_tokens();
+            parser.advance_tokens();
+
+            match parser.curr_token.token() {
+                Token::Star => {
+                    let span = parser.curr_token.into_span();
+                    let path = UseParser::non_empty_path(prefix, span)?;
+                    imports.push(ImportStatement {
+                        path,
+                        alias: None,
+                        is_glob: true,
+                        visibility,
+                    });
+                    return Ok(());
+                }
+                Token::LeftBrace => {
+                    return UseParser::parse_use_group(parser, prefix, visibility, imports)
+                }
+                _ => {
+                    let segment = PrefixParser::Name.parse(parser)?.into_ident().unwrap();
+                    prefix.segments.push(segment);
+                }
+            }
+        }
+
+        // This only happens in cases such as `use dep;` or `use crate`
+        let path = UseParser::non_empty_path(prefix, parser.curr_token.into_span())?;
 
         // Current token is the last identifier in the path
         //
@@ -37,36 +108,105 @@ impl UseParser {
             alias = Some(parser.curr_token.clone().into());
         }
 
-        // Current token is the alias or the last path segment
-        //
-        // Peek ahead and check if the next token is `;`
-        parser.peek_check_variant_advance(&Token::Semicolon)?;
+        imports.push(ImportStatement {
+            path,
+            alias,
+            is_glob: false,
+            visibility,
+        });
+        Ok(())
+    }
+
+    /// Parses a group of imports which share the same prefix.
+    /// Trailing commas are allowed.
+    ///
+    /// Cursor Start : `{`
+    ///
+    /// Cursor End : `}`
+    fn parse_use_group(
+        parser: &mut Parser,
+        prefix: Path,
+        visibility: Visibility,
+        imports: &mut Vec<ImportStatement>,
+    ) -> Result<(), ParserErrorKind> {
+        loop {
+            // Current token is `{` or the `,` after the previous import
+            //
+            // Bump cursor. Current token is the first segment of the import or `}`
+            parser.advance_tokens();
+            if parser.curr_token == Token::RightBrace {
+                return Ok(());
+            }
 
-        Ok(ImportStatement { path, alias })
+            let segment = PrefixParser::Name.parse(parser)?.into_ident().unwrap();
+            let mut path = prefix.clone();
+            path.segments.push(segment);
+            UseParser::parse_use_tree(parser, path, visibility, imports)?;
+
+            // Imports are separated by a comma, unless this is the last import
+            if parser.peek_token == Token::Comma {
+                parser.advance_tokens();
+            } else {
+                parser.peek_check_variant_advance(&Token::RightBrace)?;
+                return Ok(());
+            }
+        }
+    }
+
+    fn non_empty_path(path: Path, span: Span) -> Result<Path, ParserErrorKind> {
+        if path.segments.is_empty() {
+            return Err(ParserErrorKind::SingleKeywordSegmentNotAllowed {
+                span,
+                path_kind: path.kind,
+            });
+        }
+        Ok(path)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::parser::test_parse;
+    use crate::{parser::test_parse, token::Token, Visibility};
 
     use super::UseParser;
 
     #[test]
     fn valid_syntax() {
         let vectors = vec![
-            "use std::hash;",
-            "use std;",
-            "use foo::bar as hello;",
-            "use bar as bar;",
+            ("use std::hash;", 1),
+            ("use std;", 1),
+            ("use foo::bar as hello;", 1),
+            ("use bar as bar;", 1),
+            ("use dep::std::hash::*;", 1),
+            ("use dep::std::{hash, merkle};", 2),
+            ("use crate::{foo::bar as baz, foo::*, qux,};", 3),
+            ("use std::{hash::{sha256, blake2s}, merkle};", 3),
+            ("use std::{};", 0),
         ];
 
-        for src in vectors {
+        for (src, num_imports) in vectors {
             let mut parser = test_parse(src);
-            UseParser::parse(&mut parser).unwrap();
+            let imports = UseParser::parse(&mut parser, Visibility::Private).unwrap();
+            assert_eq!(imports.len(), num_imports, "src: {}", src);
+            assert_eq!(parser.curr_token.token(), &Token::Semicolon);
         }
     }
 
+    #[test]
+    fn group_paths() {
+        let src = "use dep::std::{hash::*, merkle::check_membership as check};";
+        let imports = UseParser::parse(&mut test_parse(src), Visibility::Public).unwrap();
+
+        assert_eq!(imports[0].path.as_string(), "std::hash");
+        assert!(imports[0].is_glob);
+        assert_eq!(imports[1].path.as_string(), "std::merkle::check_membership");
+        assert_eq!(imports[1].alias.as_ref().unwrap().0.contents, "check");
+        assert!(!imports[1].is_glob);
+        assert!(imports
+            .iter()
+            .all(|import| import.visibility == Visibility::Public));
+    }
+
     #[test]
     fn invalid_syntax() {
         let vectors = vec![
@@ -82,11 +222,21 @@ mod test {
             //
             // Path ends with `::`
             "use hello:: as foo;",
+            //
+            // Glob imports cannot have an alias or be followed by a path
+            "use std::* as foo;",
+            "use std::*::hash;",
+            "use dep::*;",
+            //
+            // Unclosed or unseparated groups
+            "use std::{hash, merkle;",
+            "use std::{hash merkle};",
+            "use std::{hash} as foo;",
         ];
 
         for src in vectors {
             let mut parser = test_parse(src);
-            UseParser::parse(&mut parser).unwrap_err();
+            UseParser::parse(&mut parser, Visibility::Private).unwrap_err();
         }
     }
 }
@@ -0,0 +1,89 @@
+use super::*;
+
+pub struct WhileParser;
+
+impl WhileParser {
+    /// Parses a while expression.
+    ///
+    /// ```noir
+    /// while CONDITION {
+    ///  <STMT> <STMT> <STMT> ...
+    /// }
+    ///```
+    ///
+    /// Cursor Start : `while`
+    ///
+    /// Cursor End : `}`
+    pub fn parse(parser: &mut Parser) -> ParserExprKindResult {
+        // Current token is `while`
+        //
+        // Bump cursor.
+        parser.advance_tokens();
+
+        // Current token is the start of the condition
+        //
+        // Struct literals are not allowed here, as `while x {}`
+        // would otherwise be parsed as a struct literal.
+        let condition = parser.parse_expression_no_constructors(Precedence::Lowest)?;
+
+        // Current token is now the end of the condition
+        //
+        // Peek ahead and check if the next token is `{`
+        parser.peek_check_variant_advance(&Token::LeftBrace)?;
+
+        // Parse the while loop body
+        //
+        // Current token is the `{`
+        // This is the correct cursor position to call `parse_block_expression`
+        let block = BlockParser::parse_block_expression(parser)?;
+
+        // The cursor position is inherited from the block expression
+        // parsing procedure which is `}`
+
+        let while_expr = WhileExpression { condition, block };
+
+        Ok(ExpressionKind::While(Box::new(while_expr)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parser::test_parse, token::Token};
+
+    use super::WhileParser;
+
+    #[test]
+    fn valid_syntax() {
+        const SRC: &'static str = r#"
+            while i < 10 {
+                i = i + 1;
+            }
+        "#;
+
+        let mut parser = test_parse(SRC);
+        let start = parser.curr_token.clone();
+        WhileParser::parse(&mut parser).unwrap();
+        let end = parser.curr_token;
+
+        assert_eq!(start, Token::Keyword(crate::token::Keyword::While));
+        assert_eq!(end, Token::RightBrace);
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        /// The condition cannot be empty
+        const SRC_NO_CONDITION: &'static str = r#"
+            while {
+
+            }
+        "#;
+        /// The body must be a block
+        const SRC_NO_BLOCK: &'static str = r#"
+            while i < 10
+                i = i + 1;
+        "#;
+
+        WhileParser::parse(&mut test_parse(SRC_NO_CONDITION)).unwrap_err();
+        WhileParser::parse(&mut test_parse(SRC_NO_BLOCK)).unwrap_err();
+    }
+}